@@ -2,7 +2,7 @@ use std::env;
 use std::fs::File;
 
 use memmap::MmapOptions;
-use parser_riff::{RiffChunk, RiffResult};
+use parser_riff::{RiffChunk, RiffParseMode, RiffResult};
 
 pub fn print_riff_chunk(chunk: &RiffChunk, chunk_level: usize) -> RiffResult<()> {
     match chunk {
@@ -10,12 +10,15 @@ pub fn print_riff_chunk(chunk: &RiffChunk, chunk_level: usize) -> RiffResult<()>
             chunk_type,
             chunk_id,
             subchunks,
+            chunk_offset,
+            ..
         } => {
             println!(
-                "{:chunk_level$}{} [{}]",
+                "{:chunk_level$}{} [{}] @ {:#010X}",
                 "",
                 chunk_id.escape_default(),
                 chunk_type.escape_default(),
+                chunk_offset,
             );
 
             for chunk in subchunks {
@@ -25,12 +28,15 @@ pub fn print_riff_chunk(chunk: &RiffChunk, chunk_level: usize) -> RiffResult<()>
         RiffChunk::Normal {
             chunk_id,
             chunk_data,
+            chunk_offset,
+            ..
         } => {
             println!(
-                "{:chunk_level$}{} ({})",
+                "{:chunk_level$}{} ({}) @ {:#010X}",
                 "",
                 chunk_id.escape_default(),
-                chunk_data.len()
+                chunk_data.len(),
+                chunk_offset,
             );
         }
     }
@@ -39,7 +45,16 @@ pub fn print_riff_chunk(chunk: &RiffChunk, chunk_level: usize) -> RiffResult<()>
 }
 
 fn main() {
-    let riff_path = env::args().nth(1).expect("No input file argument");
+    let parse_mode = if env::args().any(|arg| arg == "--lenient") {
+        RiffParseMode::Lenient
+    } else {
+        RiffParseMode::Strict
+    };
+
+    let riff_path = env::args()
+        .skip(1)
+        .find(|arg| arg != "--lenient")
+        .expect("No input file argument");
     let riff_file = File::open(riff_path).expect("Failed to open input file");
 
     let riff_mmap: &[u8] = unsafe {
@@ -48,7 +63,11 @@ fn main() {
             .expect("Failed to mmap input file")
     };
 
-    if let Ok(riff_root) = RiffChunk::new(riff_mmap) {
+    if let Ok((riff_root, riff_warnings)) = RiffChunk::with_mode(riff_mmap, parse_mode) {
         let _ = print_riff_chunk(&riff_root, 0);
+
+        for riff_warning in riff_warnings {
+            eprintln!("Warning: {riff_warning}");
+        }
     }
 }
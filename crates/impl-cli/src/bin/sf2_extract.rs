@@ -0,0 +1,176 @@
+use std::env;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::Path;
+
+use impl_cli::wav::{write_wav, WavFormat, WavSampleLoop, WavSampler};
+use memmap::MmapOptions;
+use parser_sf2::{Sf2SampleData, Sf2SampleHeader, Sf2SoundFont};
+
+/// Sample points of a sample, as 24-bit values when requested and available.
+fn sample_points(sample_data: &Sf2SampleData, use_24bit: bool) -> Vec<i32> {
+    match sample_data.frames_24() {
+        Some(frames_24) if use_24bit => frames_24.collect(),
+        _ => sample_data.frames_16().map(i32::from).collect(),
+    }
+}
+
+/// Whether a sample can be written as a channel of a stereo file.
+fn is_mergeable<'a>(soundfont: &'a Sf2SoundFont<'a>, sample_header: &Sf2SampleHeader) -> bool {
+    sample_header
+        .sample_type()
+        .is_ok_and(|sample_type| !sample_type.is_rom())
+        && soundfont.sample_data(sample_header).is_ok()
+}
+
+fn wav_sampler(sample_header: &Sf2SampleHeader, sample_length: usize) -> WavSampler {
+    let original_pitch = match sample_header.original_pitch {
+        original_pitch @ 0..=127 => original_pitch as i32,
+        // Unpitched samples and invalid values default to middle C.
+        _ => 60,
+    };
+
+    // The pitch correction is applied on playback, the recorded pitch is
+    // off by the same amount in the opposite direction.
+    let pitch = (original_pitch * 100 - sample_header.pitch_correction as i32).clamp(0, 12799);
+
+    let start = sample_header.start.get();
+    let start_loop = sample_header.start_loop.get().wrapping_sub(start);
+    let end_loop = sample_header.end_loop.get().wrapping_sub(start);
+
+    // The `smpl` loop end is inclusive, unlike the one of the SoundFont.
+    let sample_loops = if (start_loop < end_loop) && (end_loop as usize <= sample_length) {
+        vec![WavSampleLoop {
+            start: start_loop,
+            end: end_loop - 1,
+        }]
+    } else {
+        vec![]
+    };
+
+    WavSampler {
+        unity_note: (pitch / 100) as u8,
+        pitch_fraction_cents: (pitch % 100) as u8,
+        sample_loops,
+    }
+}
+
+fn file_name(sample_index: usize, sample_name: &str) -> String {
+    let sample_name = sample_name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || "-_.() ".contains(c) {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+
+    format!("{:04} {}.wav", sample_index, sample_name.trim())
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = env::args().collect::<Vec<_>>();
+
+    let merge_stereo = args.iter().any(|arg| arg == "--stereo");
+    let use_24bit = args.iter().any(|arg| arg == "--24bit");
+
+    let args = args
+        .into_iter()
+        .filter(|arg| !arg.starts_with("--"))
+        .collect::<Vec<_>>();
+
+    let sf2_path = args.get(1).expect("No input SoundFont argument");
+    let output_path = Path::new(args.get(2).expect("No output directory argument"));
+
+    let sf2_file = File::open(sf2_path).expect("Failed to open input SoundFont");
+    let sf2_mmap: &[u8] = unsafe {
+        &MmapOptions::new()
+            .map(&sf2_file)
+            .expect("Failed to mmap input SoundFont")
+    };
+
+    let sf2_soundfont = Sf2SoundFont::new(sf2_mmap)?;
+
+    if use_24bit && sf2_soundfont.sample_data_24_chunk()?.is_none() {
+        eprintln!("Warning: no 24-bit sample data present, writing 16-bit files");
+    }
+
+    fs::create_dir_all(output_path)?;
+
+    let sample_headers = sf2_soundfont.sample_headers()?;
+
+    for (sample_index, sample_header) in sample_headers.iter().enumerate() {
+        let sample_name = sample_header.sample_name()?;
+        let sample_type = sample_header.sample_type()?;
+
+        if sample_type.is_rom() {
+            eprintln!("Skipping ROM sample '{}'", sample_name);
+            continue;
+        }
+
+        let sample_data = sf2_soundfont.sample_data(sample_header)?;
+        let mut channels = vec![sample_points(&sample_data, use_24bit)];
+
+        // Left and right samples are merged when both can be written, the
+        // pair then being written along the left sample. Otherwise, as with
+        // a broken link, each side is written on its own.
+        let stereo_partner = if merge_stereo && (sample_type.is_left() || sample_type.is_right()) {
+            match sf2_soundfont.linked_sample_header(sample_index) {
+                Ok(linked_sample_header) => linked_sample_header.filter(|linked_sample_header| {
+                    is_mergeable(&sf2_soundfont, linked_sample_header)
+                }),
+                Err(err) => {
+                    eprintln!("Warning: sample '{}': {}", sample_name, err);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        match stereo_partner {
+            Some(_) if sample_type.is_right() => continue,
+            Some(linked_sample_header) => {
+                let linked_sample_data = sf2_soundfont.sample_data(linked_sample_header)?;
+                channels.push(sample_points(&linked_sample_data, use_24bit));
+            }
+            None => {}
+        }
+
+        let frame_count = channels.iter().map(Vec::len).max().unwrap_or(0);
+        let samples = (0..frame_count)
+            .flat_map(|frame| {
+                channels
+                    .iter()
+                    .map(move |channel| channel.get(frame).copied().unwrap_or(0))
+            })
+            .collect::<Vec<_>>();
+
+        let wav_format = WavFormat {
+            channel_count: channels.len() as u16,
+            sample_rate: sample_header.sample_rate.get(),
+            bits_per_sample: if use_24bit && sample_data.has_24bit() {
+                24
+            } else {
+                16
+            },
+        };
+
+        let wav_path = output_path.join(file_name(sample_index, sample_name));
+        let wav_file = BufWriter::new(File::create(&wav_path)?);
+
+        write_wav(
+            wav_file,
+            &wav_format,
+            &samples,
+            Some(&wav_sampler(sample_header, sample_data.len())),
+        )?;
+
+        println!("{}", wav_path.display());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,180 @@
+use std::env;
+use std::error::Error;
+use std::fs::File;
+use std::process::ExitCode;
+
+use impl_cli::json::Json;
+use memmap::MmapOptions;
+use midi_defs::gm::GENERAL_MIDI;
+use parser_sf2::{Sf2GmCompliance, Sf2GmPresetSlot, Sf2SoundFont};
+
+const EXIT_COMPLIANT: u8 = 0;
+const EXIT_NOT_COMPLIANT: u8 = 1;
+/// The input file could not be read or parsed.
+const EXIT_INPUT_ERROR: u8 = 2;
+/// Invalid arguments, nothing got checked.
+const EXIT_USAGE: u8 = 3;
+
+const USAGE: &str = "Usage: sf2_gm_check [--format text|json] <file>";
+
+fn gm_name(bank_preset: (u16, u16)) -> &'static str {
+    GENERAL_MIDI
+        .iter()
+        .find(|(key, _)| *key == bank_preset)
+        .map_or("", |(_, name)| name)
+}
+
+fn bank_preset_json(bank_preset: (u16, u16)) -> Json {
+    let (bank, preset) = bank_preset;
+
+    Json::object([
+        ("bank", bank.into()),
+        ("preset", preset.into()),
+        ("gm_name", gm_name(bank_preset).into()),
+    ])
+}
+
+fn preset_slot_json(preset_slot: &Sf2GmPresetSlot) -> Json {
+    Json::object([
+        ("bank", preset_slot.bank.into()),
+        ("preset", preset_slot.preset.into()),
+        ("preset_name", preset_slot.preset_name.as_str().into()),
+    ])
+}
+
+fn report_json(sf2_path: &str, gm_compliance: &Sf2GmCompliance) -> Json {
+    Json::object([
+        ("file", sf2_path.into()),
+        ("compliant", gm_compliance.is_compliant().into()),
+        (
+            "covered",
+            Json::array(gm_compliance.covered.iter().copied().map(bank_preset_json)),
+        ),
+        (
+            "missing",
+            Json::array(gm_compliance.missing.iter().copied().map(bank_preset_json)),
+        ),
+        (
+            "non_standard",
+            Json::array(gm_compliance.non_standard.iter().map(preset_slot_json)),
+        ),
+        (
+            "misplaced_drum_kits",
+            Json::array(
+                gm_compliance
+                    .misplaced_drum_kits
+                    .iter()
+                    .map(preset_slot_json),
+            ),
+        ),
+        (
+            "drum_kit_names",
+            Json::array(gm_compliance.drum_kit_names.iter().map(preset_slot_json)),
+        ),
+    ])
+}
+
+fn print_report(gm_compliance: &Sf2GmCompliance) {
+    println!(
+        "Covered: {}/{}",
+        gm_compliance.covered.len(),
+        GENERAL_MIDI.len()
+    );
+
+    for &(bank, preset) in &gm_compliance.missing {
+        println!(
+            "MISSING      [{:3}:{:3}] {}",
+            bank,
+            preset,
+            gm_name((bank, preset))
+        );
+    }
+
+    for preset_slot in &gm_compliance.non_standard {
+        println!(
+            "NON-STANDARD [{:3}:{:3}] {}",
+            preset_slot.bank, preset_slot.preset, preset_slot.preset_name
+        );
+    }
+
+    for preset_slot in &gm_compliance.misplaced_drum_kits {
+        println!(
+            "DRUM KIT     [{:3}:{:3}] {} (not in bank 128)",
+            preset_slot.bank, preset_slot.preset, preset_slot.preset_name
+        );
+    }
+
+    for preset_slot in &gm_compliance.drum_kit_names {
+        println!(
+            "WARNING      [{:3}:{:3}] {} (named like a drum kit, not in bank 128)",
+            preset_slot.bank, preset_slot.preset, preset_slot.preset_name
+        );
+    }
+
+    if gm_compliance.is_compliant() {
+        println!("General MIDI compliant");
+    } else {
+        println!("Not General MIDI compliant");
+    }
+}
+
+/// Checks a SoundFont file and prints its report, returning whether it is
+/// compliant.
+fn check_file(sf2_path: &str, json_output: bool) -> Result<bool, Box<dyn Error>> {
+    let sf2_file = File::open(sf2_path)
+        .map_err(|error| format!("Failed to open input file {sf2_path:}: {error:}"))?;
+
+    let sf2_mmap: &[u8] = unsafe {
+        &MmapOptions::new()
+            .map(&sf2_file)
+            .map_err(|error| format!("Failed to mmap input file {sf2_path:}: {error:}"))?
+    };
+
+    let sf2_soundfont = Sf2SoundFont::new(sf2_mmap)?;
+    let gm_compliance = Sf2GmCompliance::new(&sf2_soundfont)?;
+
+    if json_output {
+        println!("{}", report_json(sf2_path, &gm_compliance));
+    } else {
+        print_report(&gm_compliance);
+    }
+
+    Ok(gm_compliance.is_compliant())
+}
+
+fn usage_error(message: &str) -> ExitCode {
+    eprintln!("Error: {message:}");
+    eprintln!("{USAGE:}");
+    ExitCode::from(EXIT_USAGE)
+}
+
+fn main() -> ExitCode {
+    let mut args = env::args().collect::<Vec<_>>();
+
+    let json_output = match args.iter().position(|arg| arg == "--format") {
+        Some(position) => {
+            let json_output = match args.get(position + 1).map(String::as_str) {
+                Some("json") => true,
+                Some("text") => false,
+                _ => return usage_error("Invalid format argument, expected `text` or `json`"),
+            };
+
+            args.drain(position..=position + 1);
+            json_output
+        }
+        None => false,
+    };
+
+    let Some(sf2_path) = args.get(1) else {
+        return usage_error("No input file argument");
+    };
+
+    match check_file(sf2_path, json_output) {
+        Ok(true) => ExitCode::from(EXIT_COMPLIANT),
+        Ok(false) => ExitCode::from(EXIT_NOT_COMPLIANT),
+        Err(error) => {
+            eprintln!("Error: {error:}");
+            ExitCode::from(EXIT_INPUT_ERROR)
+        }
+    }
+}
@@ -4,7 +4,54 @@ use std::fs::File;
 
 use itertools::Itertools;
 use memmap::MmapOptions;
-use parser_sf2::Sf2SoundFont;
+use midi_defs::sound_set::lookup_bank_preset;
+use parser_sf2::{Sf2Generator, Sf2Modulator, Sf2SoundFont};
+
+/// Describes a generator. Unknown generators are to be ignored rather than
+/// rejected, they are listed with their raw ID and amount.
+fn generator_description<G: Sf2Generator>(generator: &G) -> String {
+    match generator.generator_kind() {
+        Ok(generator_kind) => format!(
+            "{} {}",
+            generator_kind,
+            generator_kind.decode_amount(generator.raw_amount()),
+        ),
+        Err(_) => format!(
+            "unknown:{} {:#06X}",
+            generator.generator_id(),
+            generator.raw_amount(),
+        ),
+    }
+}
+
+/// Describes a modulator. Modulators with unknown fields are to be ignored
+/// as well, they are listed with their raw fields.
+fn modulator_description<M: Sf2Modulator>(modulator: &M) -> String {
+    let (Ok(source), Ok(destination), Ok(amount_source), Ok(transform)) = (
+        modulator.source(),
+        modulator.destination(),
+        modulator.amount_source(),
+        modulator.transform(),
+    ) else {
+        return format!(
+            "unknown:{:#06X} -> {:#06X} * {} * {:#06X} ({:#06X})",
+            modulator.raw_source(),
+            modulator.raw_destination(),
+            modulator.amount(),
+            modulator.raw_amount_source(),
+            modulator.raw_transform(),
+        );
+    };
+
+    format!(
+        "{} -> {} * {} * {} ({})",
+        source,
+        destination,
+        modulator.amount(),
+        amount_source,
+        transform,
+    )
+}
 
 fn main() -> Result<(), Box<dyn Error>> {
     let sf2_path = env::args().nth(1).expect("No input file argument");
@@ -38,11 +85,17 @@ fn main() -> Result<(), Box<dyn Error>> {
         .iter()
         .sorted_by_key(|phdr| phdr.bank_preset())
     {
+        let sound_sets = lookup_bank_preset(preset_header.bank_preset())
+            .iter()
+            .map(|(sound_set, name)| format!("{sound_set:}: {name:}"))
+            .join(", ");
+
         println!(
-            "PRESET HEADER [{:3}:{:3}] {}",
+            "PRESET HEADER [{:3}:{:3}] {} ({})",
             preset_header.bank(),
             preset_header.preset(),
             preset_header.preset_name()?,
+            sound_sets,
         )
     }
 
@@ -53,6 +106,20 @@ fn main() -> Result<(), Box<dyn Error>> {
         )
     }
 
+    for preset_generator in sf2_soundfont.preset_generators()? {
+        println!(
+            "PRESET GENERATOR {}",
+            generator_description(preset_generator)
+        )
+    }
+
+    for preset_modulator in sf2_soundfont.preset_modulators()? {
+        println!(
+            "PRESET MODULATOR {}",
+            modulator_description(preset_modulator)
+        )
+    }
+
     for instrument_header in sf2_soundfont.instrument_headers()? {
         println!(
             "INSTRUMENT HEADER {:5} {}",
@@ -68,6 +135,20 @@ fn main() -> Result<(), Box<dyn Error>> {
         )
     }
 
+    for instrument_generator in sf2_soundfont.instrument_generators()? {
+        println!(
+            "INSTRUMENT GENERATOR {}",
+            generator_description(instrument_generator)
+        )
+    }
+
+    for instrument_modulator in sf2_soundfont.instrument_modulators()? {
+        println!(
+            "INSTRUMENT MODULATOR {}",
+            modulator_description(instrument_modulator)
+        )
+    }
+
     for sample_header in sf2_soundfont.sample_headers()? {
         println!("SAMPLE HEADER {}", sample_header.sample_name()?,)
     }
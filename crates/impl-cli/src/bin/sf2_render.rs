@@ -0,0 +1,153 @@
+use std::env;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::BufWriter;
+
+use impl_cli::wav::{quantize, write_wav, WavFormat};
+use memmap::MmapOptions;
+use midi_defs::message::MidiMessage;
+use midi_defs::smf::{Smf, SmfEvent};
+use parser_sf2::Sf2SoundFont;
+use synth_sf2::{Synth, SynthError, SynthInterpolation, CHANNEL_COUNT};
+
+/// Upper limit of the release and effect tail rendered after the last event,
+/// in seconds.
+const MAX_TAIL_LENGTH: f64 = 10.0;
+
+fn program_change(synth: &mut Synth, channel: usize, program: u8) -> Result<(), Box<dyn Error>> {
+    match synth.program_change(channel, program) {
+        Err(SynthError::PresetNotFound { .. }) => {
+            eprintln!(
+                "Warning: no preset found for program {} on channel {}",
+                program,
+                channel + 1,
+            );
+            Ok(())
+        }
+        result => Ok(result?),
+    }
+}
+
+/// Removes an option and its value from the arguments, returning the value
+/// if the option was present.
+fn take_option(args: &mut Vec<String>, option: &str) -> Result<Option<String>, Box<dyn Error>> {
+    let Some(position) = args.iter().position(|arg| arg == option) else {
+        return Ok(None);
+    };
+
+    if position + 1 >= args.len() {
+        return Err(format!("No argument for {option:}").into());
+    }
+
+    Ok(args.drain(position..=position + 1).nth(1))
+}
+
+/// Removes a flag from the arguments, returning whether it was present.
+fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    let length = args.len();
+    args.retain(|arg| arg != flag);
+    args.len() != length
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = env::args().collect::<Vec<_>>();
+
+    let sample_rate = match take_option(&mut args, "--sample-rate")? {
+        Some(sample_rate) => sample_rate
+            .parse::<u32>()
+            .map_err(|_| format!("Invalid sample rate argument: {sample_rate:}"))?,
+        None => 44100,
+    };
+
+    let interpolation = match take_option(&mut args, "--interpolation")? {
+        Some(interpolation) => interpolation
+            .parse::<SynthInterpolation>()
+            .map_err(|_| format!("Invalid interpolation argument: {interpolation:}"))?,
+        None => SynthInterpolation::default(),
+    };
+
+    let is_dry = take_flag(&mut args, "--dry");
+    let is_reverb_disabled = take_flag(&mut args, "--no-reverb") || is_dry;
+    let is_chorus_disabled = take_flag(&mut args, "--no-chorus") || is_dry;
+
+    let sf2_path = args.get(1).ok_or("No input SoundFont argument")?;
+    let midi_path = args.get(2).ok_or("No input MIDI file argument")?;
+    let wav_path = args.get(3).ok_or("No output WAV file argument")?;
+
+    let sf2_file = File::open(sf2_path)
+        .map_err(|error| format!("Failed to open input SoundFont {sf2_path:}: {error:}"))?;
+    let sf2_mmap: &[u8] = unsafe {
+        &MmapOptions::new()
+            .map(&sf2_file)
+            .map_err(|error| format!("Failed to mmap input SoundFont {sf2_path:}: {error:}"))?
+    };
+
+    let sf2_soundfont = Sf2SoundFont::new(sf2_mmap)?;
+    let midi_data = fs::read(midi_path)
+        .map_err(|error| format!("Failed to read input MIDI file {midi_path:}: {error:}"))?;
+    let midi_file = Smf::new(&midi_data)?;
+
+    let mut synth = Synth::new(&sf2_soundfont, sample_rate);
+    synth.set_interpolation(interpolation);
+    synth.set_reverb_enabled(!is_reverb_disabled);
+    synth.set_chorus_enabled(!is_chorus_disabled);
+
+    for channel in 0..CHANNEL_COUNT {
+        program_change(&mut synth, channel, 0)?;
+    }
+
+    let mut output = Vec::new();
+
+    let mut render_until = |synth: &mut Synth, time: f64| -> Result<(), Box<dyn Error>> {
+        if !time.is_finite() {
+            return Err(format!("Invalid event time {time:}").into());
+        }
+
+        let frame_count = (time * sample_rate as f64).round() as usize;
+        let start = output.len();
+
+        if frame_count * 2 > start {
+            output.resize(frame_count * 2, 0.0);
+            synth.render(&mut output[start..]);
+        }
+
+        Ok(())
+    };
+
+    let mut end_time = 0.0;
+
+    for timed_event in midi_file.events() {
+        render_until(&mut synth, timed_event.seconds)?;
+        end_time = timed_event.seconds;
+
+        let SmfEvent::Midi(message) = timed_event.event else {
+            continue;
+        };
+
+        match *message {
+            MidiMessage::ProgramChange { channel, program } => {
+                program_change(&mut synth, channel as usize, program)?;
+            }
+            _ => synth.process_message(message)?,
+        }
+    }
+
+    // Let the released notes and the effects ring out.
+    let mut tail_time = end_time;
+
+    while synth.is_active() && (tail_time < end_time + MAX_TAIL_LENGTH) {
+        tail_time += 0.1;
+        render_until(&mut synth, tail_time)?;
+    }
+
+    let wav_format = WavFormat {
+        channel_count: 2,
+        sample_rate,
+        bits_per_sample: 16,
+    };
+
+    let wav_file = BufWriter::new(File::create(wav_path)?);
+    write_wav(wav_file, &wav_format, &quantize(&output, 16), None)?;
+
+    Ok(())
+}
@@ -0,0 +1,221 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use impl_cli::json::Json;
+use parser_riff::RiffParseMode;
+use parser_sf2::{Sf2Severity, Sf2SoundFont};
+
+const EXIT_CLEAN: u8 = 0;
+const EXIT_WARNINGS: u8 = 1;
+const EXIT_ERRORS: u8 = 2;
+/// Invalid arguments or unreadable inputs, nothing got validated.
+const EXIT_USAGE: u8 = 3;
+
+const USAGE: &str = "Usage: sf2_validate [--format text|json] <file or directory>...";
+
+struct Diagnostic {
+    severity: Sf2Severity,
+    offset: Option<usize>,
+    message: String,
+}
+
+impl Diagnostic {
+    fn error(message: String) -> Diagnostic {
+        Diagnostic {
+            severity: Sf2Severity::Error,
+            offset: None,
+            message,
+        }
+    }
+}
+
+/// Expands directories into the SoundFont files found within, recursively.
+fn collect_files(path: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    if !path.is_dir() {
+        files.push(path.to_owned());
+        return Ok(());
+    }
+
+    let mut entries = fs::read_dir(path)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    entries.sort();
+
+    for entry in entries {
+        let is_sf2 = entry
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("sf2"));
+
+        if entry.is_dir() || is_sf2 {
+            collect_files(&entry, files)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_file(path: &Path) -> Vec<Diagnostic> {
+    let buffer = match fs::read(path) {
+        Ok(buffer) => buffer,
+        Err(err) => return vec![Diagnostic::error(err.to_string())],
+    };
+
+    let (soundfont, warnings) = match Sf2SoundFont::with_mode(&buffer, RiffParseMode::Lenient) {
+        Ok(result) => result,
+        Err(err) => return vec![Diagnostic::error(err.to_string())],
+    };
+
+    let riff_diagnostics = warnings.into_iter().map(|warning| Diagnostic {
+        severity: Sf2Severity::Warning,
+        offset: Some(warning.offset()),
+        message: warning.to_string(),
+    });
+
+    let sf2_diagnostics = soundfont
+        .validate()
+        .into_iter()
+        .map(|diagnostic| Diagnostic {
+            severity: diagnostic.severity(),
+            offset: diagnostic.offset,
+            message: diagnostic.violation.to_string(),
+        });
+
+    riff_diagnostics.chain(sf2_diagnostics).collect()
+}
+
+fn count_diagnostics(reports: &[(PathBuf, Vec<Diagnostic>)], severity: Sf2Severity) -> usize {
+    reports
+        .iter()
+        .flat_map(|(_, diagnostics)| diagnostics)
+        .filter(|diagnostic| diagnostic.severity == severity)
+        .count()
+}
+
+fn report_json(reports: &[(PathBuf, Vec<Diagnostic>)]) -> Json {
+    Json::object([
+        (
+            "error_count",
+            count_diagnostics(reports, Sf2Severity::Error).into(),
+        ),
+        (
+            "warning_count",
+            count_diagnostics(reports, Sf2Severity::Warning).into(),
+        ),
+        (
+            "files",
+            Json::array(reports.iter().map(|(path, diagnostics)| {
+                Json::object([
+                    ("file", path.display().to_string().into()),
+                    (
+                        "diagnostics",
+                        Json::array(diagnostics.iter().map(|diagnostic| {
+                            Json::object([
+                                ("severity", diagnostic.severity.to_string().into()),
+                                ("offset", diagnostic.offset.into()),
+                                ("message", diagnostic.message.as_str().into()),
+                            ])
+                        })),
+                    ),
+                ])
+            })),
+        ),
+    ])
+}
+
+fn print_report(reports: &[(PathBuf, Vec<Diagnostic>)]) {
+    for (path, diagnostics) in reports {
+        for diagnostic in diagnostics {
+            match diagnostic.offset {
+                Some(offset) => println!(
+                    "{}: {} at {:#010X}: {}",
+                    path.display(),
+                    diagnostic.severity,
+                    offset,
+                    diagnostic.message,
+                ),
+                None => println!(
+                    "{}: {}: {}",
+                    path.display(),
+                    diagnostic.severity,
+                    diagnostic.message,
+                ),
+            }
+        }
+    }
+
+    println!(
+        "{} files checked, {} errors, {} warnings",
+        reports.len(),
+        count_diagnostics(reports, Sf2Severity::Error),
+        count_diagnostics(reports, Sf2Severity::Warning),
+    );
+}
+
+fn usage_error(message: &str) -> ExitCode {
+    eprintln!("Error: {message:}");
+    eprintln!("{USAGE:}");
+    ExitCode::from(EXIT_USAGE)
+}
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1).collect::<Vec<_>>();
+
+    let json_output = match args.iter().position(|arg| arg == "--format") {
+        Some(position) => {
+            let json_output = match args.get(position + 1).map(String::as_str) {
+                Some("json") => true,
+                Some("text") => false,
+                _ => return usage_error("Invalid format argument, expected `text` or `json`"),
+            };
+
+            args.drain(position..=position + 1);
+            json_output
+        }
+        None => false,
+    };
+
+    if args.is_empty() {
+        return usage_error("No input file or directory argument");
+    }
+
+    let mut files = Vec::new();
+
+    for arg in &args {
+        if let Err(err) = collect_files(Path::new(arg), &mut files) {
+            return usage_error(&format!("Failed to read input directory {arg:}: {err:}"));
+        }
+    }
+
+    if files.is_empty() {
+        return usage_error("No SoundFont files found in the input directories");
+    }
+
+    let reports = files
+        .into_iter()
+        .map(|path| {
+            let diagnostics = validate_file(&path);
+            (path, diagnostics)
+        })
+        .collect::<Vec<_>>();
+
+    if json_output {
+        println!("{}", report_json(&reports));
+    } else {
+        print_report(&reports);
+    }
+
+    let worst_severity = reports
+        .iter()
+        .flat_map(|(_, diagnostics)| diagnostics)
+        .map(|diagnostic| diagnostic.severity)
+        .max();
+
+    ExitCode::from(match worst_severity {
+        None => EXIT_CLEAN,
+        Some(Sf2Severity::Warning) => EXIT_WARNINGS,
+        Some(Sf2Severity::Error) => EXIT_ERRORS,
+    })
+}
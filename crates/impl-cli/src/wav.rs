@@ -0,0 +1,143 @@
+use std::io::{Seek, Write};
+
+use parser_riff::{RiffResult, RiffWriter};
+
+/// Format of PCM WAV files, the sample points are stored as little-endian
+/// signed integers (unsigned for 8-bit files), interleaved by channel.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct WavFormat {
+    pub channel_count: u16,
+    pub sample_rate: u32,
+    pub bits_per_sample: u16,
+}
+
+impl WavFormat {
+    fn block_align(&self) -> u16 {
+        self.channel_count * self.bits_per_sample.div_ceil(8)
+    }
+
+    fn fmt_chunk_data(&self) -> Vec<u8> {
+        const WAVE_FORMAT_PCM: u16 = 0x0001;
+
+        let block_align = self.block_align();
+        let byte_rate = self.sample_rate * block_align as u32;
+
+        [
+            &WAVE_FORMAT_PCM.to_le_bytes()[..],
+            &self.channel_count.to_le_bytes(),
+            &self.sample_rate.to_le_bytes(),
+            &byte_rate.to_le_bytes(),
+            &block_align.to_le_bytes(),
+            &self.bits_per_sample.to_le_bytes(),
+        ]
+        .concat()
+    }
+}
+
+/// Forward loop of a `smpl` chunk, both ends being inclusive sample frames.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct WavSampleLoop {
+    pub start: u32,
+    pub end: u32,
+}
+
+/// Sampler information of the `smpl` chunk: the pitch of the recording as
+/// a MIDI key number plus a fraction of a semitone upwards, in cents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WavSampler {
+    pub unity_note: u8,
+    pub pitch_fraction_cents: u8,
+    pub sample_loops: Vec<WavSampleLoop>,
+}
+
+impl WavSampler {
+    fn smpl_chunk_data(&self, sample_rate: u32) -> Vec<u8> {
+        let sample_period = (1_000_000_000.0 / sample_rate as f64).round() as u32;
+        let pitch_fraction = (self.pitch_fraction_cents as u64 * (1 << 32) / 100) as u32;
+
+        let mut chunk_data = [
+            0,                              // Manufacturer
+            0,                              // Product
+            sample_period,                  // Nanoseconds per sample frame
+            self.unity_note as u32,         // MIDI unity note
+            pitch_fraction,                 // MIDI pitch fraction
+            0,                              // SMPTE format
+            0,                              // SMPTE offset
+            self.sample_loops.len() as u32, // Number of sample loops
+            0,                              // Sampler data size
+        ]
+        .iter()
+        .flat_map(|field| field.to_le_bytes())
+        .collect::<Vec<u8>>();
+
+        for (cue_point_id, sample_loop) in self.sample_loops.iter().enumerate() {
+            chunk_data.extend(
+                [
+                    cue_point_id as u32, // Cue point ID
+                    0,                   // Loop type (forward)
+                    sample_loop.start,   // Start
+                    sample_loop.end,     // End
+                    0,                   // Fraction
+                    0,                   // Play count (infinite)
+                ]
+                .iter()
+                .flat_map(|field| field.to_le_bytes()),
+            );
+        }
+
+        chunk_data
+    }
+}
+
+/// Writes a WAV file of the given sample points, each holding a value in the
+/// range of `bits_per_sample`, with an optional `smpl` chunk.
+pub fn write_wav<W: Write + Seek>(
+    writer: W,
+    wav_format: &WavFormat,
+    samples: &[i32],
+    sampler: Option<&WavSampler>,
+) -> RiffResult<W> {
+    let bytes_per_sample = wav_format.bits_per_sample.div_ceil(8) as usize;
+
+    let mut riff_writer = RiffWriter::new(writer);
+    riff_writer.begin_container("RIFF", "WAVE")?;
+    riff_writer.write_chunk("fmt ", &wav_format.fmt_chunk_data())?;
+
+    if let Some(sampler) = sampler {
+        riff_writer.write_chunk("smpl", &sampler.smpl_chunk_data(wav_format.sample_rate))?;
+    }
+
+    riff_writer.begin_chunk("data")?;
+    for sample_chunk in samples.chunks(4096) {
+        let chunk_data = sample_chunk
+            .iter()
+            .flat_map(|&sample| {
+                let sample = if bytes_per_sample == 1 {
+                    sample + 128
+                } else {
+                    sample
+                };
+                sample.to_le_bytes().into_iter().take(bytes_per_sample)
+            })
+            .collect::<Vec<u8>>();
+
+        riff_writer.write_data(&chunk_data)?;
+    }
+    riff_writer.end_chunk()?;
+
+    riff_writer.end_chunk()?;
+    riff_writer.finish()
+}
+
+/// Converts floating point sample points to integers of the given bit depth,
+/// clipping them to the -1.0..1.0 range.
+pub fn quantize(samples: &[f32], bits_per_sample: u16) -> Vec<i32> {
+    let scale = (1i64 << (bits_per_sample - 1)) as f32;
+
+    samples
+        .iter()
+        .map(|&sample| {
+            ((sample * scale).round() as i64).clamp(-(scale as i64), scale as i64 - 1) as i32
+        })
+        .collect()
+}
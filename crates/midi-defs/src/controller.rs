@@ -0,0 +1,65 @@
+//! Standard MIDI controller numbers.
+
+pub const BANK_SELECT_MSB: u8 = 0;
+pub const MODULATION_WHEEL_MSB: u8 = 1;
+pub const BREATH_CONTROLLER_MSB: u8 = 2;
+pub const FOOT_CONTROLLER_MSB: u8 = 4;
+pub const PORTAMENTO_TIME_MSB: u8 = 5;
+pub const DATA_ENTRY_MSB: u8 = 6;
+pub const CHANNEL_VOLUME_MSB: u8 = 7;
+pub const BALANCE_MSB: u8 = 8;
+pub const PAN_MSB: u8 = 10;
+pub const EXPRESSION_MSB: u8 = 11;
+
+pub const BANK_SELECT_LSB: u8 = 32;
+pub const MODULATION_WHEEL_LSB: u8 = 33;
+pub const BREATH_CONTROLLER_LSB: u8 = 34;
+pub const FOOT_CONTROLLER_LSB: u8 = 36;
+pub const PORTAMENTO_TIME_LSB: u8 = 37;
+pub const DATA_ENTRY_LSB: u8 = 38;
+pub const CHANNEL_VOLUME_LSB: u8 = 39;
+pub const BALANCE_LSB: u8 = 40;
+pub const PAN_LSB: u8 = 42;
+pub const EXPRESSION_LSB: u8 = 43;
+
+pub const SUSTAIN: u8 = 64;
+pub const PORTAMENTO: u8 = 65;
+pub const SOSTENUTO: u8 = 66;
+pub const SOFT_PEDAL: u8 = 67;
+pub const LEGATO_FOOTSWITCH: u8 = 68;
+pub const HOLD_2: u8 = 69;
+
+pub const SOUND_CONTROLLER_1: u8 = 70;
+pub const RESONANCE: u8 = 71;
+pub const RELEASE_TIME: u8 = 72;
+pub const ATTACK_TIME: u8 = 73;
+pub const BRIGHTNESS: u8 = 74;
+pub const DECAY_TIME: u8 = 75;
+pub const VIBRATO_RATE: u8 = 76;
+pub const VIBRATO_DEPTH: u8 = 77;
+pub const VIBRATO_DELAY: u8 = 78;
+
+pub const PORTAMENTO_CONTROL: u8 = 84;
+
+pub const REVERB_SEND_LEVEL: u8 = 91;
+pub const TREMOLO_DEPTH: u8 = 92;
+pub const CHORUS_SEND_LEVEL: u8 = 93;
+pub const CELESTE_DEPTH: u8 = 94;
+pub const PHASER_DEPTH: u8 = 95;
+
+pub const DATA_INCREMENT: u8 = 96;
+pub const DATA_DECREMENT: u8 = 97;
+pub const NRPN_LSB: u8 = 98;
+pub const NRPN_MSB: u8 = 99;
+pub const RPN_LSB: u8 = 100;
+pub const RPN_MSB: u8 = 101;
+
+// Channel mode messages
+pub const ALL_SOUND_OFF: u8 = 120;
+pub const RESET_ALL_CONTROLLERS: u8 = 121;
+pub const LOCAL_CONTROL: u8 = 122;
+pub const ALL_NOTES_OFF: u8 = 123;
+pub const OMNI_MODE_OFF: u8 = 124;
+pub const OMNI_MODE_ON: u8 = 125;
+pub const MONO_MODE_ON: u8 = 126;
+pub const POLY_MODE_ON: u8 = 127;
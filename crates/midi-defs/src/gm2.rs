@@ -0,0 +1,274 @@
+// General MIDI 2 specification, GM2 sound set
+
+/// General MIDI Level 2 sound set, keyed by bank MSB, bank LSB and program.
+///
+/// Melodic sounds are in bank MSB 121 with the variations selected by the
+/// bank LSB, drum sets are in bank MSB 120.
+#[rustfmt::skip]
+pub const GENERAL_MIDI_2: &[((u8, u8, u8), &str)] = &[
+    ((121,   0,   0), "Acoustic Grand Piano"             ),
+    ((121,   1,   0), "Acoustic Grand Piano (wide)"      ),
+    ((121,   2,   0), "Acoustic Grand Piano (dark)"      ),
+    ((121,   0,   1), "Bright Acoustic Piano"            ),
+    ((121,   1,   1), "Bright Acoustic Piano (wide)"     ),
+    ((121,   0,   2), "Electric Grand Piano"             ),
+    ((121,   1,   2), "Electric Grand Piano (wide)"      ),
+    ((121,   0,   3), "Honky-tonk Piano"                 ),
+    ((121,   1,   3), "Honky-tonk Piano (wide)"          ),
+    ((121,   0,   4), "Electric Piano 1"                 ),
+    ((121,   1,   4), "Detuned Electric Piano 1"         ),
+    ((121,   2,   4), "Electric Piano 1 (velocity mix)"  ),
+    ((121,   3,   4), "60's Electric Piano"              ),
+    ((121,   0,   5), "Electric Piano 2"                 ),
+    ((121,   1,   5), "Detuned Electric Piano 2"         ),
+    ((121,   2,   5), "Electric Piano 2 (velocity mix)"  ),
+    ((121,   3,   5), "EP Legend"                        ),
+    ((121,   4,   5), "EP Phase"                         ),
+    ((121,   0,   6), "Harpsichord"                      ),
+    ((121,   1,   6), "Harpsichord (octave mix)"         ),
+    ((121,   2,   6), "Harpsichord (wide)"               ),
+    ((121,   3,   6), "Harpsichord (with key off)"       ),
+    ((121,   0,   7), "Clavi"                            ),
+    ((121,   1,   7), "Pulse Clavi"                      ),
+    ((121,   0,   8), "Celesta"                          ),
+    ((121,   0,   9), "Glockenspiel"                     ),
+    ((121,   0,  10), "Music Box"                        ),
+    ((121,   0,  11), "Vibraphone"                       ),
+    ((121,   1,  11), "Vibraphone (wet)"                 ),
+    ((121,   0,  12), "Marimba"                          ),
+    ((121,   1,  12), "Marimba (wide)"                   ),
+    ((121,   0,  13), "Xylophone"                        ),
+    ((121,   0,  14), "Tubular Bells"                    ),
+    ((121,   1,  14), "Church Bell"                      ),
+    ((121,   2,  14), "Carillon"                         ),
+    ((121,   0,  15), "Dulcimer"                         ),
+    ((121,   0,  16), "Drawbar Organ"                    ),
+    ((121,   1,  16), "Detuned Drawbar Organ"            ),
+    ((121,   2,  16), "Italian 60's Organ"               ),
+    ((121,   3,  16), "Drawbar Organ 2"                  ),
+    ((121,   0,  17), "Percussive Organ"                 ),
+    ((121,   1,  17), "Detuned Percussive Organ"         ),
+    ((121,   2,  17), "Percussive Organ 2"               ),
+    ((121,   0,  18), "Rock Organ"                       ),
+    ((121,   0,  19), "Church Organ"                     ),
+    ((121,   1,  19), "Church Organ (octave mix)"        ),
+    ((121,   2,  19), "Detuned Church Organ"             ),
+    ((121,   0,  20), "Reed Organ"                       ),
+    ((121,   1,  20), "Puff Organ"                       ),
+    ((121,   0,  21), "Accordion"                        ),
+    ((121,   1,  21), "Accordion 2"                      ),
+    ((121,   0,  22), "Harmonica"                        ),
+    ((121,   0,  23), "Tango Accordion"                  ),
+    ((121,   0,  24), "Acoustic Guitar (nylon)"          ),
+    ((121,   1,  24), "Ukulele"                          ),
+    ((121,   2,  24), "Acoustic Guitar (nylon + key off)"),
+    ((121,   3,  24), "Acoustic Guitar (nylon 2)"        ),
+    ((121,   0,  25), "Acoustic Guitar (steel)"          ),
+    ((121,   1,  25), "12-Strings Guitar"                ),
+    ((121,   2,  25), "Mandolin"                         ),
+    ((121,   3,  25), "Steel Guitar with Body Sound"     ),
+    ((121,   0,  26), "Electric Guitar (jazz)"           ),
+    ((121,   1,  26), "Electric Guitar (pedal steel)"    ),
+    ((121,   0,  27), "Electric Guitar (clean)"          ),
+    ((121,   1,  27), "Electric Guitar (detuned clean)"  ),
+    ((121,   2,  27), "Mid Tone Guitar"                  ),
+    ((121,   0,  28), "Electric Guitar (muted)"          ),
+    ((121,   1,  28), "Electric Guitar (funky cutting)"  ),
+    ((121,   2,  28), "Electric Guitar (muted velo-sw)"  ),
+    ((121,   3,  28), "Jazz Man"                         ),
+    ((121,   0,  29), "Overdriven Guitar"                ),
+    ((121,   1,  29), "Guitar Pinch"                     ),
+    ((121,   0,  30), "Distortion Guitar"                ),
+    ((121,   1,  30), "Distortion Guitar (with feedback)"),
+    ((121,   2,  30), "Distorted Rhythm Guitar"          ),
+    ((121,   0,  31), "Guitar Harmonics"                 ),
+    ((121,   1,  31), "Guitar Feedback"                  ),
+    ((121,   0,  32), "Acoustic Bass"                    ),
+    ((121,   0,  33), "Electric Bass (finger)"           ),
+    ((121,   1,  33), "Finger Slap Bass"                 ),
+    ((121,   0,  34), "Electric Bass (pick)"             ),
+    ((121,   0,  35), "Fretless Bass"                    ),
+    ((121,   0,  36), "Slap Bass 1"                      ),
+    ((121,   0,  37), "Slap Bass 2"                      ),
+    ((121,   0,  38), "Synth Bass 1"                     ),
+    ((121,   1,  38), "Synth Bass (warm)"                ),
+    ((121,   2,  38), "Synth Bass 3 (resonance)"         ),
+    ((121,   3,  38), "Clavi Bass"                       ),
+    ((121,   4,  38), "Hammer"                           ),
+    ((121,   0,  39), "Synth Bass 2"                     ),
+    ((121,   1,  39), "Synth Bass 4 (attack)"            ),
+    ((121,   2,  39), "Synth Bass (rubber)"              ),
+    ((121,   3,  39), "Attack Pulse"                     ),
+    ((121,   0,  40), "Violin"                           ),
+    ((121,   1,  40), "Violin (slow attack)"             ),
+    ((121,   0,  41), "Viola"                            ),
+    ((121,   0,  42), "Cello"                            ),
+    ((121,   0,  43), "Contrabass"                       ),
+    ((121,   0,  44), "Tremolo Strings"                  ),
+    ((121,   0,  45), "Pizzicato Strings"                ),
+    ((121,   0,  46), "Orchestral Harp"                  ),
+    ((121,   1,  46), "Yang Chin"                        ),
+    ((121,   0,  47), "Timpani"                          ),
+    ((121,   0,  48), "String Ensembles 1"               ),
+    ((121,   1,  48), "Strings & Brass"                  ),
+    ((121,   2,  48), "60s Strings"                      ),
+    ((121,   0,  49), "String Ensembles 2"               ),
+    ((121,   0,  50), "Synth Strings 1"                  ),
+    ((121,   1,  50), "Synth Strings 3"                  ),
+    ((121,   0,  51), "Synth Strings 2"                  ),
+    ((121,   0,  52), "Choir Aahs"                       ),
+    ((121,   1,  52), "Choir Aahs 2"                     ),
+    ((121,   0,  53), "Voice Oohs"                       ),
+    ((121,   1,  53), "Humming"                          ),
+    ((121,   0,  54), "Synth Voice"                      ),
+    ((121,   1,  54), "Analog Voice"                     ),
+    ((121,   0,  55), "Orchestra Hit"                    ),
+    ((121,   1,  55), "Bass Hit Plus"                    ),
+    ((121,   2,  55), "6th Hit"                          ),
+    ((121,   3,  55), "Euro Hit"                         ),
+    ((121,   0,  56), "Trumpet"                          ),
+    ((121,   1,  56), "Dark Trumpet Soft"                ),
+    ((121,   0,  57), "Trombone"                         ),
+    ((121,   1,  57), "Trombone 2"                       ),
+    ((121,   2,  57), "Bright Trombone"                  ),
+    ((121,   0,  58), "Tuba"                             ),
+    ((121,   0,  59), "Muted Trumpet"                    ),
+    ((121,   1,  59), "Muted Trumpet 2"                  ),
+    ((121,   0,  60), "French Horn"                      ),
+    ((121,   1,  60), "French Horn 2 (warm)"             ),
+    ((121,   0,  61), "Brass Section"                    ),
+    ((121,   1,  61), "Brass Section 2 (octave mix)"     ),
+    ((121,   0,  62), "Synth Brass 1"                    ),
+    ((121,   1,  62), "Synth Brass 3"                    ),
+    ((121,   2,  62), "Analog Synth Brass 1"             ),
+    ((121,   3,  62), "Jump Brass"                       ),
+    ((121,   0,  63), "Synth Brass 2"                    ),
+    ((121,   1,  63), "Synth Brass 4"                    ),
+    ((121,   2,  63), "Analog Synth Brass 2"             ),
+    ((121,   0,  64), "Soprano Sax"                      ),
+    ((121,   0,  65), "Alto Sax"                         ),
+    ((121,   0,  66), "Tenor Sax"                        ),
+    ((121,   0,  67), "Baritone Sax"                     ),
+    ((121,   0,  68), "Oboe"                             ),
+    ((121,   0,  69), "English Horn"                     ),
+    ((121,   0,  70), "Bassoon"                          ),
+    ((121,   0,  71), "Clarinet"                         ),
+    ((121,   0,  72), "Piccolo"                          ),
+    ((121,   0,  73), "Flute"                            ),
+    ((121,   0,  74), "Recorder"                         ),
+    ((121,   0,  75), "Pan Flute"                        ),
+    ((121,   0,  76), "Blown Bottle"                     ),
+    ((121,   0,  77), "Shakuhachi"                       ),
+    ((121,   0,  78), "Whistle"                          ),
+    ((121,   0,  79), "Ocarina"                          ),
+    ((121,   0,  80), "Lead 1 (square)"                  ),
+    ((121,   1,  80), "Lead 1a (square 2)"               ),
+    ((121,   2,  80), "Lead 1b (sine)"                   ),
+    ((121,   0,  81), "Lead 2 (sawtooth)"                ),
+    ((121,   1,  81), "Lead 2a (sawtooth 2)"             ),
+    ((121,   2,  81), "Lead 2b (saw + pulse)"            ),
+    ((121,   3,  81), "Lead 2c (double sawtooth)"        ),
+    ((121,   4,  81), "Lead 2d (sequenced analog)"       ),
+    ((121,   0,  82), "Lead 3 (calliope)"                ),
+    ((121,   0,  83), "Lead 4 (chiff)"                   ),
+    ((121,   0,  84), "Lead 5 (charang)"                 ),
+    ((121,   1,  84), "Lead 5a (wire lead)"              ),
+    ((121,   0,  85), "Lead 6 (voice)"                   ),
+    ((121,   0,  86), "Lead 7 (fifths)"                  ),
+    ((121,   0,  87), "Lead 8 (bass + lead)"             ),
+    ((121,   1,  87), "Lead 8a (soft wrl)"               ),
+    ((121,   0,  88), "Pad 1 (new age)"                  ),
+    ((121,   0,  89), "Pad 2 (warm)"                     ),
+    ((121,   1,  89), "Pad 2a (sine pad)"                ),
+    ((121,   0,  90), "Pad 3 (polysynth)"                ),
+    ((121,   0,  91), "Pad 4 (choir)"                    ),
+    ((121,   1,  91), "Pad 4a (itopia)"                  ),
+    ((121,   0,  92), "Pad 5 (bowed)"                    ),
+    ((121,   0,  93), "Pad 6 (metallic)"                 ),
+    ((121,   0,  94), "Pad 7 (halo)"                     ),
+    ((121,   0,  95), "Pad 8 (sweep)"                    ),
+    ((121,   0,  96), "FX 1 (rain)"                      ),
+    ((121,   0,  97), "FX 2 (soundtrack)"                ),
+    ((121,   0,  98), "FX 3 (crystal)"                   ),
+    ((121,   1,  98), "FX 3a (synth mallet)"             ),
+    ((121,   0,  99), "FX 4 (atmosphere)"                ),
+    ((121,   0, 100), "FX 5 (brightness)"                ),
+    ((121,   0, 101), "FX 6 (goblins)"                   ),
+    ((121,   0, 102), "FX 7 (echoes)"                    ),
+    ((121,   1, 102), "FX 7a (echo bell)"                ),
+    ((121,   2, 102), "FX 7b (echo pan)"                 ),
+    ((121,   0, 103), "FX 8 (sci-fi)"                    ),
+    ((121,   0, 104), "Sitar"                            ),
+    ((121,   1, 104), "Sitar 2 (bend)"                   ),
+    ((121,   0, 105), "Banjo"                            ),
+    ((121,   0, 106), "Shamisen"                         ),
+    ((121,   0, 107), "Koto"                             ),
+    ((121,   1, 107), "Taisho Koto"                      ),
+    ((121,   0, 108), "Kalimba"                          ),
+    ((121,   0, 109), "Bag pipe"                         ),
+    ((121,   0, 110), "Fiddle"                           ),
+    ((121,   0, 111), "Shanai"                           ),
+    ((121,   0, 112), "Tinkle Bell"                      ),
+    ((121,   0, 113), "Agogo"                            ),
+    ((121,   0, 114), "Steel Drums"                      ),
+    ((121,   0, 115), "Woodblock"                        ),
+    ((121,   1, 115), "Castanets"                        ),
+    ((121,   0, 116), "Taiko Drum"                       ),
+    ((121,   1, 116), "Concert Bass Drum"                ),
+    ((121,   0, 117), "Melodic Tom"                      ),
+    ((121,   1, 117), "Melodic Tom 2 (power)"            ),
+    ((121,   0, 118), "Synth Drum"                       ),
+    ((121,   1, 118), "Rhythm Box Tom"                   ),
+    ((121,   2, 118), "Electric Drum"                    ),
+    ((121,   0, 119), "Reverse Cymbal"                   ),
+    ((121,   0, 120), "Guitar Fret Noise"                ),
+    ((121,   1, 120), "Guitar Cutting Noise"             ),
+    ((121,   2, 120), "Acoustic Bass String Slap"        ),
+    ((121,   0, 121), "Breath Noise"                     ),
+    ((121,   1, 121), "Flute Key Click"                  ),
+    ((121,   0, 122), "Seashore"                         ),
+    ((121,   1, 122), "Rain"                             ),
+    ((121,   2, 122), "Thunder"                          ),
+    ((121,   3, 122), "Wind"                             ),
+    ((121,   4, 122), "Stream"                           ),
+    ((121,   5, 122), "Bubble"                           ),
+    ((121,   0, 123), "Bird Tweet"                       ),
+    ((121,   1, 123), "Dog"                              ),
+    ((121,   2, 123), "Horse Gallop"                     ),
+    ((121,   3, 123), "Bird Tweet 2"                     ),
+    ((121,   0, 124), "Telephone Ring"                   ),
+    ((121,   1, 124), "Telephone Ring 2"                 ),
+    ((121,   2, 124), "Door Creaking"                    ),
+    ((121,   3, 124), "Door"                             ),
+    ((121,   4, 124), "Scratch"                          ),
+    ((121,   5, 124), "Wind Chime"                       ),
+    ((121,   0, 125), "Helicopter"                       ),
+    ((121,   1, 125), "Car Engine"                       ),
+    ((121,   2, 125), "Car Stop"                         ),
+    ((121,   3, 125), "Car Pass"                         ),
+    ((121,   4, 125), "Car Crash"                        ),
+    ((121,   5, 125), "Siren"                            ),
+    ((121,   6, 125), "Train"                            ),
+    ((121,   7, 125), "Jetplane"                         ),
+    ((121,   8, 125), "Starship"                         ),
+    ((121,   9, 125), "Burst Noise"                      ),
+    ((121,   0, 126), "Applause"                         ),
+    ((121,   1, 126), "Laughing"                         ),
+    ((121,   2, 126), "Screaming"                        ),
+    ((121,   3, 126), "Punch"                            ),
+    ((121,   4, 126), "Heart Beat"                       ),
+    ((121,   5, 126), "Footsteps"                        ),
+    ((121,   0, 127), "Gunshot"                          ),
+    ((121,   1, 127), "Machine Gun"                      ),
+    ((121,   2, 127), "Lasergun"                         ),
+    ((121,   3, 127), "Explosion"                        ),
+    ((120,   0,   0), "Standard Set"                     ),
+    ((120,   0,   8), "Room Set"                         ),
+    ((120,   0,  16), "Power Set"                        ),
+    ((120,   0,  24), "Electronic Set"                   ),
+    ((120,   0,  25), "Analog Set"                       ),
+    ((120,   0,  32), "Jazz Set"                         ),
+    ((120,   0,  40), "Brush Set"                        ),
+    ((120,   0,  48), "Orchestra Set"                    ),
+    ((120,   0,  56), "SFX Set"                          ),
+];
@@ -0,0 +1,249 @@
+// Roland SC-55 owner's manual, GS tone map
+
+/// Roland GS (SC-55) tone map, keyed by bank MSB, bank LSB and program.
+///
+/// Variation tones are selected by the bank MSB, the bank LSB is left at 0.
+#[rustfmt::skip]
+pub const ROLAND_GS: &[((u8, u8, u8), &str)] = &[
+    ((  0,   0,   0), "Piano 1"     ),
+    ((  8,   0,   0), "Piano 1w"    ),
+    (( 16,   0,   0), "Piano 1d"    ),
+    ((  0,   0,   1), "Piano 2"     ),
+    ((  8,   0,   1), "Piano 2w"    ),
+    ((  0,   0,   2), "Piano 3"     ),
+    ((  8,   0,   2), "Piano 3w"    ),
+    ((  0,   0,   3), "Honky-tonk"  ),
+    ((  8,   0,   3), "Honky-tonk w"),
+    ((  0,   0,   4), "E.Piano 1"   ),
+    ((  8,   0,   4), "Detuned EP 1"),
+    (( 16,   0,   4), "E.Piano 1w"  ),
+    (( 24,   0,   4), "60's E.Piano"),
+    ((  0,   0,   5), "E.Piano 2"   ),
+    ((  8,   0,   5), "Detuned EP 2"),
+    (( 16,   0,   5), "E.Piano 2w"  ),
+    ((  0,   0,   6), "Harpsichord" ),
+    ((  8,   0,   6), "Coupled Hps."),
+    (( 16,   0,   6), "Harpsi.w"    ),
+    (( 24,   0,   6), "Harpsi.o"    ),
+    ((  0,   0,   7), "Clav."       ),
+    ((  0,   0,   8), "Celesta"     ),
+    ((  0,   0,   9), "Glockenspiel"),
+    ((  0,   0,  10), "Music Box"   ),
+    ((  0,   0,  11), "Vibraphone"  ),
+    ((  8,   0,  11), "Vib.w"       ),
+    ((  0,   0,  12), "Marimba"     ),
+    ((  8,   0,  12), "Marimba w"   ),
+    ((  0,   0,  13), "Xylophone"   ),
+    ((  0,   0,  14), "Tubular-bell"),
+    ((  8,   0,  14), "Church Bell" ),
+    ((  9,   0,  14), "Carillon"    ),
+    ((  0,   0,  15), "Santur"      ),
+    ((  0,   0,  16), "Organ 1"     ),
+    ((  8,   0,  16), "Detuned Or.1"),
+    (( 16,   0,  16), "60's Organ 1"),
+    (( 32,   0,  16), "Organ 4"     ),
+    ((  0,   0,  17), "Organ 2"     ),
+    ((  8,   0,  17), "Detuned Or.2"),
+    (( 32,   0,  17), "Organ 5"     ),
+    ((  0,   0,  18), "Organ 3"     ),
+    ((  0,   0,  19), "Church Org.1"),
+    ((  8,   0,  19), "Church Org.2"),
+    (( 16,   0,  19), "Church Org.3"),
+    ((  0,   0,  20), "Reed Organ"  ),
+    ((  0,   0,  21), "Accordion Fr"),
+    ((  8,   0,  21), "Accordion It"),
+    ((  0,   0,  22), "Harmonica"   ),
+    ((  0,   0,  23), "Bandoneon"   ),
+    ((  0,   0,  24), "Nylon-str.Gt"),
+    ((  8,   0,  24), "Ukulele"     ),
+    (( 16,   0,  24), "Nylon Gt.o"  ),
+    (( 32,   0,  24), "Nylon Gt.2"  ),
+    ((  0,   0,  25), "Steel-str.Gt"),
+    ((  8,   0,  25), "12-str.Gt"   ),
+    (( 16,   0,  25), "Mandolin"    ),
+    ((  0,   0,  26), "Jazz Gt."    ),
+    ((  8,   0,  26), "Hawaiian Gt."),
+    ((  0,   0,  27), "Clean Gt."   ),
+    ((  8,   0,  27), "Chorus Gt."  ),
+    ((  0,   0,  28), "Muted Gt."   ),
+    ((  8,   0,  28), "Funk Gt."    ),
+    ((  0,   0,  29), "Overdrive Gt"),
+    ((  0,   0,  30), "DistortionGt"),
+    ((  8,   0,  30), "Feedback Gt."),
+    ((  0,   0,  31), "Gt.Harmonics"),
+    ((  8,   0,  31), "Gt. Feedback"),
+    ((  0,   0,  32), "Acoustic Bs."),
+    ((  0,   0,  33), "Fingered Bs."),
+    ((  0,   0,  34), "Picked Bs."  ),
+    ((  0,   0,  35), "Fretless Bs."),
+    ((  0,   0,  36), "Slap Bass 1" ),
+    ((  0,   0,  37), "Slap Bass 2" ),
+    ((  0,   0,  38), "Synth Bass 1"),
+    ((  1,   0,  38), "SynthBass101"),
+    ((  8,   0,  38), "Synth Bass 3"),
+    ((  0,   0,  39), "Synth Bass 2"),
+    ((  8,   0,  39), "Synth Bass 4"),
+    (( 16,   0,  39), "Rubber Bass" ),
+    ((  0,   0,  40), "Violin"      ),
+    ((  8,   0,  40), "Slow Violin" ),
+    ((  0,   0,  41), "Viola"       ),
+    ((  0,   0,  42), "Cello"       ),
+    ((  0,   0,  43), "Contrabass"  ),
+    ((  0,   0,  44), "Tremolo Str" ),
+    ((  0,   0,  45), "PizzicatoStr"),
+    ((  0,   0,  46), "Harp"        ),
+    ((  0,   0,  47), "Timpani"     ),
+    ((  0,   0,  48), "Strings"     ),
+    ((  8,   0,  48), "Orchestra"   ),
+    ((  0,   0,  49), "Slow Strings"),
+    ((  0,   0,  50), "Syn.Strings1"),
+    ((  8,   0,  50), "Syn.Strings3"),
+    ((  0,   0,  51), "Syn.Strings2"),
+    ((  0,   0,  52), "Choir Aahs"  ),
+    (( 32,   0,  52), "Choir Aahs 2"),
+    ((  0,   0,  53), "Voice Oohs"  ),
+    ((  0,   0,  54), "SynVox"      ),
+    ((  0,   0,  55), "OrchestraHit"),
+    ((  0,   0,  56), "Trumpet"     ),
+    ((  0,   0,  57), "Trombone"    ),
+    ((  1,   0,  57), "Trombone 2"  ),
+    ((  0,   0,  58), "Tuba"        ),
+    ((  0,   0,  59), "MutedTrumpet"),
+    ((  0,   0,  60), "French Horn" ),
+    ((  1,   0,  60), "French Horn2"),
+    ((  0,   0,  61), "Brass 1"     ),
+    ((  8,   0,  61), "Brass 2"     ),
+    ((  0,   0,  62), "Synth Brass1"),
+    ((  8,   0,  62), "Synth Brass3"),
+    (( 16,   0,  62), "AnalogBrass1"),
+    ((  0,   0,  63), "Synth Brass2"),
+    ((  8,   0,  63), "Synth Brass4"),
+    (( 16,   0,  63), "AnalogBrass2"),
+    ((  0,   0,  64), "Soprano Sax" ),
+    ((  0,   0,  65), "Alto Sax"    ),
+    ((  0,   0,  66), "Tenor Sax"   ),
+    ((  0,   0,  67), "Baritone Sax"),
+    ((  0,   0,  68), "Oboe"        ),
+    ((  0,   0,  69), "English Horn"),
+    ((  0,   0,  70), "Bassoon"     ),
+    ((  0,   0,  71), "Clarinet"    ),
+    ((  0,   0,  72), "Piccolo"     ),
+    ((  0,   0,  73), "Flute"       ),
+    ((  0,   0,  74), "Recorder"    ),
+    ((  0,   0,  75), "Pan Flute"   ),
+    ((  0,   0,  76), "Bottle Blow" ),
+    ((  0,   0,  77), "Shakuhachi"  ),
+    ((  0,   0,  78), "Whistle"     ),
+    ((  0,   0,  79), "Ocarina"     ),
+    ((  0,   0,  80), "Square Wave" ),
+    ((  1,   0,  80), "Square"      ),
+    ((  8,   0,  80), "Sine Wave"   ),
+    ((  0,   0,  81), "Saw Wave"    ),
+    ((  1,   0,  81), "Saw"         ),
+    ((  8,   0,  81), "Doctor Solo" ),
+    ((  0,   0,  82), "Syn.Calliope"),
+    ((  0,   0,  83), "Chiffer Lead"),
+    ((  0,   0,  84), "Charang"     ),
+    ((  0,   0,  85), "Solo Vox"    ),
+    ((  0,   0,  86), "5th Saw Wave"),
+    ((  0,   0,  87), "Bass & Lead" ),
+    ((  0,   0,  88), "Fantasia"    ),
+    ((  0,   0,  89), "Warm Pad"    ),
+    ((  0,   0,  90), "Polysynth"   ),
+    ((  0,   0,  91), "Space Voice" ),
+    ((  0,   0,  92), "Bowed Glass" ),
+    ((  0,   0,  93), "Metal Pad"   ),
+    ((  0,   0,  94), "Halo Pad"    ),
+    ((  0,   0,  95), "Sweep Pad"   ),
+    ((  0,   0,  96), "Ice Rain"    ),
+    ((  0,   0,  97), "Soundtrack"  ),
+    ((  0,   0,  98), "Crystal"     ),
+    ((  1,   0,  98), "Syn Mallet"  ),
+    ((  0,   0,  99), "Atmosphere"  ),
+    ((  0,   0, 100), "Brightness"  ),
+    ((  0,   0, 101), "Goblin"      ),
+    ((  0,   0, 102), "Echo Drops"  ),
+    ((  1,   0, 102), "Echo Bell"   ),
+    ((  2,   0, 102), "Echo Pan"    ),
+    ((  0,   0, 103), "Star Theme"  ),
+    ((  0,   0, 104), "Sitar"       ),
+    ((  1,   0, 104), "Sitar 2"     ),
+    ((  0,   0, 105), "Banjo"       ),
+    ((  0,   0, 106), "Shamisen"    ),
+    ((  0,   0, 107), "Koto"        ),
+    ((  8,   0, 107), "Taisho Koto" ),
+    ((  0,   0, 108), "Kalimba"     ),
+    ((  0,   0, 109), "Bag Pipe"    ),
+    ((  0,   0, 110), "Fiddle"      ),
+    ((  0,   0, 111), "Shanai"      ),
+    ((  0,   0, 112), "Tinkle Bell" ),
+    ((  0,   0, 113), "Agogo"       ),
+    ((  0,   0, 114), "Steel Drums" ),
+    ((  0,   0, 115), "Woodblock"   ),
+    ((  8,   0, 115), "Castanets"   ),
+    ((  0,   0, 116), "Taiko"       ),
+    ((  8,   0, 116), "Concert BD"  ),
+    ((  0,   0, 117), "Melo. Tom 1" ),
+    ((  8,   0, 117), "Melo. Tom 2" ),
+    ((  0,   0, 118), "Synth Drum"  ),
+    ((  8,   0, 118), "808 Tom"     ),
+    ((  9,   0, 118), "Elec Perc."  ),
+    ((  0,   0, 119), "Reverse Cym."),
+    ((  0,   0, 120), "Gt.FretNoise"),
+    ((  1,   0, 120), "Gt.Cut Noise"),
+    ((  2,   0, 120), "String Slap" ),
+    ((  0,   0, 121), "Breath Noise"),
+    ((  1,   0, 121), "Fl.Key Click"),
+    ((  0,   0, 122), "Seashore"    ),
+    ((  1,   0, 122), "Rain"        ),
+    ((  2,   0, 122), "Thunder"     ),
+    ((  3,   0, 122), "Wind"        ),
+    ((  4,   0, 122), "Stream"      ),
+    ((  5,   0, 122), "Bubble"      ),
+    ((  0,   0, 123), "Bird"        ),
+    ((  1,   0, 123), "Dog"         ),
+    ((  2,   0, 123), "Horse-Gallop"),
+    ((  3,   0, 123), "Bird 2"      ),
+    ((  0,   0, 124), "Telephone 1" ),
+    ((  1,   0, 124), "Telephone 2" ),
+    ((  2,   0, 124), "DoorCreaking"),
+    ((  3,   0, 124), "Door"        ),
+    ((  4,   0, 124), "Scratch"     ),
+    ((  5,   0, 124), "Windchime"   ),
+    ((  0,   0, 125), "Helicopter"  ),
+    ((  1,   0, 125), "Car-Engine"  ),
+    ((  2,   0, 125), "Car-Stop"    ),
+    ((  3,   0, 125), "Car-Pass"    ),
+    ((  4,   0, 125), "Car-Crash"   ),
+    ((  5,   0, 125), "Siren"       ),
+    ((  6,   0, 125), "Train"       ),
+    ((  7,   0, 125), "Jetplane"    ),
+    ((  8,   0, 125), "Starship"    ),
+    ((  9,   0, 125), "Burst Noise" ),
+    ((  0,   0, 126), "Applause"    ),
+    ((  1,   0, 126), "Laughing"    ),
+    ((  2,   0, 126), "Screaming"   ),
+    ((  3,   0, 126), "Punch"       ),
+    ((  4,   0, 126), "Heart Beat"  ),
+    ((  5,   0, 126), "Footsteps"   ),
+    ((  0,   0, 127), "Gun Shot"    ),
+    ((  1,   0, 127), "Machine Gun" ),
+    ((  2,   0, 127), "Lasergun"    ),
+    ((  3,   0, 127), "Explosion"   ),
+];
+
+/// GS drum sets, selected by program change on the rhythm part regardless
+/// of the bank select controllers.
+#[rustfmt::skip]
+pub const ROLAND_GS_DRUM_SETS: &[(u8, &str)] = &[
+    (  0, "STANDARD"  ),
+    (  8, "ROOM"      ),
+    ( 16, "POWER"     ),
+    ( 24, "ELECTRONIC"),
+    ( 25, "TR-808"    ),
+    ( 32, "JAZZ"      ),
+    ( 40, "BRUSH"     ),
+    ( 48, "ORCHESTRA" ),
+    ( 56, "SFX"       ),
+    (127, "CM-64/32L" ),
+];
@@ -1 +1,10 @@
+pub mod controller;
 pub mod gm;
+pub mod gm2;
+pub mod gs;
+pub mod message;
+pub mod note;
+pub mod rpn;
+pub mod smf;
+pub mod sound_set;
+pub mod xg;
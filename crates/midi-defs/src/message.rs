@@ -0,0 +1,269 @@
+//! MIDI 1.0 messages.
+
+#[rustfmt::skip]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MidiMessage {
+    // Channel voice messages
+    NoteOff         { channel: u8, key: u8, velocity: u8 },
+    NoteOn          { channel: u8, key: u8, velocity: u8 },
+    PolyPressure    { channel: u8, key: u8, pressure: u8 },
+    ControlChange   { channel: u8, controller: u8, value: u8 },
+    ProgramChange   { channel: u8, program: u8 },
+    ChannelPressure { channel: u8, pressure: u8 },
+    /// Pitch wheel position from 0 to 16383, 8192 being the center.
+    PitchBend       { channel: u8, value: u16 },
+
+    // System exclusive message, without the `F0` and `F7` framing bytes
+    SysEx(Vec<u8>),
+
+    // System common messages
+    TimeCodeQuarterFrame(u8),
+    SongPosition(u16),
+    SongSelect(u8),
+    TuneRequest,
+
+    // System real-time messages
+    TimingClock,
+    Start,
+    Continue,
+    Stop,
+    ActiveSensing,
+    SystemReset,
+}
+
+/// Number of data bytes following a status byte, `None` for the system
+/// exclusive and undefined status bytes.
+pub fn data_length(status: u8) -> Option<usize> {
+    match status {
+        0x80..=0xBF | 0xE0..=0xEF => Some(2),
+        0xC0..=0xDF => Some(1),
+        0xF1 | 0xF3 => Some(1),
+        0xF2 => Some(2),
+        0xF6 | 0xF8 | 0xFA..=0xFC | 0xFE | 0xFF => Some(0),
+        _ => None,
+    }
+}
+
+impl MidiMessage {
+    /// Builds a message from a status byte and its data bytes, any message
+    /// but system exclusive. The number of data bytes must match the status.
+    pub fn from_status(status: u8, data: &[u8]) -> Option<MidiMessage> {
+        if data_length(status) != Some(data.len()) || data.iter().any(|&byte| byte >= 0x80) {
+            return None;
+        }
+
+        let channel = status & 0x0F;
+
+        #[rustfmt::skip]
+        let message = match (status, data) {
+            (0x80..=0x8F, &[key, velocity])   => MidiMessage::NoteOff { channel, key, velocity },
+            (0x90..=0x9F, &[key, velocity])   => MidiMessage::NoteOn { channel, key, velocity },
+            (0xA0..=0xAF, &[key, pressure])   => MidiMessage::PolyPressure { channel, key, pressure },
+            (0xB0..=0xBF, &[controller, value]) => MidiMessage::ControlChange { channel, controller, value },
+            (0xC0..=0xCF, &[program])         => MidiMessage::ProgramChange { channel, program },
+            (0xD0..=0xDF, &[pressure])        => MidiMessage::ChannelPressure { channel, pressure },
+            (0xE0..=0xEF, &[lsb, msb])        => MidiMessage::PitchBend { channel, value: ((msb as u16) << 7) | lsb as u16 },
+            (0xF1, &[value])                  => MidiMessage::TimeCodeQuarterFrame(value),
+            (0xF2, &[lsb, msb])               => MidiMessage::SongPosition(((msb as u16) << 7) | lsb as u16),
+            (0xF3, &[song])                   => MidiMessage::SongSelect(song),
+            (0xF6, _)                         => MidiMessage::TuneRequest,
+            (0xF8, _)                         => MidiMessage::TimingClock,
+            (0xFA, _)                         => MidiMessage::Start,
+            (0xFB, _)                         => MidiMessage::Continue,
+            (0xFC, _)                         => MidiMessage::Stop,
+            (0xFE, _)                         => MidiMessage::ActiveSensing,
+            (0xFF, _)                         => MidiMessage::SystemReset,
+            _ => return None,
+        };
+
+        Some(message)
+    }
+
+    /// Status byte of the message, including the channel of channel messages.
+    pub fn status(&self) -> u8 {
+        match self {
+            MidiMessage::NoteOff { channel, .. } => 0x80 | (channel & 0x0F),
+            MidiMessage::NoteOn { channel, .. } => 0x90 | (channel & 0x0F),
+            MidiMessage::PolyPressure { channel, .. } => 0xA0 | (channel & 0x0F),
+            MidiMessage::ControlChange { channel, .. } => 0xB0 | (channel & 0x0F),
+            MidiMessage::ProgramChange { channel, .. } => 0xC0 | (channel & 0x0F),
+            MidiMessage::ChannelPressure { channel, .. } => 0xD0 | (channel & 0x0F),
+            MidiMessage::PitchBend { channel, .. } => 0xE0 | (channel & 0x0F),
+            MidiMessage::SysEx(_) => 0xF0,
+            MidiMessage::TimeCodeQuarterFrame(_) => 0xF1,
+            MidiMessage::SongPosition(_) => 0xF2,
+            MidiMessage::SongSelect(_) => 0xF3,
+            MidiMessage::TuneRequest => 0xF6,
+            MidiMessage::TimingClock => 0xF8,
+            MidiMessage::Start => 0xFA,
+            MidiMessage::Continue => 0xFB,
+            MidiMessage::Stop => 0xFC,
+            MidiMessage::ActiveSensing => 0xFE,
+            MidiMessage::SystemReset => 0xFF,
+        }
+    }
+
+    pub fn channel(&self) -> Option<u8> {
+        match self.status() {
+            status @ 0x80..=0xEF => Some(status & 0x0F),
+            _ => None,
+        }
+    }
+
+    pub fn is_realtime(&self) -> bool {
+        self.status() >= 0xF8
+    }
+
+    /// Note-on messages of zero velocity are note-offs.
+    pub fn is_note_off(&self) -> bool {
+        matches!(
+            self,
+            MidiMessage::NoteOff { .. } | MidiMessage::NoteOn { velocity: 0, .. }
+        )
+    }
+
+    fn write_data(&self, output: &mut Vec<u8>) {
+        match *self {
+            MidiMessage::NoteOff { key, velocity, .. }
+            | MidiMessage::NoteOn { key, velocity, .. } => output.extend([key, velocity]),
+            MidiMessage::PolyPressure { key, pressure, .. } => output.extend([key, pressure]),
+            MidiMessage::ControlChange {
+                controller, value, ..
+            } => output.extend([controller, value]),
+            MidiMessage::ProgramChange { program, .. } => output.push(program),
+            MidiMessage::ChannelPressure { pressure, .. } => output.push(pressure),
+            MidiMessage::PitchBend { value, .. } | MidiMessage::SongPosition(value) => {
+                output.extend([(value & 0x7F) as u8, ((value >> 7) & 0x7F) as u8])
+            }
+            MidiMessage::SysEx(ref data) => {
+                output.extend(data);
+                output.push(0xF7);
+            }
+            MidiMessage::TimeCodeQuarterFrame(value) | MidiMessage::SongSelect(value) => {
+                output.push(value)
+            }
+            _ => {}
+        }
+    }
+
+    /// Appends the encoded message to `output`, without running status.
+    pub fn encode(&self, output: &mut Vec<u8>) {
+        output.push(self.status());
+        self.write_data(output);
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+        self.encode(&mut output);
+        output
+    }
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+/// Byte stream decoder handling running status, system exclusive messages
+/// and real-time messages interleaved with other messages.
+///
+/// Unterminated system exclusive messages, which get interrupted by another
+/// status byte, are discarded.
+#[derive(Debug, Clone, Default)]
+pub struct MidiDecoder {
+    status: Option<u8>,
+    data: Vec<u8>,
+    sysex_data: Option<Vec<u8>>,
+}
+
+impl MidiDecoder {
+    pub fn new() -> MidiDecoder {
+        MidiDecoder::default()
+    }
+
+    /// Feeds a single byte to the decoder, returning the message it completes.
+    pub fn push(&mut self, byte: u8) -> Option<MidiMessage> {
+        match byte {
+            // Real-time messages may appear anywhere without affecting the state.
+            0xF8..=0xFF => MidiMessage::from_status(byte, &[]),
+
+            0xF0 => {
+                self.status = None;
+                self.sysex_data = Some(Vec::new());
+                None
+            }
+
+            0xF7 => {
+                self.status = None;
+                self.sysex_data.take().map(MidiMessage::SysEx)
+            }
+
+            0x80..=0xF6 => {
+                self.sysex_data = None;
+                self.data.clear();
+
+                if data_length(byte) == Some(0) {
+                    self.status = None;
+                    MidiMessage::from_status(byte, &[])
+                } else {
+                    self.status = data_length(byte).map(|_| byte);
+                    None
+                }
+            }
+
+            0x00..=0x7F => {
+                if let Some(sysex_data) = &mut self.sysex_data {
+                    sysex_data.push(byte);
+                    return None;
+                }
+
+                let status = self.status?;
+                self.data.push(byte);
+
+                if Some(self.data.len()) != data_length(status) {
+                    return None;
+                }
+
+                let message = MidiMessage::from_status(status, &self.data);
+                self.data.clear();
+
+                // Only channel messages establish running status.
+                if status >= 0xF0 {
+                    self.status = None;
+                }
+
+                message
+            }
+        }
+    }
+
+    pub fn decode(&mut self, bytes: &[u8]) -> Vec<MidiMessage> {
+        bytes.iter().filter_map(|&byte| self.push(byte)).collect()
+    }
+}
+
+/// Byte stream encoder, omitting repeated status bytes of consecutive
+/// channel messages.
+#[derive(Debug, Clone, Default)]
+pub struct MidiEncoder {
+    running_status: Option<u8>,
+}
+
+impl MidiEncoder {
+    pub fn new() -> MidiEncoder {
+        MidiEncoder::default()
+    }
+
+    pub fn encode(&mut self, message: &MidiMessage, output: &mut Vec<u8>) {
+        let status = message.status();
+
+        if message.is_realtime() {
+            output.push(status);
+            return;
+        }
+
+        if self.running_status != Some(status) {
+            output.push(status);
+        }
+
+        self.running_status = message.channel().map(|_| status);
+        message.write_data(output);
+    }
+}
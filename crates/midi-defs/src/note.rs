@@ -0,0 +1,37 @@
+//! MIDI note numbers and note names.
+
+pub const MIDDLE_C: u8 = 60;
+
+/// Octave number of middle C in scientific pitch notation, numbering the
+/// notes from C-1 to G9. Some manufacturers (e.g. Yamaha) use 3 instead.
+pub const DEFAULT_MIDDLE_C_OCTAVE: i32 = 4;
+
+pub const NOTE_NAMES: [&str; 12] = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
+
+/// Name of a note number, e.g. `C4` for middle C.
+pub fn note_name(key: u8, middle_c_octave: i32) -> String {
+    let octave = (key / 12) as i32 - (MIDDLE_C / 12) as i32 + middle_c_octave;
+    format!("{}{}", NOTE_NAMES[(key % 12) as usize], octave)
+}
+
+/// Parses a note name as written by `note_name`, flats are accepted too.
+pub fn note_from_name(name: &str, middle_c_octave: i32) -> Option<u8> {
+    let octave_position = name.find(|c: char| c == '-' || c.is_ascii_digit())?;
+    let (pitch_class, octave) = name.split_at(octave_position);
+
+    let pitch_class = match pitch_class {
+        "Db" => 1,
+        "Eb" => 3,
+        "Gb" => 6,
+        "Ab" => 8,
+        "Bb" => 10,
+        _ => NOTE_NAMES.iter().position(|&name| name == pitch_class)? as i32,
+    };
+
+    let octave = octave.parse::<i32>().ok()?;
+    let key = (octave - middle_c_octave) * 12 + MIDDLE_C as i32 + pitch_class;
+
+    u8::try_from(key).ok().filter(|&key| key <= 127)
+}
@@ -0,0 +1,12 @@
+//! Registered parameter numbers, as 14-bit values of the RPN MSB and LSB
+//! controllers.
+
+pub const PITCH_BEND_SENSITIVITY: u16 = 0x0000;
+pub const FINE_TUNING: u16 = 0x0001;
+pub const COARSE_TUNING: u16 = 0x0002;
+pub const TUNING_PROGRAM_SELECT: u16 = 0x0003;
+pub const TUNING_BANK_SELECT: u16 = 0x0004;
+pub const MODULATION_DEPTH_RANGE: u16 = 0x0005;
+
+/// Deselects the current parameter, so stray data entry gets ignored.
+pub const NULL: u16 = 0x3FFF;
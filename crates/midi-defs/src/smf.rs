@@ -0,0 +1,579 @@
+//! Standard MIDI File reader.
+//!
+//! https://www.midi.org/specifications-old/item/the-midi-1-0-specification
+
+use derive_more::{Display, Error};
+
+use crate::message::{data_length, MidiMessage};
+
+#[rustfmt::skip]
+#[derive(Debug, Display, Error)]
+pub enum SmfError {
+    #[display(fmt = "Missing MThd chunk")]
+    MissingHeaderChunk,
+
+    #[display(fmt = "Malformed MThd chunk")]
+    MalformedHeaderChunk,
+
+    #[display(fmt = "Unknown SMF format {format:}")]
+    UnknownFormat {
+        format: u16,
+    },
+
+    #[display(fmt = "Invalid division {division:#06X}")]
+    InvalidDivision {
+        division: u16,
+    },
+
+    #[display(fmt = "Unexpected end of data at offset {offset:#010X}")]
+    UnexpectedEndOfData {
+        offset: usize,
+    },
+
+    #[display(fmt = "Malformed variable-length quantity at offset {offset:#010X}")]
+    MalformedVariableLengthQuantity {
+        offset: usize,
+    },
+
+    #[display(fmt = "Missing running status at offset {offset:#010X}")]
+    MissingRunningStatus {
+        offset: usize,
+    },
+
+    #[display(fmt = "Malformed event at offset {offset:#010X}")]
+    MalformedEvent {
+        offset: usize,
+    },
+
+    #[display(fmt = "Unexpected status byte {status:#04X} at offset {offset:#010X}")]
+    UnexpectedStatus {
+        status: u8,
+        offset: usize,
+    },
+}
+
+pub type SmfResult<T> = Result<T, SmfError>;
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SmfFormat {
+    /// A single multi-channel track.
+    SingleTrack,
+    /// Simultaneous tracks of a single sequence.
+    MultiTrack,
+    /// Independent single-track sequences.
+    MultiSequence,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SmfTimebase {
+    /// Metrical time, delta times are fractions of a quarter note.
+    TicksPerQuarter(u16),
+    /// Time-code-based time, delta times are fractions of a SMPTE frame.
+    /// The frame rate is one of 24, 25, 29 (30 drop frame) and 30.
+    Smpte {
+        frames_per_second: u8,
+        ticks_per_frame: u8,
+    },
+}
+
+#[rustfmt::skip]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SmfMetaEvent {
+    SequenceNumber(u16),
+    Text(String),
+    Copyright(String),
+    TrackName(String),
+    InstrumentName(String),
+    Lyric(String),
+    Marker(String),
+    CuePoint(String),
+    ProgramName(String),
+    DeviceName(String),
+    ChannelPrefix(u8),
+    Port(u8),
+    EndOfTrack,
+    Tempo { microseconds_per_quarter: u32 },
+    SmpteOffset { hours: u8, minutes: u8, seconds: u8, frames: u8, subframes: u8 },
+    TimeSignature { numerator: u8, denominator: u8, clocks_per_click: u8, notated_32nds_per_quarter: u8 },
+    KeySignature { sharps: i8, is_minor: bool },
+    SequencerSpecific(Vec<u8>),
+    Unknown { meta_type: u8, data: Vec<u8> },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SmfEvent {
+    /// Channel message, SMF files do not hold system common and real-time
+    /// messages.
+    Midi(MidiMessage),
+    /// System exclusive message, without the leading `F0` status byte.
+    SysEx(Vec<u8>),
+    /// Arbitrary bytes sent as-is, introduced by an `F7` status byte.
+    Escape(Vec<u8>),
+    Meta(SmfMetaEvent),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SmfTrackEvent {
+    /// Absolute time of the event within its track, in ticks.
+    pub tick: u64,
+    pub event: SmfEvent,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Smf {
+    pub format: SmfFormat,
+    pub timebase: SmfTimebase,
+    pub tracks: Vec<Vec<SmfTrackEvent>>,
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+struct ByteReader<'a> {
+    buffer: &'a [u8],
+    buffer_offset: usize,
+    position: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    /// Reader of a buffer located at `buffer_offset` in the file, the offset
+    /// is only used for error reporting.
+    fn new(buffer: &'a [u8], buffer_offset: usize) -> Self {
+        ByteReader {
+            buffer,
+            buffer_offset,
+            position: 0,
+        }
+    }
+
+    fn offset(&self) -> usize {
+        self.buffer_offset + self.position
+    }
+
+    fn is_empty(&self) -> bool {
+        self.position >= self.buffer.len()
+    }
+
+    fn read_bytes(&mut self, length: usize) -> SmfResult<&'a [u8]> {
+        let bytes = self
+            .buffer
+            .get(self.position..self.position.saturating_add(length))
+            .ok_or(SmfError::UnexpectedEndOfData {
+                offset: self.buffer_offset + self.buffer.len(),
+            })?;
+
+        self.position += length;
+        Ok(bytes)
+    }
+
+    fn peek_u8(&self) -> SmfResult<u8> {
+        self.buffer
+            .get(self.position)
+            .copied()
+            .ok_or(SmfError::UnexpectedEndOfData {
+                offset: self.offset(),
+            })
+    }
+
+    fn read_u8(&mut self) -> SmfResult<u8> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_u16(&mut self) -> SmfResult<u16> {
+        Ok(u16::from_be_bytes(self.read_bytes(2)?.try_into().unwrap()))
+    }
+
+    fn read_u32(&mut self) -> SmfResult<u32> {
+        Ok(u32::from_be_bytes(self.read_bytes(4)?.try_into().unwrap()))
+    }
+
+    /// Variable-length quantity, at most 4 bytes long.
+    fn read_vlq(&mut self) -> SmfResult<u32> {
+        let offset = self.offset();
+        let mut value = 0;
+
+        for _ in 0..4 {
+            let byte = self.read_u8()?;
+            value = (value << 7) | (byte & 0x7F) as u32;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        Err(SmfError::MalformedVariableLengthQuantity { offset })
+    }
+
+    /// Data bytes of a variable-length event (SysEx and meta events).
+    fn read_vlq_data(&mut self) -> SmfResult<&'a [u8]> {
+        let length = self.read_vlq()? as usize;
+        self.read_bytes(length)
+    }
+}
+
+fn read_meta_event(meta_type: u8, data: &[u8]) -> SmfMetaEvent {
+    let text = || String::from_utf8_lossy(data).into_owned();
+
+    match (meta_type, data) {
+        (0x00, &[msb, lsb]) => SmfMetaEvent::SequenceNumber(u16::from_be_bytes([msb, lsb])),
+        (0x01, _) => SmfMetaEvent::Text(text()),
+        (0x02, _) => SmfMetaEvent::Copyright(text()),
+        (0x03, _) => SmfMetaEvent::TrackName(text()),
+        (0x04, _) => SmfMetaEvent::InstrumentName(text()),
+        (0x05, _) => SmfMetaEvent::Lyric(text()),
+        (0x06, _) => SmfMetaEvent::Marker(text()),
+        (0x07, _) => SmfMetaEvent::CuePoint(text()),
+        (0x08, _) => SmfMetaEvent::ProgramName(text()),
+        (0x09, _) => SmfMetaEvent::DeviceName(text()),
+        (0x20, &[channel]) => SmfMetaEvent::ChannelPrefix(channel),
+        (0x21, &[port]) => SmfMetaEvent::Port(port),
+        (0x2F, _) => SmfMetaEvent::EndOfTrack,
+        (0x51, &[a, b, c]) => SmfMetaEvent::Tempo {
+            microseconds_per_quarter: u32::from_be_bytes([0, a, b, c]),
+        },
+        (0x54, &[hours, minutes, seconds, frames, subframes]) => SmfMetaEvent::SmpteOffset {
+            hours,
+            minutes,
+            seconds,
+            frames,
+            subframes,
+        },
+        (0x58, &[numerator, denominator, clocks_per_click, notated_32nds_per_quarter]) => {
+            SmfMetaEvent::TimeSignature {
+                numerator,
+                denominator: 1u8.checked_shl(denominator as u32).unwrap_or(0),
+                clocks_per_click,
+                notated_32nds_per_quarter,
+            }
+        }
+        (0x59, &[sharps, mode]) => SmfMetaEvent::KeySignature {
+            sharps: sharps as i8,
+            is_minor: mode == 1,
+        },
+        (0x7F, _) => SmfMetaEvent::SequencerSpecific(data.to_vec()),
+        _ => SmfMetaEvent::Unknown {
+            meta_type,
+            data: data.to_vec(),
+        },
+    }
+}
+
+fn read_track(buffer: &[u8], buffer_offset: usize) -> SmfResult<Vec<SmfTrackEvent>> {
+    let mut reader = ByteReader::new(buffer, buffer_offset);
+    let mut track_events = Vec::new();
+
+    let mut tick = 0;
+    let mut running_status = None;
+
+    while !reader.is_empty() {
+        tick += reader.read_vlq()? as u64;
+
+        let status_offset = reader.offset();
+        let status = match reader.peek_u8()? {
+            status @ 0x80..=0xFF => {
+                reader.position += 1;
+                status
+            }
+            _ => running_status.ok_or(SmfError::MissingRunningStatus {
+                offset: status_offset,
+            })?,
+        };
+
+        let event = match status {
+            0x80..=0xEF => {
+                // Only channel messages may use running status.
+                running_status = Some(status);
+
+                let data = reader.read_bytes(data_length(status).unwrap())?;
+
+                SmfEvent::Midi(MidiMessage::from_status(status, data).ok_or(
+                    SmfError::MalformedEvent {
+                        offset: status_offset,
+                    },
+                )?)
+            }
+            0xF0 => {
+                running_status = None;
+                SmfEvent::SysEx(reader.read_vlq_data()?.to_vec())
+            }
+            0xF7 => {
+                running_status = None;
+                SmfEvent::Escape(reader.read_vlq_data()?.to_vec())
+            }
+            0xFF => {
+                running_status = None;
+                let meta_type = reader.read_u8()?;
+                let data = reader.read_vlq_data()?;
+                SmfEvent::Meta(read_meta_event(meta_type, data))
+            }
+            _ => {
+                return Err(SmfError::UnexpectedStatus {
+                    status,
+                    offset: status_offset,
+                })
+            }
+        };
+
+        let is_end_of_track = event == SmfEvent::Meta(SmfMetaEvent::EndOfTrack);
+        track_events.push(SmfTrackEvent { tick, event });
+
+        if is_end_of_track {
+            break;
+        }
+    }
+
+    Ok(track_events)
+}
+
+impl Smf {
+    pub fn new(buffer: &[u8]) -> SmfResult<Smf> {
+        let mut reader = ByteReader::new(buffer, 0);
+
+        if reader.read_bytes(4).ok() != Some(&b"MThd"[..]) {
+            return Err(SmfError::MissingHeaderChunk);
+        }
+
+        let header_length = reader.read_u32()? as usize;
+        if header_length < 6 {
+            return Err(SmfError::MalformedHeaderChunk);
+        }
+
+        let mut header_reader = ByteReader::new(reader.read_bytes(header_length)?, 8);
+        let format = header_reader.read_u16()?;
+        let track_count = header_reader.read_u16()?;
+        let division = header_reader.read_u16()?;
+
+        let format = match format {
+            0 => SmfFormat::SingleTrack,
+            1 => SmfFormat::MultiTrack,
+            2 => SmfFormat::MultiSequence,
+            format => return Err(SmfError::UnknownFormat { format }),
+        };
+
+        let timebase = if division & 0x8000 != 0 {
+            // The high byte is the negated frame rate, in two's complement.
+            let frames_per_second = match (division >> 8) as u8 as i8 {
+                frame_rate @ (-24 | -25 | -29 | -30) => frame_rate.unsigned_abs(),
+                _ => return Err(SmfError::InvalidDivision { division }),
+            };
+
+            let ticks_per_frame = (division & 0xFF) as u8;
+            if ticks_per_frame == 0 {
+                return Err(SmfError::InvalidDivision { division });
+            }
+
+            SmfTimebase::Smpte {
+                frames_per_second,
+                ticks_per_frame,
+            }
+        } else if division == 0 {
+            return Err(SmfError::InvalidDivision { division });
+        } else {
+            SmfTimebase::TicksPerQuarter(division)
+        };
+
+        let mut tracks = Vec::new();
+
+        // Chunks of unknown types must be skipped, as the specification requires.
+        while (tracks.len() < track_count as usize) && !reader.is_empty() {
+            let chunk_id = reader.read_bytes(4)?;
+            let chunk_length = reader.read_u32()? as usize;
+            let chunk_offset = reader.offset();
+            let chunk_data = reader.read_bytes(chunk_length)?;
+
+            if chunk_id == b"MTrk" {
+                tracks.push(read_track(chunk_data, chunk_offset)?);
+            }
+        }
+
+        Ok(Smf {
+            format,
+            timebase,
+            tracks,
+        })
+    }
+
+    /// The tempo changes of the file, in merged playback order.
+    pub fn tempo_map(&self) -> SmfTempoMap {
+        let tempo_changes = self
+            .merged_events()
+            .filter_map(|(tick, _, event)| match event {
+                SmfEvent::Meta(SmfMetaEvent::Tempo {
+                    microseconds_per_quarter,
+                }) => Some((tick, *microseconds_per_quarter)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        SmfTempoMap::new(self.timebase, &tempo_changes)
+    }
+
+    fn merged_events(&self) -> SmfMergedEvents<'_> {
+        SmfMergedEvents {
+            smf: self,
+            track_positions: vec![0; self.tracks.len()],
+            track_offsets: self.track_offsets(),
+        }
+    }
+
+    /// Start ticks of the tracks. Simultaneous tracks all start at zero, while
+    /// the sequences of format 2 files are played one after another.
+    fn track_offsets(&self) -> Vec<u64> {
+        match self.format {
+            SmfFormat::SingleTrack | SmfFormat::MultiTrack => vec![0; self.tracks.len()],
+            SmfFormat::MultiSequence => self
+                .tracks
+                .iter()
+                .scan(0, |track_offset, track| {
+                    let current_offset = *track_offset;
+                    *track_offset += track.last().map_or(0, |track_event| track_event.tick);
+                    Some(current_offset)
+                })
+                .collect(),
+        }
+    }
+
+    /// Iterates over the events of all tracks merged in playback order, with
+    /// their times resolved through the tempo map. Simultaneous events are
+    /// ordered by their track index.
+    pub fn events(&self) -> SmfEventIterator<'_> {
+        SmfEventIterator {
+            merged_events: self.merged_events(),
+            tempo_map: self.tempo_map(),
+        }
+    }
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+/// Conversion of tick positions to seconds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SmfTempoMap {
+    timebase: SmfTimebase,
+    /// Tick, tempo and time in seconds of the tempo changes.
+    tempo_changes: Vec<(u64, u32, f64)>,
+}
+
+impl SmfTempoMap {
+    /// Tempo until the first tempo event, 120 beats per minute.
+    pub const DEFAULT_TEMPO: u32 = 500_000;
+
+    fn new(timebase: SmfTimebase, tempo_changes: &[(u64, u32)]) -> SmfTempoMap {
+        let mut tempo_map = SmfTempoMap {
+            timebase,
+            tempo_changes: Vec::with_capacity(tempo_changes.len()),
+        };
+
+        for &(tick, tempo) in tempo_changes {
+            let seconds = tempo_map.tick_to_seconds(tick);
+            tempo_map.tempo_changes.push((tick, tempo, seconds));
+        }
+
+        tempo_map
+    }
+
+    fn last_tempo_change(&self, tick: u64) -> (u64, u32, f64) {
+        let index = self
+            .tempo_changes
+            .partition_point(|&(change_tick, _, _)| change_tick <= tick);
+
+        match index {
+            0 => (0, Self::DEFAULT_TEMPO, 0.0),
+            index => self.tempo_changes[index - 1],
+        }
+    }
+
+    /// Tempo in microseconds per quarter note at the given tick.
+    pub fn tempo_at(&self, tick: u64) -> u32 {
+        let (_, tempo, _) = self.last_tempo_change(tick);
+        tempo
+    }
+
+    pub fn tick_to_seconds(&self, tick: u64) -> f64 {
+        match self.timebase {
+            SmfTimebase::TicksPerQuarter(ticks_per_quarter) => {
+                let (change_tick, tempo, change_seconds) = self.last_tempo_change(tick);
+
+                change_seconds
+                    + (tick - change_tick) as f64 * (tempo as f64 / 1_000_000.0)
+                        / ticks_per_quarter as f64
+            }
+            SmfTimebase::Smpte {
+                frames_per_second,
+                ticks_per_frame,
+            } => {
+                let frames_per_second = match frames_per_second {
+                    29 => 30_000.0 / 1001.0,
+                    frames_per_second => frames_per_second as f64,
+                };
+
+                tick as f64 / (frames_per_second * ticks_per_frame as f64)
+            }
+        }
+    }
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+struct SmfMergedEvents<'a> {
+    smf: &'a Smf,
+    track_positions: Vec<usize>,
+    track_offsets: Vec<u64>,
+}
+
+impl<'a> Iterator for SmfMergedEvents<'a> {
+    type Item = (u64, usize, &'a SmfEvent);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (track_index, tick) = self
+            .smf
+            .tracks
+            .iter()
+            .enumerate()
+            .filter_map(|(track_index, track)| {
+                track
+                    .get(self.track_positions[track_index])
+                    .map(|track_event| {
+                        (
+                            track_index,
+                            self.track_offsets[track_index] + track_event.tick,
+                        )
+                    })
+            })
+            .min_by_key(|&(track_index, tick)| (tick, track_index))?;
+
+        let track_event = &self.smf.tracks[track_index][self.track_positions[track_index]];
+        self.track_positions[track_index] += 1;
+
+        Some((tick, track_index, &track_event.event))
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SmfTimedEvent<'a> {
+    pub tick: u64,
+    pub seconds: f64,
+    pub track_index: usize,
+    pub event: &'a SmfEvent,
+}
+
+pub struct SmfEventIterator<'a> {
+    merged_events: SmfMergedEvents<'a>,
+    tempo_map: SmfTempoMap,
+}
+
+impl<'a> Iterator for SmfEventIterator<'a> {
+    type Item = SmfTimedEvent<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.merged_events
+            .next()
+            .map(|(tick, track_index, event)| SmfTimedEvent {
+                tick,
+                seconds: self.tempo_map.tick_to_seconds(tick),
+                track_index,
+                event,
+            })
+    }
+}
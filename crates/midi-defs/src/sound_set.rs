@@ -0,0 +1,98 @@
+//! Identification of SoundFont presets against the standard sound sets.
+
+use derive_more::Display;
+
+use crate::gm::GENERAL_MIDI;
+use crate::gm2::GENERAL_MIDI_2;
+use crate::gs::{ROLAND_GS, ROLAND_GS_DRUM_SETS};
+use crate::xg::YAMAHA_XG;
+
+#[rustfmt::skip]
+#[derive(Debug, Display, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SoundSet {
+    #[display(fmt = "GM")]  GeneralMidi,
+    #[display(fmt = "GM2")] GeneralMidi2,
+    #[display(fmt = "GS")]  RolandGs,
+    #[display(fmt = "XG")]  YamahaXg,
+}
+
+/// SoundFont bank holding the drum kits.
+pub const PERCUSSION_BANK: u16 = 128;
+
+pub const GM2_DRUM_BANK_MSB: u8 = 120;
+pub const GM2_MELODIC_BANK_MSB: u8 = 121;
+pub const XG_SFX_BANK_MSB: u8 = 64;
+pub const XG_SFX_KIT_BANK_MSB: u8 = 126;
+pub const XG_DRUM_BANK_MSB: u8 = 127;
+
+fn find(table: &[((u8, u8, u8), &'static str)], keys: &[(u8, u8, u8)]) -> Option<&'static str> {
+    keys.iter().find_map(|key| {
+        table
+            .iter()
+            .find(|(table_key, _)| table_key == key)
+            .map(|(_, name)| *name)
+    })
+}
+
+/// Finds the standard sounds a SoundFont preset corresponds to, at most one
+/// per sound set, e.g. for `Sf2PresetHeader::bank_preset()`.
+///
+/// SoundFont banks get interpreted the way sound sets are commonly laid out
+/// in SoundFonts:
+/// - bank 128 holds the drum kits of all sound sets,
+/// - banks 0 to 127 hold the GS variation tones of the same bank MSB, and
+///   the GM2 and XG variations of the same bank LSB,
+/// - banks above 128 are 14-bit bank numbers (`MSB * 128 + LSB`).
+pub fn lookup_bank_preset(bank_preset: (u16, u16)) -> Vec<(SoundSet, &'static str)> {
+    let (bank, preset) = bank_preset;
+
+    let Some(program) = u8::try_from(preset).ok().filter(|&program| program <= 127) else {
+        return Vec::new();
+    };
+
+    let general_midi = GENERAL_MIDI
+        .iter()
+        .find(|(key, _)| *key == bank_preset)
+        .map(|(_, name)| *name);
+
+    let (general_midi_2, roland_gs, yamaha_xg) = match bank {
+        PERCUSSION_BANK => (
+            find(GENERAL_MIDI_2, &[(GM2_DRUM_BANK_MSB, 0, program)]),
+            ROLAND_GS_DRUM_SETS
+                .iter()
+                .find(|(drum_set_program, _)| *drum_set_program == program)
+                .map(|(_, name)| *name),
+            find(YAMAHA_XG, &[(XG_DRUM_BANK_MSB, 0, program)]),
+        ),
+        0..=127 => {
+            let bank = bank as u8;
+
+            (
+                find(GENERAL_MIDI_2, &[(GM2_MELODIC_BANK_MSB, bank, program)]),
+                find(ROLAND_GS, &[(bank, 0, program)]),
+                find(YAMAHA_XG, &[(bank, 0, program), (0, bank, program)]),
+            )
+        }
+        _ => match u8::try_from(bank >> 7) {
+            Ok(bank_msb) if bank_msb <= 127 => {
+                let keys = [(bank_msb, (bank & 0x7F) as u8, program)];
+                (
+                    find(GENERAL_MIDI_2, &keys),
+                    find(ROLAND_GS, &keys),
+                    find(YAMAHA_XG, &keys),
+                )
+            }
+            _ => (None, None, None),
+        },
+    };
+
+    [
+        (SoundSet::GeneralMidi, general_midi),
+        (SoundSet::GeneralMidi2, general_midi_2),
+        (SoundSet::RolandGs, roland_gs),
+        (SoundSet::YamahaXg, yamaha_xg),
+    ]
+    .into_iter()
+    .filter_map(|(sound_set, name)| name.map(|name| (sound_set, name)))
+    .collect()
+}
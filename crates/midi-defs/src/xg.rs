@@ -0,0 +1,189 @@
+// Yamaha XG specification, voice list
+
+/// Yamaha XG voices, keyed by bank MSB, bank LSB and program.
+///
+/// Covers the basic voices of bank MSB 0, the SFX voices of bank MSB 64,
+/// the SFX kits of bank MSB 126 and the drum kits of bank MSB 127. Variation
+/// voices in bank MSB 0 with a non-zero LSB are not listed.
+#[rustfmt::skip]
+pub const YAMAHA_XG: &[((u8, u8, u8), &str)] = &[
+    ((  0,   0,   0), "GrandPno"      ),
+    ((  0,   0,   1), "BritePno"      ),
+    ((  0,   0,   2), "E.Grand"       ),
+    ((  0,   0,   3), "HnkyTonk"      ),
+    ((  0,   0,   4), "E.Piano1"      ),
+    ((  0,   0,   5), "E.Piano2"      ),
+    ((  0,   0,   6), "Harpsi."       ),
+    ((  0,   0,   7), "Clavi."        ),
+    ((  0,   0,   8), "Celesta"       ),
+    ((  0,   0,   9), "Glocken"       ),
+    ((  0,   0,  10), "MusicBox"      ),
+    ((  0,   0,  11), "Vibes"         ),
+    ((  0,   0,  12), "Marimba"       ),
+    ((  0,   0,  13), "Xylophon"      ),
+    ((  0,   0,  14), "TubulBel"      ),
+    ((  0,   0,  15), "Dulcimer"      ),
+    ((  0,   0,  16), "DrawOrgn"      ),
+    ((  0,   0,  17), "PercOrgn"      ),
+    ((  0,   0,  18), "RockOrgn"      ),
+    ((  0,   0,  19), "ChrchOrg"      ),
+    ((  0,   0,  20), "ReedOrgn"      ),
+    ((  0,   0,  21), "Acordion"      ),
+    ((  0,   0,  22), "Harmnica"      ),
+    ((  0,   0,  23), "TangoAcd"      ),
+    ((  0,   0,  24), "NylonGtr"      ),
+    ((  0,   0,  25), "SteelGtr"      ),
+    ((  0,   0,  26), "Jazz Gtr"      ),
+    ((  0,   0,  27), "CleanGtr"      ),
+    ((  0,   0,  28), "Mute.Gtr"      ),
+    ((  0,   0,  29), "Ovrdrive"      ),
+    ((  0,   0,  30), "Dist.Gtr"      ),
+    ((  0,   0,  31), "GtrHarmo"      ),
+    ((  0,   0,  32), "Aco.Bass"      ),
+    ((  0,   0,  33), "FngrBass"      ),
+    ((  0,   0,  34), "PickBass"      ),
+    ((  0,   0,  35), "Fretless"      ),
+    ((  0,   0,  36), "SlapBas1"      ),
+    ((  0,   0,  37), "SlapBas2"      ),
+    ((  0,   0,  38), "SynBass1"      ),
+    ((  0,   0,  39), "SynBass2"      ),
+    ((  0,   0,  40), "Violin"        ),
+    ((  0,   0,  41), "Viola"         ),
+    ((  0,   0,  42), "Cello"         ),
+    ((  0,   0,  43), "Contrabs"      ),
+    ((  0,   0,  44), "Trem.Str"      ),
+    ((  0,   0,  45), "Pizz.Str"      ),
+    ((  0,   0,  46), "Harp"          ),
+    ((  0,   0,  47), "Timpani"       ),
+    ((  0,   0,  48), "Strings1"      ),
+    ((  0,   0,  49), "Strings2"      ),
+    ((  0,   0,  50), "Syn.Str1"      ),
+    ((  0,   0,  51), "Syn.Str2"      ),
+    ((  0,   0,  52), "ChoirAah"      ),
+    ((  0,   0,  53), "VoiceOoh"      ),
+    ((  0,   0,  54), "SynVoice"      ),
+    ((  0,   0,  55), "Orch.Hit"      ),
+    ((  0,   0,  56), "Trumpet"       ),
+    ((  0,   0,  57), "Trombone"      ),
+    ((  0,   0,  58), "Tuba"          ),
+    ((  0,   0,  59), "Mute.Trp"      ),
+    ((  0,   0,  60), "Fr.Horn"       ),
+    ((  0,   0,  61), "BrasSect"      ),
+    ((  0,   0,  62), "SynBras1"      ),
+    ((  0,   0,  63), "SynBras2"      ),
+    ((  0,   0,  64), "SprnoSax"      ),
+    ((  0,   0,  65), "Alto Sax"      ),
+    ((  0,   0,  66), "TenorSax"      ),
+    ((  0,   0,  67), "Bari.Sax"      ),
+    ((  0,   0,  68), "Oboe"          ),
+    ((  0,   0,  69), "Eng.Horn"      ),
+    ((  0,   0,  70), "Bassoon"       ),
+    ((  0,   0,  71), "Clarinet"      ),
+    ((  0,   0,  72), "Piccolo"       ),
+    ((  0,   0,  73), "Flute"         ),
+    ((  0,   0,  74), "Recorder"      ),
+    ((  0,   0,  75), "PanFlute"      ),
+    ((  0,   0,  76), "Bottle"        ),
+    ((  0,   0,  77), "Shakhchi"      ),
+    ((  0,   0,  78), "Whistle"       ),
+    ((  0,   0,  79), "Ocarina"       ),
+    ((  0,   0,  80), "SquareLd"      ),
+    ((  0,   0,  81), "Saw.Lead"      ),
+    ((  0,   0,  82), "CaliopLd"      ),
+    ((  0,   0,  83), "Chiff Ld"      ),
+    ((  0,   0,  84), "CharanLd"      ),
+    ((  0,   0,  85), "Voice Ld"      ),
+    ((  0,   0,  86), "Fifth Ld"      ),
+    ((  0,   0,  87), "Bass &Ld"      ),
+    ((  0,   0,  88), "NewAgePd"      ),
+    ((  0,   0,  89), "Warm Pad"      ),
+    ((  0,   0,  90), "PolySyPd"      ),
+    ((  0,   0,  91), "ChoirPad"      ),
+    ((  0,   0,  92), "BowedPad"      ),
+    ((  0,   0,  93), "MetalPad"      ),
+    ((  0,   0,  94), "Halo Pad"      ),
+    ((  0,   0,  95), "SweepPad"      ),
+    ((  0,   0,  96), "Rain"          ),
+    ((  0,   0,  97), "SoundTrk"      ),
+    ((  0,   0,  98), "Crystal"       ),
+    ((  0,   0,  99), "Atmosphr"      ),
+    ((  0,   0, 100), "Bright"        ),
+    ((  0,   0, 101), "Goblin"        ),
+    ((  0,   0, 102), "Echoes"        ),
+    ((  0,   0, 103), "Sci-Fi"        ),
+    ((  0,   0, 104), "Sitar"         ),
+    ((  0,   0, 105), "Banjo"         ),
+    ((  0,   0, 106), "Shamisen"      ),
+    ((  0,   0, 107), "Koto"          ),
+    ((  0,   0, 108), "Kalimba"       ),
+    ((  0,   0, 109), "Bagpipe"       ),
+    ((  0,   0, 110), "Fiddle"        ),
+    ((  0,   0, 111), "Shanai"        ),
+    ((  0,   0, 112), "TnklBell"      ),
+    ((  0,   0, 113), "Agogo"         ),
+    ((  0,   0, 114), "SteelDrm"      ),
+    ((  0,   0, 115), "WoodBlok"      ),
+    ((  0,   0, 116), "TaikoDrm"      ),
+    ((  0,   0, 117), "MelodTom"      ),
+    ((  0,   0, 118), "Syn.Drum"      ),
+    ((  0,   0, 119), "RevCymbl"      ),
+    ((  0,   0, 120), "FretNoiz"      ),
+    ((  0,   0, 121), "BrthNoiz"      ),
+    ((  0,   0, 122), "Seashore"      ),
+    ((  0,   0, 123), "Tweet"         ),
+    ((  0,   0, 124), "Telphone"      ),
+    ((  0,   0, 125), "Helicptr"      ),
+    ((  0,   0, 126), "Applause"      ),
+    ((  0,   0, 127), "Gunshot"       ),
+    (( 64,   0,   0), "CuttngNz"      ),
+    (( 64,   0,   1), "CttngNz2"      ),
+    (( 64,   0,   3), "Str Slap"      ),
+    (( 64,   0,  16), "Fl.KClik"      ),
+    (( 64,   0,  32), "Rain"          ),
+    (( 64,   0,  33), "Thunder"       ),
+    (( 64,   0,  34), "Wind"          ),
+    (( 64,   0,  35), "Stream"        ),
+    (( 64,   0,  36), "Bubble"        ),
+    (( 64,   0,  37), "Feed"          ),
+    (( 64,   0,  48), "Dog"           ),
+    (( 64,   0,  49), "Horse"         ),
+    (( 64,   0,  50), "Tweet 2"       ),
+    (( 64,   0,  64), "Tel.Dial"      ),
+    (( 64,   0,  65), "DoorSqek"      ),
+    (( 64,   0,  66), "Door Slam"     ),
+    (( 64,   0,  67), "Scratch"       ),
+    (( 64,   0,  68), "Scratch 2"     ),
+    (( 64,   0,  69), "WindChim"      ),
+    (( 64,   0,  70), "Telphon2"      ),
+    (( 64,   0,  80), "CarEngin"      ),
+    (( 64,   0,  81), "Car Stop"      ),
+    (( 64,   0,  82), "Car Pass"      ),
+    (( 64,   0,  83), "CarCrash"      ),
+    (( 64,   0,  84), "Siren"         ),
+    (( 64,   0,  85), "Train"         ),
+    (( 64,   0,  86), "Jetplane"      ),
+    (( 64,   0,  87), "Starship"      ),
+    (( 64,   0,  88), "Burst"         ),
+    (( 64,   0,  89), "Coaster"       ),
+    (( 64,   0,  90), "SbMarine"      ),
+    (( 64,   0,  96), "Laughing"      ),
+    (( 64,   0,  97), "Scream"        ),
+    (( 64,   0,  98), "Punch"         ),
+    (( 64,   0,  99), "Heart"         ),
+    (( 64,   0, 100), "FootStep"      ),
+    (( 64,   0, 112), "MchinGun"      ),
+    (( 64,   0, 113), "LaserGun"      ),
+    (( 64,   0, 114), "Xplosion"      ),
+    (( 64,   0, 115), "FireWork"      ),
+    ((126,   0,   0), "SFX Kit 1"     ),
+    ((126,   0,   1), "SFX Kit 2"     ),
+    ((127,   0,   0), "Standard Kit"  ),
+    ((127,   0,   1), "Standard Kit 2"),
+    ((127,   0,   8), "Room Kit"      ),
+    ((127,   0,  16), "Rock Kit"      ),
+    ((127,   0,  24), "Electro Kit"   ),
+    ((127,   0,  25), "Analog Kit"    ),
+    ((127,   0,  32), "Jazz Kit"      ),
+    ((127,   0,  40), "Brush Kit"     ),
+    ((127,   0,  48), "Classic Kit"   ),
+];
@@ -0,0 +1,139 @@
+use midi_defs::message::{MidiDecoder, MidiEncoder, MidiMessage};
+
+#[test]
+fn decoder_running_status() {
+    let mut decoder = MidiDecoder::new();
+
+    #[rustfmt::skip]
+    let messages = decoder.decode(&[
+        0x90, 0x3C, 0x64, // Note on
+        0x40, 0x50,       // Note on, running status
+        0xE1, 0x00, 0x40, // Pitch bend
+    ]);
+
+    assert_eq!(
+        messages,
+        [
+            MidiMessage::NoteOn {
+                channel: 0,
+                key: 0x3C,
+                velocity: 0x64
+            },
+            MidiMessage::NoteOn {
+                channel: 0,
+                key: 0x40,
+                velocity: 0x50
+            },
+            MidiMessage::PitchBend {
+                channel: 1,
+                value: 0x2000
+            },
+        ]
+    );
+}
+
+#[test]
+fn decoder_realtime_interleaved() {
+    let mut decoder = MidiDecoder::new();
+
+    #[rustfmt::skip]
+    let messages = decoder.decode(&[
+        0xB0, 0x07, 0xF8, 0x64, // Control change split by a timing clock
+        0x0A, 0xFE, 0x40,       // Control change, running status
+    ]);
+
+    assert_eq!(
+        messages,
+        [
+            MidiMessage::TimingClock,
+            MidiMessage::ControlChange {
+                channel: 0,
+                controller: 0x07,
+                value: 0x64
+            },
+            MidiMessage::ActiveSensing,
+            MidiMessage::ControlChange {
+                channel: 0,
+                controller: 0x0A,
+                value: 0x40
+            },
+        ]
+    );
+}
+
+#[test]
+fn decoder_system_exclusive() {
+    let mut decoder = MidiDecoder::new();
+
+    #[rustfmt::skip]
+    let messages = decoder.decode(&[
+        0xF0, 0x7E, 0x7F, 0x09, 0x01, 0xF7, // GM system on
+        0x3C, 0x64,                         // Data without running status
+        0xF0, 0x41, 0x10, 0x42, 0x12,       // Unterminated system exclusive
+        0xF2, 0x10, 0x02,                   // Song position
+    ]);
+
+    assert_eq!(
+        messages,
+        [
+            MidiMessage::SysEx(vec![0x7E, 0x7F, 0x09, 0x01]),
+            MidiMessage::SongPosition(0x0110),
+        ]
+    );
+}
+
+#[test]
+fn encoder_round_trip() {
+    let messages = [
+        MidiMessage::ProgramChange {
+            channel: 9,
+            program: 0,
+        },
+        MidiMessage::NoteOn {
+            channel: 9,
+            key: 36,
+            velocity: 100,
+        },
+        MidiMessage::TimingClock,
+        MidiMessage::NoteOn {
+            channel: 9,
+            key: 36,
+            velocity: 0,
+        },
+        MidiMessage::SysEx(vec![0x7E, 0x7F, 0x09, 0x01]),
+        MidiMessage::NoteOn {
+            channel: 9,
+            key: 38,
+            velocity: 100,
+        },
+        MidiMessage::TuneRequest,
+        MidiMessage::ChannelPressure {
+            channel: 2,
+            pressure: 0x30,
+        },
+    ];
+
+    let mut encoder = MidiEncoder::new();
+    let mut bytes = Vec::new();
+
+    for message in &messages {
+        encoder.encode(message, &mut bytes);
+    }
+
+    #[rustfmt::skip]
+    assert_eq!(
+        bytes,
+        [
+            0xC9, 0x00,
+            0x99, 0x24, 0x64,
+            0xF8,
+            0x24, 0x00,
+            0xF0, 0x7E, 0x7F, 0x09, 0x01, 0xF7,
+            0x99, 0x26, 0x64,
+            0xF6,
+            0xD2, 0x30,
+        ]
+    );
+
+    assert_eq!(MidiDecoder::new().decode(&bytes), messages);
+}
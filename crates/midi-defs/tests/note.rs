@@ -0,0 +1,40 @@
+use midi_defs::gm::percussion_name;
+use midi_defs::note::{note_from_name, note_name, DEFAULT_MIDDLE_C_OCTAVE, MIDDLE_C};
+
+#[test]
+fn note_names() {
+    assert_eq!(note_name(0, DEFAULT_MIDDLE_C_OCTAVE), "C-1");
+    assert_eq!(note_name(MIDDLE_C, DEFAULT_MIDDLE_C_OCTAVE), "C4");
+    assert_eq!(note_name(69, DEFAULT_MIDDLE_C_OCTAVE), "A4");
+    assert_eq!(note_name(127, DEFAULT_MIDDLE_C_OCTAVE), "G9");
+
+    assert_eq!(note_name(0, 3), "C-2");
+    assert_eq!(note_name(MIDDLE_C, 3), "C3");
+    assert_eq!(note_name(61, 3), "C#3");
+}
+
+#[test]
+fn note_name_round_trip() {
+    for middle_c_octave in [3, 4, 5] {
+        for key in 0..=127 {
+            assert_eq!(
+                note_from_name(&note_name(key, middle_c_octave), middle_c_octave),
+                Some(key)
+            );
+        }
+    }
+
+    assert_eq!(note_from_name("Bb3", DEFAULT_MIDDLE_C_OCTAVE), Some(58));
+    assert_eq!(note_from_name("G#9", DEFAULT_MIDDLE_C_OCTAVE), None);
+    assert_eq!(note_from_name("B-2", DEFAULT_MIDDLE_C_OCTAVE), None);
+    assert_eq!(note_from_name("H4", DEFAULT_MIDDLE_C_OCTAVE), None);
+}
+
+#[test]
+fn percussion_names() {
+    assert_eq!(percussion_name(34), None);
+    assert_eq!(percussion_name(35), Some("Acoustic Bass Drum"));
+    assert_eq!(percussion_name(38), Some("Acoustic Snare"));
+    assert_eq!(percussion_name(81), Some("Open Triangle"));
+    assert_eq!(percussion_name(82), None);
+}
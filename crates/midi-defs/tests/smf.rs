@@ -0,0 +1,229 @@
+use midi_defs::message::MidiMessage;
+use midi_defs::smf::{Smf, SmfError, SmfEvent, SmfFormat, SmfMetaEvent, SmfTimebase};
+
+fn smf_file(format: u16, division: u16, tracks: &[&[u8]]) -> Vec<u8> {
+    let mut buffer = Vec::new();
+
+    buffer.extend(b"MThd");
+    buffer.extend(6u32.to_be_bytes());
+    buffer.extend(format.to_be_bytes());
+    buffer.extend((tracks.len() as u16).to_be_bytes());
+    buffer.extend(division.to_be_bytes());
+
+    for track in tracks {
+        buffer.extend(b"MTrk");
+        buffer.extend((track.len() as u32).to_be_bytes());
+        buffer.extend(*track);
+    }
+
+    buffer
+}
+
+const END_OF_TRACK: [u8; 4] = [0x00, 0xFF, 0x2F, 0x00];
+
+#[test]
+fn running_status_and_variable_length_quantities() {
+    #[rustfmt::skip]
+    let track = [
+        &[0x00, 0x90, 0x3C, 0x64][..],         // Note on
+        &[0x81, 0x00, 0x3C, 0x00],             // Delta time 128, running status
+        &[0xFF, 0xFF, 0x7F, 0xE1, 0x00, 0x40], // Delta time 2^21 - 1, pitch bend
+        &END_OF_TRACK,
+    ]
+    .concat();
+
+    let smf = Smf::new(&smf_file(0, 96, &[&track])).unwrap();
+
+    assert_eq!(smf.format, SmfFormat::SingleTrack);
+    assert_eq!(smf.timebase, SmfTimebase::TicksPerQuarter(96));
+
+    let ticks = smf.tracks[0].iter().map(|e| e.tick).collect::<Vec<_>>();
+    assert_eq!(ticks, [0, 128, 128 + 0x1F_FFFF, 128 + 0x1F_FFFF]);
+
+    assert_eq!(
+        smf.tracks[0][1].event,
+        SmfEvent::Midi(MidiMessage::NoteOn {
+            channel: 0,
+            key: 0x3C,
+            velocity: 0,
+        })
+    );
+    assert_eq!(
+        smf.tracks[0][2].event,
+        SmfEvent::Midi(MidiMessage::PitchBend {
+            channel: 1,
+            value: 0x2000,
+        })
+    );
+}
+
+#[test]
+fn meta_and_sysex_events() {
+    #[rustfmt::skip]
+    let track = [
+        &[0x00, 0xFF, 0x03, 0x04][..], b"Lead",
+        &[0x00, 0xFF, 0x58, 0x04, 0x06, 0x03, 0x18, 0x08],
+        &[0x00, 0xFF, 0x59, 0x02, 0xFD, 0x01],
+        &[0x00, 0xFF, 0x06, 0x05], b"Verse",
+        &[0x00, 0xF0, 0x05, 0x7E, 0x7F, 0x09, 0x01, 0xF7],
+        &END_OF_TRACK,
+    ]
+    .concat();
+
+    let smf = Smf::new(&smf_file(0, 96, &[&track])).unwrap();
+    let events = smf.tracks[0].iter().map(|e| &e.event).collect::<Vec<_>>();
+
+    assert_eq!(
+        events,
+        [
+            &SmfEvent::Meta(SmfMetaEvent::TrackName("Lead".to_owned())),
+            &SmfEvent::Meta(SmfMetaEvent::TimeSignature {
+                numerator: 6,
+                denominator: 8,
+                clocks_per_click: 24,
+                notated_32nds_per_quarter: 8,
+            }),
+            &SmfEvent::Meta(SmfMetaEvent::KeySignature {
+                sharps: -3,
+                is_minor: true,
+            }),
+            &SmfEvent::Meta(SmfMetaEvent::Marker("Verse".to_owned())),
+            &SmfEvent::SysEx(vec![0x7E, 0x7F, 0x09, 0x01, 0xF7]),
+            &SmfEvent::Meta(SmfMetaEvent::EndOfTrack),
+        ]
+    );
+}
+
+#[test]
+fn merged_events_follow_tempo_map() {
+    #[rustfmt::skip]
+    let conductor_track = [
+        &[0x00, 0xFF, 0x51, 0x03, 0x07, 0xA1, 0x20][..],  // 500000 us
+        &[0x83, 0x60, 0xFF, 0x51, 0x03, 0x03, 0xD0, 0x90], // 480 ticks, 250000 us
+        &END_OF_TRACK,
+    ]
+    .concat();
+
+    #[rustfmt::skip]
+    let note_track = [
+        &[0x83, 0x60, 0x90, 0x3C, 0x64][..], // 480 ticks
+        &[0x83, 0x60, 0x80, 0x3C, 0x00],     // 960 ticks
+        &END_OF_TRACK,
+    ]
+    .concat();
+
+    let smf = Smf::new(&smf_file(1, 480, &[&conductor_track, &note_track])).unwrap();
+
+    let tempo_map = smf.tempo_map();
+    assert_eq!(tempo_map.tempo_at(0), 500_000);
+    assert_eq!(tempo_map.tempo_at(480), 250_000);
+
+    let events = smf
+        .events()
+        .map(|timed_event| {
+            (
+                timed_event.tick,
+                timed_event.seconds,
+                timed_event.track_index,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        events,
+        [
+            (0, 0.0, 0),
+            (480, 0.5, 0),
+            (480, 0.5, 0),
+            (480, 0.5, 1),
+            (960, 0.75, 1),
+            (960, 0.75, 1),
+        ]
+    );
+}
+
+#[test]
+fn format_2_sequences_are_consecutive() {
+    let track = [&[0x60, 0x90, 0x3C, 0x64][..], &END_OF_TRACK].concat();
+
+    let smf = Smf::new(&smf_file(2, 96, &[&track, &track])).unwrap();
+    let ticks = smf
+        .events()
+        .map(|timed_event| timed_event.tick)
+        .collect::<Vec<_>>();
+
+    assert_eq!(ticks, [96, 96, 192, 192]);
+}
+
+#[test]
+fn smpte_timebase() {
+    let track = [&[0x81, 0x64, 0x90, 0x3C, 0x64][..], &END_OF_TRACK].concat();
+
+    // 25 fps, 40 ticks per frame: milliseconds
+    let division = u16::from_be_bytes([(-25i8) as u8, 40]);
+    let smf = Smf::new(&smf_file(0, division, &[&track])).unwrap();
+
+    assert_eq!(
+        smf.timebase,
+        SmfTimebase::Smpte {
+            frames_per_second: 25,
+            ticks_per_frame: 40,
+        }
+    );
+
+    let timed_event = smf.events().next().unwrap();
+    assert_eq!(timed_event.tick, 228);
+    assert!((timed_event.seconds - 0.228).abs() < 1e-9);
+
+    for frames_per_second in [24, 29, 30] {
+        let division = u16::from_be_bytes([(-(frames_per_second as i8)) as u8, 4]);
+        let smf = Smf::new(&smf_file(0, division, &[&track])).unwrap();
+
+        assert_eq!(
+            smf.timebase,
+            SmfTimebase::Smpte {
+                frames_per_second,
+                ticks_per_frame: 4,
+            }
+        );
+    }
+}
+
+#[test]
+fn invalid_divisions() {
+    let track = END_OF_TRACK;
+
+    // No ticks per quarter note, -128 fps, whose negation overflows, an
+    // unknown frame rate and a frame without ticks
+    for division in [0x0000, 0x8028, 0xE628, 0xE700] {
+        assert!(
+            matches!(
+                Smf::new(&smf_file(0, division, &[&track])),
+                Err(SmfError::InvalidDivision { division: error_division })
+                    if error_division == division
+            ),
+            "{division:#06X}"
+        );
+    }
+}
+
+#[test]
+fn malformed_files() {
+    assert!(matches!(
+        Smf::new(b"RIFF"),
+        Err(SmfError::MissingHeaderChunk)
+    ));
+
+    // Data byte without a preceding status byte.
+    let track = [0x00, 0x3C, 0x64];
+    assert!(matches!(
+        Smf::new(&smf_file(0, 96, &[&track])),
+        Err(SmfError::MissingRunningStatus { offset: 23 })
+    ));
+
+    let track = [0x00, 0x90, 0x3C];
+    assert!(matches!(
+        Smf::new(&smf_file(0, 96, &[&track])),
+        Err(SmfError::UnexpectedEndOfData { .. })
+    ));
+}
@@ -0,0 +1,78 @@
+use midi_defs::sound_set::{lookup_bank_preset, SoundSet};
+
+#[test]
+fn capital_tones() {
+    assert_eq!(
+        lookup_bank_preset((0, 0)),
+        [
+            (SoundSet::GeneralMidi, "Acoustic Grand Piano"),
+            (SoundSet::GeneralMidi2, "Acoustic Grand Piano"),
+            (SoundSet::RolandGs, "Piano 1"),
+            (SoundSet::YamahaXg, "GrandPno"),
+        ]
+    );
+}
+
+#[test]
+fn variation_banks() {
+    // GS variation by bank MSB, GM2 variation by bank LSB
+    assert_eq!(
+        lookup_bank_preset((8, 4)),
+        [(SoundSet::RolandGs, "Detuned EP 1")]
+    );
+    assert_eq!(
+        lookup_bank_preset((3, 4)),
+        [(SoundSet::GeneralMidi2, "60's Electric Piano")]
+    );
+    assert_eq!(
+        lookup_bank_preset((1, 122)),
+        [
+            (SoundSet::GeneralMidi2, "Rain"),
+            (SoundSet::RolandGs, "Rain"),
+        ]
+    );
+
+    // 14-bit bank numbers
+    assert_eq!(
+        lookup_bank_preset((121 * 128 + 2, 0)),
+        [(SoundSet::GeneralMidi2, "Acoustic Grand Piano (dark)")]
+    );
+
+    // XG SFX voices
+    assert_eq!(
+        lookup_bank_preset((64, 84)),
+        [(SoundSet::YamahaXg, "Siren")]
+    );
+
+    assert_eq!(lookup_bank_preset((0, 128)), []);
+    assert_eq!(lookup_bank_preset((300, 0)), []);
+}
+
+#[test]
+fn drum_kits() {
+    assert_eq!(
+        lookup_bank_preset((128, 0)),
+        [
+            (SoundSet::GeneralMidi, "Standard Drum Kit"),
+            (SoundSet::GeneralMidi2, "Standard Set"),
+            (SoundSet::RolandGs, "STANDARD"),
+            (SoundSet::YamahaXg, "Standard Kit"),
+        ]
+    );
+    assert_eq!(
+        lookup_bank_preset((128, 25)),
+        [
+            (SoundSet::GeneralMidi2, "Analog Set"),
+            (SoundSet::RolandGs, "TR-808"),
+            (SoundSet::YamahaXg, "Analog Kit"),
+        ]
+    );
+    assert_eq!(
+        lookup_bank_preset((128, 40)),
+        [
+            (SoundSet::GeneralMidi2, "Brush Set"),
+            (SoundSet::RolandGs, "BRUSH"),
+            (SoundSet::YamahaXg, "Brush Kit"),
+        ]
+    );
+}
@@ -1,3 +1,5 @@
+use std::io;
+
 use derive_more::{Display, Error, From};
 
 #[rustfmt::skip]
@@ -6,9 +8,10 @@ pub enum RiffError {
     #[display(fmt = "Missing chunk")]
     MissingChunk,
 
-    #[display(fmt = "Missing subchunk '{chunk_id}'")]
+    #[display(fmt = "Missing subchunk '{chunk_id}' in chunk at offset {offset:#010X}")]
     MissingSubchunk {
         chunk_id: String,
+        offset: usize,
     },
 
     #[display(fmt = "Normal chunks cannot have subchunks")]
@@ -17,9 +20,30 @@ pub enum RiffError {
     #[display(fmt = "Container chunks cannot have data")]
     ContainerChunkNoData,
 
-    #[display(fmt = "Truncated chunk data")]
-    TruncatedChunkData,
+    #[display(fmt = "Truncated chunk data at offset {offset:#010X}")]
+    TruncatedChunkData {
+        offset: usize,
+    },
+
+    #[display(fmt = "Malformed identifier at offset {offset:#010X}")]
+    MalformedIdentifier {
+        offset: usize,
+    },
+
+    #[display(fmt = "Malformed chunk at offset {offset:#010X}")]
+    MalformedChunk {
+        offset: usize,
+    },
+
+    #[display(fmt = "No open chunk")]
+    NoOpenChunk,
+
+    #[display(fmt = "Unclosed chunk")]
+    UnclosedChunk,
+
+    #[display(fmt = "Chunk too large")]
+    ChunkTooLarge,
 
-    #[display(fmt = "Malformed identifier")]
-    MalformedIdentifier,
+    #[from]
+    Io(io::Error),
 }
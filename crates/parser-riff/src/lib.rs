@@ -1,9 +1,15 @@
 mod error;
 mod raw_chunk;
 mod riff_chunk;
+mod riff_stream_chunk;
+mod riff_warning;
+mod riff_writer;
 
 pub use error::RiffError;
 pub use raw_chunk::{RawChunk, RawChunkIterator};
 pub use riff_chunk::RiffChunk;
+pub use riff_stream_chunk::RiffStreamChunk;
+pub use riff_warning::{RiffParseMode, RiffWarning};
+pub use riff_writer::RiffWriter;
 
 pub type RiffResult<T> = Result<T, RiffError>;
@@ -1,25 +1,83 @@
-use crate::{RiffError, RiffResult};
+use crate::riff_chunk::from_fourcc;
+use crate::{RiffError, RiffParseMode, RiffResult, RiffWarning};
 
 pub enum RawChunk<'a> {
     Container {
         chunk_type: &'a [u8],
         chunk_id: &'a [u8],
         chunk_data: &'a [u8],
+        chunk_offset: usize,
+        chunk_size: u32,
+        chunk_padding: usize,
     },
     Normal {
         chunk_id: &'a [u8],
         chunk_data: &'a [u8],
+        chunk_offset: usize,
+        chunk_size: u32,
+        chunk_padding: usize,
     },
 }
 
+impl<'a> RawChunk<'a> {
+    /// Absolute offset of the chunk header.
+    pub fn chunk_offset(&self) -> usize {
+        match self {
+            RawChunk::Container { chunk_offset, .. } => *chunk_offset,
+            RawChunk::Normal { chunk_offset, .. } => *chunk_offset,
+        }
+    }
+
+    /// Chunk size as declared in the chunk header, which may differ from the
+    /// size of the chunk data when parsed in lenient mode.
+    pub fn chunk_size(&self) -> u32 {
+        match self {
+            RawChunk::Container { chunk_size, .. } => *chunk_size,
+            RawChunk::Normal { chunk_size, .. } => *chunk_size,
+        }
+    }
+
+    /// Number of pad bytes following the chunk data.
+    pub fn chunk_padding(&self) -> usize {
+        match self {
+            RawChunk::Container { chunk_padding, .. } => *chunk_padding,
+            RawChunk::Normal { chunk_padding, .. } => *chunk_padding,
+        }
+    }
+}
+
 pub struct RawChunkIterator<'a> {
     buffer: &'a [u8],
     i: usize,
+    buffer_offset: usize,
+    parse_mode: RiffParseMode,
+    warnings: Vec<RiffWarning>,
 }
 
 impl<'a> RawChunkIterator<'a> {
     pub fn new(buffer: &'a [u8]) -> Self {
-        Self { buffer, i: 0 }
+        Self::with_mode(buffer, 0, RiffParseMode::Strict)
+    }
+
+    /// Creates an iterator over `buffer`, which starts at `buffer_offset`
+    /// in the file. The offset is only used for reporting warnings.
+    pub fn with_mode(buffer: &'a [u8], buffer_offset: usize, parse_mode: RiffParseMode) -> Self {
+        Self {
+            buffer,
+            i: 0,
+            buffer_offset,
+            parse_mode,
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Warnings collected so far, only ever populated in lenient mode.
+    pub fn warnings(&self) -> &[RiffWarning] {
+        &self.warnings
+    }
+
+    pub fn into_warnings(self) -> Vec<RiffWarning> {
+        self.warnings
     }
 }
 
@@ -27,44 +85,111 @@ impl<'a> Iterator for RawChunkIterator<'a> {
     type Item = RiffResult<RawChunk<'a>>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.i + 8 <= self.buffer.len() {
+        let is_lenient = self.parse_mode == RiffParseMode::Lenient;
+
+        loop {
+            if self.i + 8 > self.buffer.len() {
+                if is_lenient && (self.i < self.buffer.len()) {
+                    self.warnings.push(RiffWarning::TrailingBytes {
+                        offset: self.buffer_offset + self.i,
+                        length: self.buffer.len() - self.i,
+                    });
+                    self.i = self.buffer.len();
+                }
+
+                return None;
+            }
+
+            let chunk_offset = self.buffer_offset + self.i;
+
             let chunk_id = &self.buffer[self.i..self.i + 4];
             let is_container = (chunk_id == b"RIFF") || (chunk_id == b"LIST");
 
             let chunk_size =
                 u32::from_le_bytes(self.buffer[self.i + 4..self.i + 8].try_into().unwrap());
+            let mut chunk_end = self.i + 8 + (chunk_size as usize);
 
-            if self.i + 8 + (chunk_size as usize) <= self.buffer.len() {
-                let chunk = if is_container {
-                    let chunk_type = chunk_id;
-                    let chunk_id = &self.buffer[self.i + 8..self.i + 12];
+            if chunk_end > self.buffer.len() {
+                if !is_lenient {
+                    return Some(Err(RiffError::TruncatedChunkData {
+                        offset: chunk_offset,
+                    }));
+                }
 
-                    let chunk_data =
-                        &self.buffer[self.i + 12..self.i + 12 + ((chunk_size as usize) - 4)];
+                self.warnings.push(RiffWarning::ChunkSizeClamped {
+                    offset: chunk_offset,
+                    declared_size: chunk_size,
+                    clamped_size: (self.buffer.len() - self.i - 8) as u32,
+                });
+                chunk_end = self.buffer.len();
+            }
 
-                    Some(Ok(RawChunk::Container {
-                        chunk_type,
-                        chunk_id,
-                        chunk_data,
-                    }))
+            let mut chunk_padding =
+                if ((chunk_end - self.i) % 2 == 1) && (chunk_end < self.buffer.len()) {
+                    1
                 } else {
-                    let chunk_data = &self.buffer[self.i + 8..self.i + 8 + (chunk_size as usize)];
+                    0
+                };
 
-                    Some(Ok(RawChunk::Normal {
-                        chunk_id,
-                        chunk_data,
-                    }))
+            // A missing pad byte shows as the next chunk header starting
+            // right after the chunk data instead of one byte later.
+            if is_lenient && (chunk_padding == 1) {
+                let has_chunk_id = |position: usize| {
+                    self.buffer
+                        .get(position..position + 4)
+                        .is_some_and(|chunk_id| from_fourcc(chunk_id, position).is_ok())
                 };
 
-                self.i = self.i + 8 + (chunk_size as usize);
-                self.i = self.i.next_multiple_of(2);
+                if has_chunk_id(chunk_end) && !has_chunk_id(chunk_end + 1) {
+                    self.warnings.push(RiffWarning::MissingPadByte {
+                        offset: chunk_offset,
+                    });
+                    chunk_padding = 0;
+                }
+            }
+
+            let chunk = if is_container {
+                if chunk_end < self.i + 12 {
+                    if !is_lenient {
+                        return Some(Err(RiffError::TruncatedChunkData {
+                            offset: chunk_offset,
+                        }));
+                    }
+
+                    self.warnings.push(RiffWarning::MalformedContainer {
+                        offset: chunk_offset,
+                    });
+                    self.i = chunk_end.next_multiple_of(2);
+                    continue;
+                }
+
+                let chunk_type = chunk_id;
+                let chunk_id = &self.buffer[self.i + 8..self.i + 12];
+                let chunk_data = &self.buffer[self.i + 12..chunk_end];
 
-                chunk
+                RawChunk::Container {
+                    chunk_type,
+                    chunk_id,
+                    chunk_data,
+                    chunk_offset,
+                    chunk_size,
+                    chunk_padding,
+                }
             } else {
-                Some(Err(RiffError::TruncatedChunkData))
-            }
-        } else {
-            None
+                let chunk_data = &self.buffer[self.i + 8..chunk_end];
+
+                RawChunk::Normal {
+                    chunk_id,
+                    chunk_data,
+                    chunk_offset,
+                    chunk_size,
+                    chunk_padding,
+                }
+            };
+
+            self.i = chunk_end + chunk_padding;
+
+            return Some(Ok(chunk));
         }
     }
 }
@@ -1,17 +1,23 @@
 use std::fmt::{self, Debug};
 use std::str;
 
-use crate::{RawChunk, RawChunkIterator, RiffError, RiffResult};
+use crate::{RawChunk, RawChunkIterator, RiffError, RiffParseMode, RiffResult, RiffWarning};
 
 pub enum RiffChunk<'a> {
     Container {
         chunk_type: &'a str,
         chunk_id: &'a str,
         subchunks: Vec<RiffChunk<'a>>,
+        chunk_offset: usize,
+        chunk_size: u32,
+        chunk_padding: usize,
     },
     Normal {
         chunk_id: &'a str,
         chunk_data: &'a [u8],
+        chunk_offset: usize,
+        chunk_size: u32,
+        chunk_padding: usize,
     },
 }
 
@@ -22,75 +28,253 @@ impl<'a> Debug for RiffChunk<'a> {
                 chunk_type,
                 chunk_id,
                 subchunks,
+                chunk_offset,
+                chunk_size,
+                chunk_padding,
             } => f
                 .debug_struct("RiffChunk::Container")
                 .field("chunk_type", chunk_type)
                 .field("chunk_id", chunk_id)
                 .field("subchunks", subchunks)
+                .field("chunk_offset", chunk_offset)
+                .field("chunk_size", chunk_size)
+                .field("chunk_padding", chunk_padding)
                 .finish(),
-            RiffChunk::Normal { chunk_id, .. } => f
+            RiffChunk::Normal {
+                chunk_id,
+                chunk_offset,
+                chunk_size,
+                chunk_padding,
+                ..
+            } => f
                 .debug_struct("RiffChunk::Normal")
                 .field("chunk_id", chunk_id)
                 .field("chunk_data", &"...")
+                .field("chunk_offset", chunk_offset)
+                .field("chunk_size", chunk_size)
+                .field("chunk_padding", chunk_padding)
                 .finish(),
         }
     }
 }
 
-impl<'a> TryFrom<RawChunk<'a>> for RiffChunk<'a> {
-    type Error = RiffError;
+pub(crate) fn from_fourcc(input: &[u8], chunk_offset: usize) -> RiffResult<&str> {
+    let (left, right) = {
+        let split_position = input.iter().position(|&b| b == b' ').unwrap_or(input.len());
+        input.split_at(split_position)
+    };
 
-    fn try_from(raw_chunk: RawChunk<'a>) -> RiffResult<Self> {
-        fn from_fourcc(input: &[u8]) -> RiffResult<&str> {
-            let (left, right) = {
-                let split_position = input.iter().position(|&b| b == b' ').unwrap_or(input.len());
-                input.split_at(split_position)
-            };
-
-            if left.iter().all(|&b| b.is_ascii_alphanumeric())
-                && right.iter().all(|&b| b == b' ')
-                && !left.is_empty()
-            {
-                Ok(unsafe { str::from_utf8_unchecked(input) })
-            } else {
-                Err(RiffError::MalformedIdentifier)
-            }
+    if left.iter().all(|&b| b.is_ascii_alphanumeric())
+        && right.iter().all(|&b| b == b' ')
+        && !left.is_empty()
+    {
+        Ok(unsafe { str::from_utf8_unchecked(input) })
+    } else {
+        Err(RiffError::MalformedIdentifier {
+            offset: chunk_offset,
+        })
+    }
+}
+
+fn fourcc<'a>(
+    input: &'a [u8],
+    chunk_offset: usize,
+    parse_mode: RiffParseMode,
+    warnings: &mut Vec<RiffWarning>,
+) -> RiffResult<&'a str> {
+    match (from_fourcc(input, chunk_offset), parse_mode) {
+        (Err(_), RiffParseMode::Lenient) => {
+            warnings.push(RiffWarning::MalformedIdentifier {
+                offset: chunk_offset,
+                identifier: input.try_into().unwrap(),
+            });
+            str::from_utf8(input).map_err(|_| RiffError::MalformedIdentifier {
+                offset: chunk_offset,
+            })
         }
+        (result, _) => result,
+    }
+}
 
-        match raw_chunk {
-            RawChunk::Container {
-                chunk_type,
-                chunk_id,
-                chunk_data,
-            } => {
-                let subchunks = RawChunkIterator::new(chunk_data)
-                    .map(|raw_chunk| RiffChunk::try_from(raw_chunk?))
-                    .collect::<RiffResult<Vec<RiffChunk>>>()?;
-
-                Ok(RiffChunk::Container {
-                    chunk_type: from_fourcc(chunk_type)?,
-                    chunk_id: from_fourcc(chunk_id)?,
-                    subchunks,
-                })
+fn from_raw_chunk<'a>(
+    raw_chunk: RawChunk<'a>,
+    parse_mode: RiffParseMode,
+    warnings: &mut Vec<RiffWarning>,
+) -> RiffResult<RiffChunk<'a>> {
+    match raw_chunk {
+        RawChunk::Container {
+            chunk_type,
+            chunk_id,
+            chunk_data,
+            chunk_offset,
+            chunk_size,
+            chunk_padding,
+        } => {
+            let chunk_type = fourcc(chunk_type, chunk_offset, parse_mode, warnings)?;
+            let chunk_id = fourcc(chunk_id, chunk_offset, parse_mode, warnings)?;
+
+            let mut raw_chunk_iterator =
+                RawChunkIterator::with_mode(chunk_data, chunk_offset + 12, parse_mode);
+            let mut subchunks = Vec::new();
+
+            while let Some(raw_subchunk) = raw_chunk_iterator.next().transpose()? {
+                let subchunk_offset = raw_subchunk.chunk_offset();
+
+                match from_raw_chunk(raw_subchunk, parse_mode, warnings) {
+                    Ok(subchunk) => subchunks.push(subchunk),
+                    Err(_) if parse_mode == RiffParseMode::Lenient => {
+                        warnings.push(RiffWarning::SkippedChunk {
+                            offset: subchunk_offset,
+                        });
+                    }
+                    Err(err) => return Err(err),
+                }
             }
-            RawChunk::Normal {
+
+            warnings.extend(raw_chunk_iterator.into_warnings());
+
+            Ok(RiffChunk::Container {
+                chunk_type,
                 chunk_id,
-                chunk_data,
-            } => Ok(RiffChunk::Normal {
-                chunk_id: from_fourcc(chunk_id)?,
-                chunk_data,
-            }),
+                subchunks,
+                chunk_offset,
+                chunk_size,
+                chunk_padding,
+            })
         }
+        RawChunk::Normal {
+            chunk_id,
+            chunk_data,
+            chunk_offset,
+            chunk_size,
+            chunk_padding,
+        } => Ok(RiffChunk::Normal {
+            chunk_id: fourcc(chunk_id, chunk_offset, parse_mode, warnings)?,
+            chunk_data,
+            chunk_offset,
+            chunk_size,
+            chunk_padding,
+        }),
+    }
+}
+
+/// Length of the chunk header and data, without the padding.
+fn raw_chunk_length(raw_chunk: &RawChunk) -> usize {
+    match raw_chunk {
+        RawChunk::Container { chunk_data, .. } => 12 + chunk_data.len(),
+        RawChunk::Normal { chunk_data, .. } => 8 + chunk_data.len(),
+    }
+}
+
+/// Whether the buffer holds nothing but well-formed chunks.
+fn is_chunk_sequence(buffer: &[u8]) -> bool {
+    let mut length = 0;
+
+    for raw_chunk in RawChunkIterator::new(buffer) {
+        let Ok(raw_chunk) = raw_chunk else {
+            return false;
+        };
+
+        length += raw_chunk_length(&raw_chunk) + raw_chunk.chunk_padding();
+
+        if RiffChunk::try_from(raw_chunk).is_err() {
+            return false;
+        }
+    }
+
+    (length > 0) && (length == buffer.len())
+}
+
+impl<'a> TryFrom<RawChunk<'a>> for RiffChunk<'a> {
+    type Error = RiffError;
+
+    fn try_from(raw_chunk: RawChunk<'a>) -> RiffResult<Self> {
+        from_raw_chunk(raw_chunk, RiffParseMode::Strict, &mut Vec::new())
     }
 }
 
 impl<'a> RiffChunk<'a> {
-    pub fn new(buffer: &[u8]) -> RiffResult<RiffChunk> {
-        let raw_chunk = RawChunkIterator::new(buffer)
+    /// Parses the chunk tree in strict mode. Any data following the root
+    /// chunk is ignored.
+    pub fn new(buffer: &[u8]) -> RiffResult<RiffChunk<'_>> {
+        Self::with_mode(buffer, RiffParseMode::Strict).map(|(chunk, _)| chunk)
+    }
+
+    /// Parses the chunk tree in the given mode, returning the warnings
+    /// collected along the way sorted by their offsets. Strict mode never
+    /// produces warnings.
+    pub fn with_mode(
+        buffer: &[u8],
+        parse_mode: RiffParseMode,
+    ) -> RiffResult<(RiffChunk<'_>, Vec<RiffWarning>)> {
+        Self::with_offset(buffer, 0, parse_mode)
+    }
+
+    /// Same as `with_mode`, for buffers holding a single chunk loaded from
+    /// `buffer_offset` of a larger file. The chunk offsets are reported
+    /// relative to the start of that file.
+    pub fn with_offset(
+        buffer: &[u8],
+        buffer_offset: usize,
+        parse_mode: RiffParseMode,
+    ) -> RiffResult<(RiffChunk<'_>, Vec<RiffWarning>)> {
+        let mut raw_chunk_iterator = RawChunkIterator::with_mode(buffer, buffer_offset, parse_mode);
+        let raw_chunk = raw_chunk_iterator
             .next()
             .transpose()?
             .ok_or(RiffError::MissingChunk)?;
-        raw_chunk.try_into()
+
+        let mut warnings = raw_chunk_iterator.into_warnings();
+
+        let root_end = raw_chunk.chunk_offset() - buffer_offset
+            + raw_chunk_length(&raw_chunk)
+            + raw_chunk.chunk_padding();
+        let trailing_bytes = &buffer[root_end..];
+
+        let raw_chunk = match (raw_chunk, parse_mode) {
+            // Strict mode ignores any data following the root chunk.
+            (raw_chunk, RiffParseMode::Strict) => raw_chunk,
+            (raw_chunk, _) if trailing_bytes.is_empty() => raw_chunk,
+            // Chunks following the root container are taken as its subchunks,
+            // its declared size having been too small.
+            (
+                RawChunk::Container {
+                    chunk_type,
+                    chunk_id,
+                    chunk_offset,
+                    chunk_size,
+                    ..
+                },
+                RiffParseMode::Lenient,
+            ) if is_chunk_sequence(trailing_bytes) => {
+                warnings.push(RiffWarning::ChunkSizeExtended {
+                    offset: chunk_offset,
+                    declared_size: chunk_size,
+                    extended_size: (buffer.len() - 8) as u32,
+                });
+
+                RawChunk::Container {
+                    chunk_type,
+                    chunk_id,
+                    chunk_data: &buffer[12..],
+                    chunk_offset,
+                    chunk_size,
+                    chunk_padding: 0,
+                }
+            }
+            (raw_chunk, RiffParseMode::Lenient) => {
+                warnings.push(RiffWarning::TrailingBytes {
+                    offset: buffer_offset + root_end,
+                    length: trailing_bytes.len(),
+                });
+                raw_chunk
+            }
+        };
+
+        let chunk = from_raw_chunk(raw_chunk, parse_mode, &mut warnings)?;
+        warnings.sort_by_key(RiffWarning::offset);
+
+        Ok((chunk, warnings))
     }
 
     pub fn chunk_id(&self) -> &'a str {
@@ -100,6 +284,31 @@ impl<'a> RiffChunk<'a> {
         }
     }
 
+    /// Absolute offset of the chunk header.
+    pub fn chunk_offset(&self) -> usize {
+        match self {
+            RiffChunk::Container { chunk_offset, .. } => *chunk_offset,
+            RiffChunk::Normal { chunk_offset, .. } => *chunk_offset,
+        }
+    }
+
+    /// Chunk size as declared in the chunk header, which may differ from the
+    /// size of the chunk data when parsed in lenient mode.
+    pub fn chunk_size(&self) -> u32 {
+        match self {
+            RiffChunk::Container { chunk_size, .. } => *chunk_size,
+            RiffChunk::Normal { chunk_size, .. } => *chunk_size,
+        }
+    }
+
+    /// Number of pad bytes following the chunk data.
+    pub fn chunk_padding(&self) -> usize {
+        match self {
+            RiffChunk::Container { chunk_padding, .. } => *chunk_padding,
+            RiffChunk::Normal { chunk_padding, .. } => *chunk_padding,
+        }
+    }
+
     pub fn chunk_data(&self) -> RiffResult<&'a [u8]> {
         match self {
             RiffChunk::Container { .. } => Err(RiffError::ContainerChunkNoData),
@@ -121,6 +330,7 @@ impl<'a> RiffChunk<'a> {
             .transpose()
             .ok_or(RiffError::MissingSubchunk {
                 chunk_id: chunk_id.to_owned(),
+                offset: self.chunk_offset(),
             })?
     }
 
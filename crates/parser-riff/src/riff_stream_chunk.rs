@@ -0,0 +1,200 @@
+use std::io::{Read, Seek, SeekFrom};
+use std::ops::Range;
+
+use crate::riff_chunk::from_fourcc;
+use crate::{RiffError, RiffResult};
+
+/// Chunk tree of a RIFF stream, built from the chunk headers only.
+///
+/// Unlike `RiffChunk` it does not hold the chunk data, which can be loaded
+/// on demand from the same stream it was parsed from.
+#[derive(Debug, Clone)]
+pub enum RiffStreamChunk {
+    Container {
+        chunk_type: String,
+        chunk_id: String,
+        chunk_offset: u64,
+        chunk_size: u32,
+        subchunks: Vec<RiffStreamChunk>,
+    },
+    Normal {
+        chunk_id: String,
+        chunk_offset: u64,
+        chunk_size: u32,
+    },
+}
+
+fn read_chunk_header<R: Read>(reader: &mut R) -> RiffResult<([u8; 4], u32)> {
+    let mut header = [0; 8];
+    reader.read_exact(&mut header)?;
+
+    let (chunk_id, chunk_size) = header.split_at(4);
+    Ok((
+        chunk_id.try_into().unwrap(),
+        u32::from_le_bytes(chunk_size.try_into().unwrap()),
+    ))
+}
+
+impl RiffStreamChunk {
+    /// Parses the chunk tree starting at the beginning of the stream.
+    pub fn new<R: Read + Seek>(reader: &mut R) -> RiffResult<RiffStreamChunk> {
+        let stream_length = reader.seek(SeekFrom::End(0))?;
+
+        if stream_length < 8 {
+            return Err(RiffError::MissingChunk);
+        }
+
+        Self::parse(reader, 0, stream_length)
+    }
+
+    fn parse<R: Read + Seek>(
+        reader: &mut R,
+        chunk_offset: u64,
+        stream_end: u64,
+    ) -> RiffResult<RiffStreamChunk> {
+        reader.seek(SeekFrom::Start(chunk_offset))?;
+        let (chunk_id, chunk_size) = read_chunk_header(reader)?;
+
+        let chunk_end = chunk_offset + 8 + chunk_size as u64;
+        if chunk_end > stream_end {
+            return Err(RiffError::TruncatedChunkData {
+                offset: chunk_offset as usize,
+            });
+        }
+
+        if (&chunk_id == b"RIFF") || (&chunk_id == b"LIST") {
+            // The list type is part of the chunk data.
+            if chunk_size < 4 {
+                return Err(RiffError::MalformedChunk {
+                    offset: chunk_offset as usize,
+                });
+            }
+
+            let chunk_type = chunk_id;
+
+            let mut chunk_id = [0; 4];
+            reader.read_exact(&mut chunk_id)?;
+
+            let mut subchunks = Vec::new();
+            let mut subchunk_offset = chunk_offset + 12;
+
+            while subchunk_offset + 8 <= chunk_end {
+                let subchunk = Self::parse(reader, subchunk_offset, chunk_end)?;
+
+                subchunk_offset = (subchunk.chunk_offset() + 8 + subchunk.chunk_size() as u64)
+                    .next_multiple_of(2);
+                subchunks.push(subchunk);
+            }
+
+            Ok(RiffStreamChunk::Container {
+                chunk_type: from_fourcc(&chunk_type, chunk_offset as usize)?.to_owned(),
+                chunk_id: from_fourcc(&chunk_id, chunk_offset as usize)?.to_owned(),
+                chunk_offset,
+                chunk_size,
+                subchunks,
+            })
+        } else {
+            Ok(RiffStreamChunk::Normal {
+                chunk_id: from_fourcc(&chunk_id, chunk_offset as usize)?.to_owned(),
+                chunk_offset,
+                chunk_size,
+            })
+        }
+    }
+
+    pub fn chunk_id(&self) -> &str {
+        match self {
+            RiffStreamChunk::Container { chunk_id, .. } => chunk_id,
+            RiffStreamChunk::Normal { chunk_id, .. } => chunk_id,
+        }
+    }
+
+    /// Absolute offset of the chunk header in the stream.
+    pub fn chunk_offset(&self) -> u64 {
+        match self {
+            RiffStreamChunk::Container { chunk_offset, .. } => *chunk_offset,
+            RiffStreamChunk::Normal { chunk_offset, .. } => *chunk_offset,
+        }
+    }
+
+    /// Size of the chunk as it would be declared in its header.
+    pub fn chunk_size(&self) -> u32 {
+        match self {
+            RiffStreamChunk::Container { chunk_size, .. } => *chunk_size,
+            RiffStreamChunk::Normal { chunk_size, .. } => *chunk_size,
+        }
+    }
+
+    pub fn subchunk_opt(&self, chunk_id: &str) -> RiffResult<Option<&RiffStreamChunk>> {
+        match self {
+            RiffStreamChunk::Container { subchunks, .. } => Ok(subchunks
+                .iter()
+                .find(|subchunk| subchunk.chunk_id() == chunk_id)),
+            RiffStreamChunk::Normal { .. } => Err(RiffError::NormalChunkNoSubchunks),
+        }
+    }
+
+    pub fn subchunk(&self, chunk_id: &str) -> RiffResult<&RiffStreamChunk> {
+        self.subchunk_opt(chunk_id)
+            .transpose()
+            .ok_or(RiffError::MissingSubchunk {
+                chunk_id: chunk_id.to_owned(),
+                offset: self.chunk_offset() as usize,
+            })?
+    }
+
+    pub fn subchunks(&self) -> RiffResult<&[RiffStreamChunk]> {
+        match self {
+            RiffStreamChunk::Container { subchunks, .. } => Ok(subchunks),
+            RiffStreamChunk::Normal { .. } => Err(RiffError::NormalChunkNoSubchunks),
+        }
+    }
+
+    pub fn is_container(&self) -> bool {
+        matches!(self, RiffStreamChunk::Container { .. })
+    }
+
+    /// Reads the whole chunk including its header, which then can be parsed
+    /// by `RiffChunk::new`.
+    pub fn read_chunk<R: Read + Seek>(&self, reader: &mut R) -> RiffResult<Vec<u8>> {
+        let mut buffer = vec![0; 8 + self.chunk_size() as usize];
+
+        reader.seek(SeekFrom::Start(self.chunk_offset()))?;
+        reader.read_exact(&mut buffer)?;
+
+        Ok(buffer)
+    }
+
+    pub fn read_chunk_data<R: Read + Seek>(&self, reader: &mut R) -> RiffResult<Vec<u8>> {
+        self.read_chunk_data_range(reader, 0..self.chunk_size())
+    }
+
+    /// Reads a byte range of the data of a normal chunk.
+    pub fn read_chunk_data_range<R: Read + Seek>(
+        &self,
+        reader: &mut R,
+        range: Range<u32>,
+    ) -> RiffResult<Vec<u8>> {
+        match self {
+            RiffStreamChunk::Container { .. } => Err(RiffError::ContainerChunkNoData),
+            RiffStreamChunk::Normal {
+                chunk_offset,
+                chunk_size,
+                ..
+            } => {
+                if (range.start > range.end) || (range.end > *chunk_size) {
+                    return Err(RiffError::TruncatedChunkData {
+                        offset: *chunk_offset as usize,
+                    });
+                }
+
+                let mut buffer = vec![0; range.len()];
+
+                reader.seek(SeekFrom::Start(chunk_offset + 8 + range.start as u64))?;
+                reader.read_exact(&mut buffer)?;
+
+                Ok(buffer)
+            }
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use derive_more::Display;
+
+/// Controls how malformed RIFF data is handled while parsing.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum RiffParseMode {
+    /// Any structural error aborts parsing. Data following the root chunk
+    /// is ignored.
+    #[default]
+    Strict,
+
+    /// Recoverable errors are reported as `RiffWarning`s: chunk sizes
+    /// overrunning their parent get clamped, a root chunk size falling short
+    /// of the chunks following it gets extended, missing pad bytes are
+    /// detected, malformed identifiers are accepted when possible,
+    /// unreadable chunks are skipped and trailing data is reported.
+    Lenient,
+}
+
+/// Recoverable problem found while parsing in `RiffParseMode::Lenient`.
+/// The offsets are absolute byte offsets of the affected chunk header.
+#[rustfmt::skip]
+#[derive(Debug, Clone, PartialEq, Eq, Display)]
+pub enum RiffWarning {
+    #[display(fmt = "Chunk size clamped from {declared_size} to {clamped_size} bytes at offset {offset:#010X}")]
+    ChunkSizeClamped {
+        offset: usize,
+        declared_size: u32,
+        clamped_size: u32,
+    },
+
+    #[display(fmt = "Chunk size extended from {declared_size} to {extended_size} bytes at offset {offset:#010X}")]
+    ChunkSizeExtended {
+        offset: usize,
+        declared_size: u32,
+        extended_size: u32,
+    },
+
+    #[display(fmt = "Missing pad byte after odd-sized chunk at offset {offset:#010X}")]
+    MissingPadByte {
+        offset: usize,
+    },
+
+    #[display(fmt = "Malformed container chunk at offset {offset:#010X}")]
+    MalformedContainer {
+        offset: usize,
+    },
+
+    #[display(fmt = "Malformed identifier '{}' at offset {offset:#010X}", "identifier.escape_ascii()")]
+    MalformedIdentifier {
+        offset: usize,
+        identifier: [u8; 4],
+    },
+
+    #[display(fmt = "Skipped unreadable chunk at offset {offset:#010X}")]
+    SkippedChunk {
+        offset: usize,
+    },
+
+    #[display(fmt = "Ignored {length} trailing bytes at offset {offset:#010X}")]
+    TrailingBytes {
+        offset: usize,
+        length: usize,
+    },
+}
+
+impl RiffWarning {
+    pub fn offset(&self) -> usize {
+        match self {
+            RiffWarning::ChunkSizeClamped { offset, .. } => *offset,
+            RiffWarning::ChunkSizeExtended { offset, .. } => *offset,
+            RiffWarning::MissingPadByte { offset } => *offset,
+            RiffWarning::MalformedContainer { offset } => *offset,
+            RiffWarning::MalformedIdentifier { offset, .. } => *offset,
+            RiffWarning::SkippedChunk { offset } => *offset,
+            RiffWarning::TrailingBytes { offset, .. } => *offset,
+        }
+    }
+}
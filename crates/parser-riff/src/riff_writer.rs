@@ -0,0 +1,128 @@
+use std::io::{Seek, SeekFrom, Write};
+
+use crate::{RiffError, RiffResult};
+
+struct OpenChunk {
+    is_container: bool,
+    size_position: u64,
+}
+
+/// Streaming RIFF writer.
+///
+/// Chunk sizes are written as placeholders when a chunk gets opened, then
+/// back-patched once the chunk is closed. Odd-sized chunks get padded to
+/// an even size as required by the RIFF specification.
+pub struct RiffWriter<W: Write + Seek> {
+    writer: W,
+    open_chunks: Vec<OpenChunk>,
+}
+
+impl<W: Write + Seek> RiffWriter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            open_chunks: Vec::new(),
+        }
+    }
+
+    /// Writes a four-character code, which must be made of printable ASCII
+    /// characters.
+    fn write_fourcc(&mut self, fourcc: &str) -> RiffResult<()> {
+        let is_printable = |b: u8| b.is_ascii_graphic() || (b == b' ');
+
+        if (fourcc.len() != 4) || !fourcc.bytes().all(is_printable) {
+            return Err(RiffError::MalformedIdentifier {
+                offset: self.writer.stream_position()? as usize,
+            });
+        }
+
+        self.writer.write_all(fourcc.as_bytes())?;
+        Ok(())
+    }
+
+    fn begin(&mut self, is_container: bool, chunk_id: &str) -> RiffResult<()> {
+        if self
+            .open_chunks
+            .last()
+            .is_some_and(|open_chunk| !open_chunk.is_container)
+        {
+            return Err(RiffError::NormalChunkNoSubchunks);
+        }
+
+        self.write_fourcc(chunk_id)?;
+
+        let size_position = self.writer.stream_position()?;
+        self.writer.write_all(&[0; 4])?;
+
+        self.open_chunks.push(OpenChunk {
+            is_container,
+            size_position,
+        });
+
+        Ok(())
+    }
+
+    /// Opens a container chunk (`RIFF` or `LIST`), subsequent chunks will be
+    /// written as its subchunks until it gets closed by `end_chunk`.
+    pub fn begin_container(&mut self, chunk_type: &str, chunk_id: &str) -> RiffResult<()> {
+        self.begin(true, chunk_type)?;
+        self.write_fourcc(chunk_id)
+    }
+
+    /// Opens a normal chunk, its data can be written piecewise by
+    /// `write_data` until it gets closed by `end_chunk`.
+    pub fn begin_chunk(&mut self, chunk_id: &str) -> RiffResult<()> {
+        self.begin(false, chunk_id)
+    }
+
+    pub fn write_data(&mut self, chunk_data: &[u8]) -> RiffResult<()> {
+        match self
+            .open_chunks
+            .last()
+            .map(|open_chunk| open_chunk.is_container)
+        {
+            Some(false) => {
+                self.writer.write_all(chunk_data)?;
+                Ok(())
+            }
+            Some(true) => Err(RiffError::ContainerChunkNoData),
+            None => Err(RiffError::NoOpenChunk),
+        }
+    }
+
+    pub fn end_chunk(&mut self) -> RiffResult<()> {
+        let OpenChunk { size_position, .. } =
+            self.open_chunks.pop().ok_or(RiffError::NoOpenChunk)?;
+
+        let end_position = self.writer.stream_position()?;
+        let chunk_size = u32::try_from(end_position - size_position - 4)
+            .map_err(|_| RiffError::ChunkTooLarge)?;
+
+        self.writer.seek(SeekFrom::Start(size_position))?;
+        self.writer.write_all(&chunk_size.to_le_bytes())?;
+        self.writer.seek(SeekFrom::Start(end_position))?;
+
+        if chunk_size % 2 == 1 {
+            self.writer.write_all(&[0])?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes a complete normal chunk.
+    pub fn write_chunk(&mut self, chunk_id: &str, chunk_data: &[u8]) -> RiffResult<()> {
+        self.begin_chunk(chunk_id)?;
+        self.write_data(chunk_data)?;
+        self.end_chunk()
+    }
+
+    /// Returns the underlying writer, all chunks must be closed at this point.
+    pub fn finish(mut self) -> RiffResult<W> {
+        if !self.open_chunks.is_empty() {
+            return Err(RiffError::UnclosedChunk);
+        }
+
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
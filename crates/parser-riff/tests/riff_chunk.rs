@@ -0,0 +1,279 @@
+use parser_riff::{RawChunkIterator, RiffChunk, RiffError, RiffParseMode, RiffWarning};
+
+fn chunk(chunk_id: &[u8; 4], chunk_size: u32, chunk_data: &[u8]) -> Vec<u8> {
+    [chunk_id, &chunk_size.to_le_bytes()[..], chunk_data].concat()
+}
+
+fn container(
+    chunk_type: &[u8; 4],
+    chunk_id: &[u8; 4],
+    chunk_size: u32,
+    subchunks: &[u8],
+) -> Vec<u8> {
+    [
+        chunk_type,
+        &chunk_size.to_le_bytes()[..],
+        chunk_id,
+        subchunks,
+    ]
+    .concat()
+}
+
+fn lenient(buffer: &[u8]) -> (RiffChunk<'_>, Vec<RiffWarning>) {
+    RiffChunk::with_mode(buffer, RiffParseMode::Lenient).unwrap()
+}
+
+fn chunk_ids<'a>(chunk: &RiffChunk<'a>) -> Vec<&'a str> {
+    chunk
+        .subchunks()
+        .unwrap()
+        .iter()
+        .map(|subchunk| subchunk.chunk_id())
+        .collect()
+}
+
+#[test]
+fn well_formed_chunks_have_no_warnings() {
+    let buffer = container(
+        b"RIFF",
+        b"TEST",
+        4 + 12 + 10,
+        &[chunk(b"odd ", 3, &[1, 2, 3, 0]), chunk(b"even", 2, &[4, 5])].concat(),
+    );
+
+    let (chunk, warnings) = lenient(&buffer);
+    assert_eq!(warnings, []);
+    assert_eq!(chunk_ids(&chunk), ["odd ", "even"]);
+    assert!(RiffChunk::new(&buffer).is_ok());
+}
+
+#[test]
+fn oversized_child() {
+    let buffer = container(
+        b"RIFF",
+        b"TEST",
+        4 + 8 + 4,
+        &chunk(b"data", 100, &[1, 2, 3, 4]),
+    );
+
+    assert!(matches!(
+        RiffChunk::new(&buffer),
+        Err(RiffError::TruncatedChunkData { offset: 12 })
+    ));
+
+    let (chunk, warnings) = lenient(&buffer);
+    assert_eq!(
+        warnings,
+        [RiffWarning::ChunkSizeClamped {
+            offset: 12,
+            declared_size: 100,
+            clamped_size: 4,
+        }]
+    );
+
+    let data = chunk.subchunk("data").unwrap();
+    assert_eq!(data.chunk_data().unwrap(), [1, 2, 3, 4]);
+    assert_eq!(data.chunk_size(), 100);
+}
+
+#[test]
+fn undersized_root() {
+    // The root size only covers the first subchunk.
+    let buffer = container(
+        b"RIFF",
+        b"TEST",
+        4 + 10,
+        &[chunk(b"one ", 2, &[1, 2]), chunk(b"two ", 2, &[3, 4])].concat(),
+    );
+
+    // Strict mode ignores the chunks past the declared size.
+    let chunk = RiffChunk::new(&buffer).unwrap();
+    assert_eq!(chunk_ids(&chunk), ["one "]);
+
+    let (chunk, warnings) = lenient(&buffer);
+    assert_eq!(
+        warnings,
+        [RiffWarning::ChunkSizeExtended {
+            offset: 0,
+            declared_size: 14,
+            extended_size: 24,
+        }]
+    );
+    assert_eq!(chunk_ids(&chunk), ["one ", "two "]);
+    assert_eq!(
+        chunk.subchunk("two ").unwrap().chunk_data().unwrap(),
+        [3, 4]
+    );
+}
+
+#[test]
+fn missing_pad_byte() {
+    let buffer = container(
+        b"RIFF",
+        b"TEST",
+        4 + 11 + 10,
+        &[chunk(b"odd ", 3, &[1, 2, 3]), chunk(b"next", 2, &[4, 5])].concat(),
+    );
+
+    assert!(RiffChunk::new(&buffer).is_err());
+
+    let (chunk, warnings) = lenient(&buffer);
+    assert_eq!(warnings, [RiffWarning::MissingPadByte { offset: 12 }]);
+    assert_eq!(chunk_ids(&chunk), ["odd ", "next"]);
+
+    let odd = chunk.subchunk("odd ").unwrap();
+    assert_eq!(odd.chunk_data().unwrap(), [1, 2, 3]);
+    assert_eq!(odd.chunk_padding(), 0);
+    assert_eq!(
+        chunk.subchunk("next").unwrap().chunk_data().unwrap(),
+        [4, 5]
+    );
+}
+
+#[test]
+fn trailing_garbage() {
+    let mut buffer = container(b"RIFF", b"TEST", 4 + 10, &chunk(b"data", 2, &[1, 2]));
+    buffer.extend(b"\0junk");
+
+    // Strict mode ignores trailing data, as real banks often carry some.
+    let chunk = RiffChunk::new(&buffer).unwrap();
+    assert_eq!(chunk_ids(&chunk), ["data"]);
+    assert!(RiffChunk::new(&buffer[..23]).is_ok());
+
+    let (chunk, warnings) = lenient(&buffer);
+    assert_eq!(
+        warnings,
+        [RiffWarning::TrailingBytes {
+            offset: 22,
+            length: 5,
+        }]
+    );
+    assert_eq!(chunk_ids(&chunk), ["data"]);
+}
+
+fn nested_chunks() -> Vec<u8> {
+    container(
+        b"RIFF",
+        b"TEST",
+        4 + 12 + 30,
+        &[
+            chunk(b"odd ", 3, &[1, 2, 3, 0]),
+            container(
+                b"LIST",
+                b"list",
+                4 + 10 + 8,
+                &[chunk(b"one ", 2, &[4, 5]), chunk(b"two ", 0, &[])].concat(),
+            ),
+        ]
+        .concat(),
+    )
+}
+
+#[test]
+fn nested_chunk_offsets() {
+    let buffer = nested_chunks();
+    let chunk = RiffChunk::new(&buffer).unwrap();
+
+    assert_eq!(chunk.chunk_offset(), 0);
+    assert_eq!(chunk.chunk_size(), 46);
+
+    let odd = chunk.subchunk("odd ").unwrap();
+    assert_eq!(odd.chunk_offset(), 12);
+    assert_eq!(odd.chunk_size(), 3);
+    assert_eq!(odd.chunk_padding(), 1);
+
+    let list = chunk.subchunk("list").unwrap();
+    assert_eq!(list.chunk_offset(), 24);
+    assert_eq!(list.chunk_size(), 22);
+    assert_eq!(list.chunk_padding(), 0);
+
+    let offsets = list
+        .subchunks()
+        .unwrap()
+        .iter()
+        .map(|subchunk| (subchunk.chunk_id(), subchunk.chunk_offset()))
+        .collect::<Vec<_>>();
+    assert_eq!(offsets, [("one ", 36), ("two ", 46)]);
+
+    // Offsets of a chunk loaded on its own are relative to its file.
+    let (list, _) = RiffChunk::with_offset(&buffer[24..], 24, RiffParseMode::Strict).unwrap();
+    assert_eq!(list.chunk_offset(), 24);
+    assert_eq!(list.subchunk("two ").unwrap().chunk_offset(), 46);
+}
+
+#[test]
+fn raw_chunk_positions() {
+    let buffer = nested_chunks();
+
+    let raw_chunks = RawChunkIterator::with_mode(&buffer[12..], 12, RiffParseMode::Strict)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    let positions = raw_chunks
+        .iter()
+        .map(|raw_chunk| {
+            (
+                raw_chunk.chunk_offset(),
+                raw_chunk.chunk_size(),
+                raw_chunk.chunk_padding(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(positions, [(12, 3, 1), (24, 22, 0)]);
+}
+
+#[test]
+fn declared_and_available_size() {
+    // A chunk cut off by the end of its container, and an odd-sized last
+    // chunk without room for its pad byte
+    let buffer = container(b"RIFF", b"TEST", 4 + 8 + 3, &chunk(b"data", 7, &[1, 2, 3]));
+
+    let (chunk, _) = lenient(&buffer);
+    assert_eq!(chunk.chunk_size(), 15);
+    assert_eq!(chunk.chunk_padding(), 0);
+
+    let data = chunk.subchunk("data").unwrap();
+    assert_eq!(data.chunk_size(), 7);
+    assert_eq!(data.chunk_data().unwrap().len(), 3);
+    assert_eq!(data.chunk_padding(), 0);
+}
+
+#[test]
+fn error_offsets() {
+    let buffer = nested_chunks();
+
+    // Truncated subchunk of the nested list
+    let mut truncated = buffer.clone();
+    truncated[40..44].copy_from_slice(&20u32.to_le_bytes());
+    assert!(matches!(
+        RiffChunk::new(&truncated),
+        Err(RiffError::TruncatedChunkData { offset: 36 })
+    ));
+
+    // Malformed identifier of the nested list
+    let mut malformed = buffer.clone();
+    malformed[32..36].copy_from_slice(b"l\0st");
+    assert!(matches!(
+        RiffChunk::new(&malformed),
+        Err(RiffError::MalformedIdentifier { offset: 24 })
+    ));
+
+    // The offset is that of the file the chunk was loaded from.
+    assert!(matches!(
+        RiffChunk::with_offset(&malformed[24..], 24, RiffParseMode::Strict),
+        Err(RiffError::MalformedIdentifier { offset: 24 })
+    ));
+
+    let chunk = RiffChunk::new(&buffer).unwrap();
+    let list = chunk.subchunk("list").unwrap();
+    assert!(matches!(
+        list.subchunk("six "),
+        Err(RiffError::MissingSubchunk { offset: 24, .. })
+    ));
+
+    let error = RiffChunk::new(&truncated).err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "Truncated chunk data at offset 0x00000024"
+    );
+}
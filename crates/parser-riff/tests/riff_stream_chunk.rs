@@ -0,0 +1,61 @@
+use std::io::Cursor;
+
+use parser_riff::{RiffError, RiffStreamChunk};
+
+fn chunk(chunk_id: &[u8; 4], chunk_size: u32, chunk_data: &[u8]) -> Vec<u8> {
+    [chunk_id, &chunk_size.to_le_bytes()[..], chunk_data].concat()
+}
+
+#[test]
+fn chunk_tree() {
+    let list = chunk(
+        b"LIST",
+        4 + 10,
+        &[b"INFO", &chunk(b"INAM", 2, b"A\0")[..]].concat(),
+    );
+    let buffer = chunk(
+        b"RIFF",
+        4 + 10 + list.len() as u32,
+        &[b"TEST", &chunk(b"data", 1, b"\x01\0")[..], &list].concat(),
+    );
+
+    let riff_chunk = RiffStreamChunk::new(&mut Cursor::new(&buffer)).unwrap();
+    assert_eq!(riff_chunk.chunk_id(), "TEST");
+
+    let subchunks = riff_chunk.subchunks().unwrap();
+    assert_eq!(subchunks.len(), 2);
+    assert_eq!(subchunks[0].chunk_offset(), 12);
+    assert_eq!(subchunks[1].chunk_offset(), 22);
+
+    let name_chunk = riff_chunk
+        .subchunk("INFO")
+        .unwrap()
+        .subchunk("INAM")
+        .unwrap();
+    assert_eq!(
+        name_chunk
+            .read_chunk_data(&mut Cursor::new(&buffer))
+            .unwrap(),
+        b"A\0"
+    );
+}
+
+#[test]
+fn container_chunks_without_list_type() {
+    for chunk_size in 0..4 {
+        let list = chunk(b"LIST", chunk_size, &vec![0; chunk_size as usize]);
+        let buffer = chunk(
+            b"RIFF",
+            4 + list.len() as u32,
+            &[&b"TEST"[..], &list, b"INFO"].concat(),
+        );
+
+        assert!(
+            matches!(
+                RiffStreamChunk::new(&mut Cursor::new(&buffer)),
+                Err(RiffError::MalformedChunk { offset: 12 })
+            ),
+            "{chunk_size}"
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use std::io::Cursor;
+
+use parser_riff::{RiffChunk, RiffError, RiffWriter};
+
+fn new_writer() -> RiffWriter<Cursor<Vec<u8>>> {
+    RiffWriter::new(Cursor::new(Vec::new()))
+}
+
+#[test]
+fn nested_container_sizes() {
+    let mut writer = new_writer();
+
+    writer.begin_container("RIFF", "TEST").unwrap();
+    writer.begin_container("LIST", "abcd").unwrap();
+    writer.begin_container("LIST", "efgh").unwrap();
+    writer.write_chunk("data", &[1, 2, 3, 4]).unwrap();
+    writer.end_chunk().unwrap();
+    writer.end_chunk().unwrap();
+    writer.write_chunk("tail", &[5, 6]).unwrap();
+    writer.end_chunk().unwrap();
+
+    let buffer = writer.finish().unwrap().into_inner();
+
+    #[rustfmt::skip]
+    let expected: &[u8] = &[
+        b'R', b'I', b'F', b'F', 50, 0, 0, 0, b'T', b'E', b'S', b'T',
+            b'L', b'I', b'S', b'T', 28, 0, 0, 0, b'a', b'b', b'c', b'd',
+                b'L', b'I', b'S', b'T', 16, 0, 0, 0, b'e', b'f', b'g', b'h',
+                    b'd', b'a', b't', b'a', 4, 0, 0, 0, 1, 2, 3, 4,
+            b't', b'a', b'i', b'l', 2, 0, 0, 0, 5, 6,
+    ];
+    assert_eq!(buffer, expected);
+}
+
+#[test]
+fn odd_chunks_are_padded() {
+    let mut writer = new_writer();
+
+    writer.begin_container("RIFF", "TEST").unwrap();
+    writer.write_chunk("odd ", &[1, 2, 3]).unwrap();
+    writer.write_chunk("even", &[4, 5]).unwrap();
+    writer.end_chunk().unwrap();
+
+    let buffer = writer.finish().unwrap().into_inner();
+
+    // The pad byte is not included in the size of the chunk, but in the
+    // size of its container.
+    assert_eq!(&buffer[4..8], &26u32.to_le_bytes());
+    assert_eq!(&buffer[12..24], b"odd \x03\x00\x00\x00\x01\x02\x03\x00");
+    assert_eq!(&buffer[24..], b"even\x02\x00\x00\x00\x04\x05");
+}
+
+#[test]
+fn data_written_piecewise() {
+    let mut writer = new_writer();
+
+    writer.begin_container("RIFF", "TEST").unwrap();
+    writer.begin_chunk("data").unwrap();
+    writer.write_data(&[1, 2]).unwrap();
+    writer.write_data(&[]).unwrap();
+    writer.write_data(&[3]).unwrap();
+    writer.end_chunk().unwrap();
+    writer.end_chunk().unwrap();
+
+    let buffer = writer.finish().unwrap().into_inner();
+    assert_eq!(&buffer[12..], b"data\x03\x00\x00\x00\x01\x02\x03\x00");
+}
+
+#[test]
+fn writer_errors() {
+    let mut writer = new_writer();
+    assert!(matches!(writer.end_chunk(), Err(RiffError::NoOpenChunk)));
+    assert!(matches!(
+        writer.write_data(&[1]),
+        Err(RiffError::NoOpenChunk)
+    ));
+
+    writer.begin_container("RIFF", "TEST").unwrap();
+    assert!(matches!(
+        writer.write_data(&[1]),
+        Err(RiffError::ContainerChunkNoData)
+    ));
+
+    writer.begin_chunk("data").unwrap();
+    assert!(matches!(
+        writer.begin_chunk("next"),
+        Err(RiffError::NormalChunkNoSubchunks)
+    ));
+    assert!(matches!(
+        writer.begin_container("LIST", "next"),
+        Err(RiffError::NormalChunkNoSubchunks)
+    ));
+    writer.end_chunk().unwrap();
+
+    assert!(matches!(writer.finish(), Err(RiffError::UnclosedChunk)));
+}
+
+#[test]
+fn malformed_identifiers() {
+    for chunk_id in ["dat", "data1", "dat\0", "dat\u{7F}", "da\u{E9}", ""] {
+        let mut writer = new_writer();
+        writer.begin_container("RIFF", "TEST").unwrap();
+
+        assert!(
+            matches!(
+                writer.write_chunk(chunk_id, &[]),
+                Err(RiffError::MalformedIdentifier { offset: 12 })
+            ),
+            "{chunk_id:?}"
+        );
+    }
+
+    let mut writer = new_writer();
+    assert!(matches!(
+        writer.begin_container("RIFF", "T\tST"),
+        Err(RiffError::MalformedIdentifier { offset: 8 })
+    ));
+
+    // Spaces are printable.
+    let mut writer = new_writer();
+    writer.begin_container("RIFF", "TEST").unwrap();
+    writer.write_chunk("ab  ", &[]).unwrap();
+}
+
+#[test]
+fn round_trip() {
+    let mut writer = new_writer();
+
+    writer.begin_container("RIFF", "TEST").unwrap();
+    writer.write_chunk("one ", &[1, 2, 3]).unwrap();
+    writer.begin_container("LIST", "list").unwrap();
+    writer.write_chunk("two ", &[4, 5]).unwrap();
+    writer.write_chunk("tri ", &[6]).unwrap();
+    writer.end_chunk().unwrap();
+    writer.write_chunk("four", &[]).unwrap();
+    writer.end_chunk().unwrap();
+
+    let buffer = writer.finish().unwrap().into_inner();
+    let chunk = RiffChunk::new(&buffer).unwrap();
+
+    assert_eq!(chunk.chunk_id(), "TEST");
+    assert_eq!(chunk.chunk_size() as usize, buffer.len() - 8);
+
+    let subchunks = chunk.subchunks().unwrap();
+    let chunk_ids = subchunks
+        .iter()
+        .map(|subchunk| subchunk.chunk_id())
+        .collect::<Vec<_>>();
+    assert_eq!(chunk_ids, ["one ", "list", "four"]);
+
+    assert_eq!(subchunks[0].chunk_data().unwrap(), [1, 2, 3]);
+    assert_eq!(subchunks[0].chunk_padding(), 1);
+    assert_eq!(subchunks[2].chunk_data().unwrap(), []);
+
+    let list = chunk.subchunk("list").unwrap();
+    assert_eq!(list.subchunk("two ").unwrap().chunk_data().unwrap(), [4, 5]);
+    assert_eq!(list.subchunk("tri ").unwrap().chunk_data().unwrap(), [6]);
+    assert_eq!(list.subchunk("tri ").unwrap().chunk_padding(), 1);
+}
@@ -1,3 +1,5 @@
+use std::io;
+
 use parser_riff::RiffError;
 
 use derive_more::{Display, Error, From};
@@ -13,14 +15,39 @@ pub enum Sf2Error {
         chunk_id: String,
     },
 
-    #[display(fmt = "Malformed '{chunk_id:}' chunk")]
+    #[display(fmt = "Malformed '{chunk_id:}' chunk at offset {offset:#010X}")]
     MalformedChunk {
         chunk_id: String,
+        offset: usize,
     },
 
-    #[display(fmt = "Missing terminator record for '{chunk_id:}' chunk")]
+    #[display(fmt = "Missing terminator record for '{chunk_id:}' chunk at offset {offset:#010X}")]
     MissingTerminatorRecord {
         chunk_id: String,
+        offset: usize,
+    },
+
+    #[display(fmt = "Index {index:} out of range in '{chunk_id:}' chunk")]
+    IndexOutOfRange {
+        chunk_id: String,
+        index: usize,
+    },
+
+    #[display(fmt = "Sample data out of bounds ({start:}..{end:})")]
+    SampleDataOutOfBounds {
+        start: u32,
+        end: u32,
+    },
+
+    #[display(fmt = "Unknown sample type '{sample_type:#06X}'")]
+    UnknownSampleType {
+        sample_type: u16,
+    },
+
+    #[display(fmt = "Broken stereo link between samples {sample_index:} and {linked_sample_index:}")]
+    BrokenSampleLink {
+        sample_index: usize,
+        linked_sample_index: usize,
     },
 
     #[display(fmt = "Malformed zero-terminated string")]
@@ -29,9 +56,47 @@ pub enum Sf2Error {
     #[display(fmt = "Malformed fixed-length string")]
     MalformedFixedstr,
 
-    #[display(fmt = "Malformed version chunk")]
-    MalformedVersionChunk,
+    #[display(fmt = "Malformed version chunk at offset {offset:#010X}")]
+    MalformedVersionChunk {
+        offset: usize,
+    },
+
+    #[display(fmt = "Unknown generator '{generator_id:}'")]
+    UnknownGenerator {
+        generator_id: u16,
+    },
+
+    #[display(fmt = "Unknown modulator curve type '{curve_type:}'")]
+    UnknownModulatorCurveType {
+        curve_type: u8,
+    },
+
+    #[display(fmt = "Unknown modulator transform '{raw_transform:}'")]
+    UnknownModulatorTransform {
+        raw_transform: u16,
+    },
+
+    #[display(fmt = "String '{string:}' is longer than {max_length:} bytes")]
+    StringTooLong {
+        string: String,
+        max_length: usize,
+    },
+
+    #[display(fmt = "Sample '{sample_name:}' has {samples_24_length:} LSB points for {samples_16_length:} points")]
+    SampleLengthMismatch {
+        sample_name: String,
+        samples_16_length: usize,
+        samples_24_length: usize,
+    },
+
+    #[display(fmt = "Too many records for '{chunk_id:}' chunk")]
+    TooManyRecords {
+        chunk_id: String,
+    },
 
     #[from]
     RiffError(RiffError),
+
+    #[from]
+    Io(io::Error),
 }
@@ -1,31 +1,59 @@
 mod error;
+mod sf2_generator;
+mod sf2_gm_compliance;
 mod sf2_info;
+mod sf2_instrument;
 mod sf2_instrument_generator;
 mod sf2_instrument_header;
 mod sf2_instrument_modulator;
 mod sf2_instrument_zone;
+mod sf2_model;
+mod sf2_modulator;
+mod sf2_preset;
 mod sf2_preset_generator;
 mod sf2_preset_header;
 mod sf2_preset_modulator;
 mod sf2_preset_zone;
+mod sf2_sample_data;
 mod sf2_sample_header;
 mod sf2_soundfont;
+mod sf2_soundfont_reader;
+mod sf2_validation;
 mod sf2_version;
+mod sf2_writer;
+mod sf2_zone;
 mod utils;
 
 pub use error::Sf2Error;
 pub use sf2_soundfont::Sf2SoundFont;
+pub use sf2_soundfont_reader::Sf2SoundFontReader;
 
+pub use sf2_generator::{Sf2Generator, Sf2GeneratorAmount, Sf2GeneratorKind};
+pub use sf2_gm_compliance::{Sf2GmCompliance, Sf2GmPresetSlot};
 pub use sf2_info::Sf2Info;
+pub use sf2_instrument::Sf2Instrument;
 pub use sf2_instrument_generator::Sf2InstrumentGenerator;
 pub use sf2_instrument_header::Sf2InstrumentHeader;
 pub use sf2_instrument_modulator::Sf2InstrumentModulator;
 pub use sf2_instrument_zone::Sf2InstrumentZone;
+pub use sf2_model::{
+    Sf2Model, Sf2ModelGenerator, Sf2ModelInfo, Sf2ModelInstrument, Sf2ModelModulator,
+    Sf2ModelPreset, Sf2ModelSample, Sf2ModelZone,
+};
+pub use sf2_modulator::{
+    Sf2ControllerPalette, Sf2GeneralController, Sf2Modulator, Sf2ModulatorDestination,
+    Sf2ModulatorSource, Sf2ModulatorTransform, Sf2SourceCurveType, Sf2SourceDirection,
+    Sf2SourcePolarity,
+};
+pub use sf2_preset::Sf2Preset;
 pub use sf2_preset_generator::Sf2PresetGenerator;
 pub use sf2_preset_header::Sf2PresetHeader;
 pub use sf2_preset_modulator::Sf2PresetModulator;
 pub use sf2_preset_zone::Sf2PresetZone;
-pub use sf2_sample_header::Sf2SampleHeader;
+pub use sf2_sample_data::Sf2SampleData;
+pub use sf2_sample_header::{Sf2SampleHeader, Sf2SampleType};
+pub use sf2_validation::{Sf2Diagnostic, Sf2Severity, Sf2Violation, SAMPLE_PADDING_LENGTH};
 pub use sf2_version::Sf2Version;
+pub use sf2_zone::Sf2Zone;
 
 pub type Sf2Result<T> = Result<T, Sf2Error>;
@@ -0,0 +1,192 @@
+use std::fmt::{self, Display};
+
+use strum::{Display, EnumIter, FromRepr};
+
+use crate::{Sf2Error, Sf2Result};
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+#[rustfmt::skip]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Display, EnumIter, FromRepr)]
+#[repr(u16)]
+pub enum Sf2GeneratorKind {
+    #[strum(to_string = "startAddrsOffset")]           StartAddrsOffset           =  0,
+    #[strum(to_string = "endAddrsOffset")]             EndAddrsOffset             =  1,
+    #[strum(to_string = "startloopAddrsOffset")]       StartloopAddrsOffset       =  2,
+    #[strum(to_string = "endloopAddrsOffset")]         EndloopAddrsOffset         =  3,
+    #[strum(to_string = "startAddrsCoarseOffset")]     StartAddrsCoarseOffset     =  4,
+    #[strum(to_string = "modLfoToPitch")]              ModLfoToPitch              =  5,
+    #[strum(to_string = "vibLfoToPitch")]              VibLfoToPitch              =  6,
+    #[strum(to_string = "modEnvToPitch")]              ModEnvToPitch              =  7,
+    #[strum(to_string = "initialFilterFc")]            InitialFilterFc            =  8,
+    #[strum(to_string = "initialFilterQ")]             InitialFilterQ             =  9,
+    #[strum(to_string = "modLfoToFilterFc")]           ModLfoToFilterFc           = 10,
+    #[strum(to_string = "modEnvToFilterFc")]           ModEnvToFilterFc           = 11,
+    #[strum(to_string = "endAddrsCoarseOffset")]       EndAddrsCoarseOffset       = 12,
+    #[strum(to_string = "modLfoToVolume")]             ModLfoToVolume             = 13,
+    #[strum(to_string = "unused1")]                    Unused1                    = 14,
+    #[strum(to_string = "chorusEffectsSend")]          ChorusEffectsSend          = 15,
+    #[strum(to_string = "reverbEffectsSend")]          ReverbEffectsSend          = 16,
+    #[strum(to_string = "pan")]                        Pan                        = 17,
+    #[strum(to_string = "unused2")]                    Unused2                    = 18,
+    #[strum(to_string = "unused3")]                    Unused3                    = 19,
+    #[strum(to_string = "unused4")]                    Unused4                    = 20,
+    #[strum(to_string = "delayModLFO")]                DelayModLfo                = 21,
+    #[strum(to_string = "freqModLFO")]                 FreqModLfo                 = 22,
+    #[strum(to_string = "delayVibLFO")]                DelayVibLfo                = 23,
+    #[strum(to_string = "freqVibLFO")]                 FreqVibLfo                 = 24,
+    #[strum(to_string = "delayModEnv")]                DelayModEnv                = 25,
+    #[strum(to_string = "attackModEnv")]               AttackModEnv               = 26,
+    #[strum(to_string = "holdModEnv")]                 HoldModEnv                 = 27,
+    #[strum(to_string = "decayModEnv")]                DecayModEnv                = 28,
+    #[strum(to_string = "sustainModEnv")]              SustainModEnv              = 29,
+    #[strum(to_string = "releaseModEnv")]              ReleaseModEnv              = 30,
+    #[strum(to_string = "keynumToModEnvHold")]         KeynumToModEnvHold         = 31,
+    #[strum(to_string = "keynumToModEnvDecay")]        KeynumToModEnvDecay        = 32,
+    #[strum(to_string = "delayVolEnv")]                DelayVolEnv                = 33,
+    #[strum(to_string = "attackVolEnv")]               AttackVolEnv               = 34,
+    #[strum(to_string = "holdVolEnv")]                 HoldVolEnv                 = 35,
+    #[strum(to_string = "decayVolEnv")]                DecayVolEnv                = 36,
+    #[strum(to_string = "sustainVolEnv")]              SustainVolEnv              = 37,
+    #[strum(to_string = "releaseVolEnv")]              ReleaseVolEnv              = 38,
+    #[strum(to_string = "keynumToVolEnvHold")]         KeynumToVolEnvHold         = 39,
+    #[strum(to_string = "keynumToVolEnvDecay")]        KeynumToVolEnvDecay        = 40,
+    #[strum(to_string = "instrument")]                 Instrument                 = 41,
+    #[strum(to_string = "reserved1")]                  Reserved1                  = 42,
+    #[strum(to_string = "keyRange")]                   KeyRange                   = 43,
+    #[strum(to_string = "velRange")]                   VelRange                   = 44,
+    #[strum(to_string = "startloopAddrsCoarseOffset")] StartloopAddrsCoarseOffset = 45,
+    #[strum(to_string = "keynum")]                     Keynum                     = 46,
+    #[strum(to_string = "velocity")]                   Velocity                   = 47,
+    #[strum(to_string = "initialAttenuation")]         InitialAttenuation         = 48,
+    #[strum(to_string = "reserved2")]                  Reserved2                  = 49,
+    #[strum(to_string = "endloopAddrsCoarseOffset")]   EndloopAddrsCoarseOffset   = 50,
+    #[strum(to_string = "coarseTune")]                 CoarseTune                 = 51,
+    #[strum(to_string = "fineTune")]                   FineTune                   = 52,
+    #[strum(to_string = "sampleID")]                   SampleId                   = 53,
+    #[strum(to_string = "sampleModes")]                SampleModes                = 54,
+    #[strum(to_string = "reserved3")]                  Reserved3                  = 55,
+    #[strum(to_string = "scaleTuning")]                ScaleTuning                = 56,
+    #[strum(to_string = "exclusiveClass")]             ExclusiveClass             = 57,
+    #[strum(to_string = "overridingRootKey")]          OverridingRootKey          = 58,
+    #[strum(to_string = "unused5")]                    Unused5                    = 59,
+    #[strum(to_string = "endOper")]                    EndOper                    = 60,
+}
+
+impl Sf2GeneratorKind {
+    pub fn from_id(generator_id: u16) -> Sf2Result<Sf2GeneratorKind> {
+        Sf2GeneratorKind::from_repr(generator_id).ok_or(Sf2Error::UnknownGenerator { generator_id })
+    }
+
+    pub fn id(&self) -> u16 {
+        *self as u16
+    }
+
+    pub fn decode_amount(&self, raw_amount: u16) -> Sf2GeneratorAmount {
+        match self {
+            Sf2GeneratorKind::KeyRange | Sf2GeneratorKind::VelRange => {
+                let [low, high] = raw_amount.to_le_bytes();
+                Sf2GeneratorAmount::Range { low, high }
+            }
+
+            Sf2GeneratorKind::Instrument
+            | Sf2GeneratorKind::SampleId
+            | Sf2GeneratorKind::SampleModes
+            | Sf2GeneratorKind::ExclusiveClass => Sf2GeneratorAmount::Unsigned(raw_amount),
+
+            _ => Sf2GeneratorAmount::Signed(raw_amount as i16),
+        }
+    }
+
+    /// The value a generator takes when no zone specifies it, as listed in
+    /// section 8.1.3 of the SoundFont 2.04 specification.
+    pub fn default_amount(&self) -> Sf2GeneratorAmount {
+        match self {
+            Sf2GeneratorKind::KeyRange | Sf2GeneratorKind::VelRange => {
+                Sf2GeneratorAmount::Range { low: 0, high: 127 }
+            }
+
+            Sf2GeneratorKind::InitialFilterFc => Sf2GeneratorAmount::Signed(13500),
+            Sf2GeneratorKind::ScaleTuning => Sf2GeneratorAmount::Signed(100),
+
+            Sf2GeneratorKind::DelayModLfo
+            | Sf2GeneratorKind::DelayVibLfo
+            | Sf2GeneratorKind::DelayModEnv
+            | Sf2GeneratorKind::AttackModEnv
+            | Sf2GeneratorKind::HoldModEnv
+            | Sf2GeneratorKind::DecayModEnv
+            | Sf2GeneratorKind::ReleaseModEnv
+            | Sf2GeneratorKind::DelayVolEnv
+            | Sf2GeneratorKind::AttackVolEnv
+            | Sf2GeneratorKind::HoldVolEnv
+            | Sf2GeneratorKind::DecayVolEnv
+            | Sf2GeneratorKind::ReleaseVolEnv => Sf2GeneratorAmount::Signed(-12000),
+
+            Sf2GeneratorKind::Keynum
+            | Sf2GeneratorKind::Velocity
+            | Sf2GeneratorKind::OverridingRootKey => Sf2GeneratorAmount::Signed(-1),
+
+            _ => self.decode_amount(0),
+        }
+    }
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Sf2GeneratorAmount {
+    Signed(i16),
+    Unsigned(u16),
+    Range { low: u8, high: u8 },
+}
+
+impl Sf2GeneratorAmount {
+    pub fn as_signed(&self) -> Option<i16> {
+        match self {
+            Sf2GeneratorAmount::Signed(amount) => Some(*amount),
+            _ => None,
+        }
+    }
+
+    pub fn as_unsigned(&self) -> Option<u16> {
+        match self {
+            Sf2GeneratorAmount::Unsigned(amount) => Some(*amount),
+            _ => None,
+        }
+    }
+
+    pub fn as_range(&self) -> Option<(u8, u8)> {
+        match self {
+            Sf2GeneratorAmount::Range { low, high } => Some((*low, *high)),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Sf2GeneratorAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Sf2GeneratorAmount::Signed(amount) => write!(f, "{amount:}"),
+            Sf2GeneratorAmount::Unsigned(amount) => write!(f, "{amount:}"),
+            Sf2GeneratorAmount::Range { low, high } => write!(f, "{low:}-{high:}"),
+        }
+    }
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+/// Common accessors of the preset-level (`pgen`) and instrument-level (`igen`)
+/// generator records, both sharing the same `sfGenList` layout.
+pub trait Sf2Generator {
+    fn generator_id(&self) -> u16;
+
+    fn raw_amount(&self) -> u16;
+
+    fn generator_kind(&self) -> Sf2Result<Sf2GeneratorKind> {
+        Sf2GeneratorKind::from_id(self.generator_id())
+    }
+
+    fn generator_amount(&self) -> Sf2Result<Sf2GeneratorAmount> {
+        Ok(self.generator_kind()?.decode_amount(self.raw_amount()))
+    }
+}
@@ -0,0 +1,100 @@
+use midi_defs::gm::GENERAL_MIDI;
+use midi_defs::sound_set::{
+    GM2_DRUM_BANK_MSB, PERCUSSION_BANK, XG_DRUM_BANK_MSB, XG_SFX_KIT_BANK_MSB,
+};
+
+use crate::{Sf2Result, Sf2SoundFont};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sf2GmPresetSlot {
+    pub bank: u16,
+    pub preset: u16,
+    pub preset_name: String,
+}
+
+/// General MIDI coverage of a SoundFont, checked against the GM Level 1
+/// sound set of `midi_defs::gm::GENERAL_MIDI`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sf2GmCompliance {
+    /// GM programs and the standard drum kit present, as bank/preset pairs.
+    pub covered: Vec<(u16, u16)>,
+    /// GM programs and the standard drum kit absent, as bank/preset pairs.
+    pub missing: Vec<(u16, u16)>,
+    /// Presets in slots not defined by General MIDI, e.g. variation banks.
+    pub non_standard: Vec<Sf2GmPresetSlot>,
+    /// Drum kits outside of bank 128, placed in a GM2/XG drum bank number.
+    pub misplaced_drum_kits: Vec<Sf2GmPresetSlot>,
+    /// Presets outside of the drum banks named like a drum kit. The name is
+    /// only a hint, so these do not affect compliance.
+    pub drum_kit_names: Vec<Sf2GmPresetSlot>,
+}
+
+fn is_drum_kit_name(preset_name: &str) -> bool {
+    let preset_name = preset_name.to_lowercase();
+    let words = preset_name.split_whitespace().collect::<Vec<_>>();
+
+    words.last() == Some(&"kit")
+        || words.contains(&"drumset")
+        || words.windows(2).any(|pair| pair == ["drum", "set"])
+}
+
+impl Sf2GmCompliance {
+    pub fn new(soundfont: &Sf2SoundFont) -> Sf2Result<Sf2GmCompliance> {
+        let preset_headers = soundfont.preset_headers()?;
+
+        let (covered, missing) = GENERAL_MIDI
+            .iter()
+            .map(|&(bank_preset, _)| bank_preset)
+            .partition(|&bank_preset| {
+                preset_headers
+                    .iter()
+                    .any(|preset_header| preset_header.bank_preset() == bank_preset)
+            });
+
+        let mut non_standard = Vec::new();
+        let mut misplaced_drum_kits = Vec::new();
+        let mut drum_kit_names = Vec::new();
+
+        for preset_header in preset_headers {
+            let (bank, preset) = preset_header.bank_preset();
+
+            let preset_slot = Sf2GmPresetSlot {
+                bank,
+                preset,
+                preset_name: preset_header.preset_name()?.to_owned(),
+            };
+
+            let is_drum_bank = u8::try_from(bank).is_ok_and(|bank| {
+                [GM2_DRUM_BANK_MSB, XG_SFX_KIT_BANK_MSB, XG_DRUM_BANK_MSB].contains(&bank)
+            });
+
+            if is_drum_bank {
+                misplaced_drum_kits.push(preset_slot.clone());
+            } else if (bank != PERCUSSION_BANK) && is_drum_kit_name(&preset_slot.preset_name) {
+                drum_kit_names.push(preset_slot.clone());
+            }
+
+            if !GENERAL_MIDI
+                .iter()
+                .any(|&(bank_preset, _)| bank_preset == (bank, preset))
+            {
+                non_standard.push(preset_slot);
+            }
+        }
+
+        Ok(Sf2GmCompliance {
+            covered,
+            missing,
+            non_standard,
+            misplaced_drum_kits,
+            drum_kit_names,
+        })
+    }
+
+    /// Every GM program and the standard drum kit is present, and no drum
+    /// banks other than 128 are used. Non-standard presets and presets merely
+    /// named like drum kits are allowed.
+    pub fn is_compliant(&self) -> bool {
+        self.missing.is_empty() && self.misplaced_drum_kits.is_empty()
+    }
+}
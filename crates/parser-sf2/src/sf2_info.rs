@@ -27,7 +27,9 @@ impl<'a> Sf2Info<'a> {
             if let Some(Sf2Version { major, minor }) = Sf2Version::read_from(chunk.chunk_data()?) {
                 Ok(Some((major.get(), minor.get())))
             } else {
-                Err(Sf2Error::MalformedVersionChunk)
+                Err(Sf2Error::MalformedVersionChunk {
+                    offset: chunk.chunk_offset(),
+                })
             }
         } else {
             Ok(None)
@@ -0,0 +1,87 @@
+use crate::{
+    Sf2Error, Sf2GeneratorKind, Sf2InstrumentGenerator, Sf2InstrumentHeader,
+    Sf2InstrumentModulator, Sf2Result, Sf2SoundFont, Sf2Zone,
+};
+
+pub struct Sf2Instrument<'a> {
+    soundfont: &'a Sf2SoundFont<'a>,
+    instrument_index: usize,
+    instrument_header: &'a Sf2InstrumentHeader,
+}
+
+impl<'a> Sf2Instrument<'a> {
+    pub(crate) fn new(
+        soundfont: &'a Sf2SoundFont<'a>,
+        instrument_index: usize,
+        instrument_header: &'a Sf2InstrumentHeader,
+    ) -> Sf2Instrument<'a> {
+        Sf2Instrument {
+            soundfont,
+            instrument_index,
+            instrument_header,
+        }
+    }
+
+    pub fn instrument_index(&self) -> usize {
+        self.instrument_index
+    }
+
+    pub fn instrument_header(&self) -> &'a Sf2InstrumentHeader {
+        self.instrument_header
+    }
+
+    pub fn all_zones(
+        &self,
+    ) -> Sf2Result<Vec<Sf2Zone<'a, Sf2InstrumentGenerator, Sf2InstrumentModulator>>> {
+        let instrument_headers = self.soundfont.instrument_headers_with_terminator()?;
+
+        let bag_start = instrument_headers[self.instrument_index]
+            .instrument_bag_index
+            .get() as usize;
+        let bag_end = instrument_headers[self.instrument_index + 1]
+            .instrument_bag_index
+            .get() as usize;
+
+        let bag_indices = self
+            .soundfont
+            .instrument_zones_with_terminator()?
+            .get(bag_start..=bag_end)
+            .ok_or(Sf2Error::IndexOutOfRange {
+                chunk_id: "ibag".to_owned(),
+                index: bag_end,
+            })?
+            .iter()
+            .map(|instrument_zone| {
+                (
+                    instrument_zone.generator_index.get() as usize,
+                    instrument_zone.modulator_index.get() as usize,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        Sf2Zone::from_bags(
+            self.soundfont,
+            &bag_indices,
+            ("igen", self.soundfont.instrument_generators()?),
+            ("imod", self.soundfont.instrument_modulators()?),
+        )
+    }
+
+    pub fn global_zone(
+        &self,
+    ) -> Sf2Result<Option<Sf2Zone<'a, Sf2InstrumentGenerator, Sf2InstrumentModulator>>> {
+        Ok(Sf2Zone::global_zone(
+            self.all_zones()?,
+            Sf2GeneratorKind::SampleId,
+        ))
+    }
+
+    pub fn zones(
+        &self,
+    ) -> Sf2Result<Vec<Sf2Zone<'a, Sf2InstrumentGenerator, Sf2InstrumentModulator>>> {
+        Ok(Sf2Zone::local_zones(
+            self.all_zones()?,
+            Sf2GeneratorKind::SampleId,
+        ))
+    }
+}
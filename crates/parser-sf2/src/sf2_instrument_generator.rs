@@ -1,8 +1,20 @@
-use zerocopy::{FromBytes, FromZeroes, Unaligned};
+use zerocopy::{AsBytes, FromBytes, FromZeroes, Unaligned, LE, U16};
 
-#[derive(Debug, FromZeroes, FromBytes, Unaligned)]
-#[repr(packed)]
+use crate::Sf2Generator;
+
+#[derive(Debug, FromZeroes, FromBytes, AsBytes, Unaligned)]
+#[repr(C, packed)]
 pub struct Sf2InstrumentGenerator {
-    // SFGenerator sfGenOper;
-    // genAmountType genAmount;
+    pub generator_operator: U16<LE>,
+    pub generator_amount: U16<LE>,
+}
+
+impl Sf2Generator for Sf2InstrumentGenerator {
+    fn generator_id(&self) -> u16 {
+        self.generator_operator.get()
+    }
+
+    fn raw_amount(&self) -> u16 {
+        self.generator_amount.get()
+    }
 }
@@ -1,10 +1,10 @@
-use zerocopy::{FromBytes, FromZeroes, Unaligned, LE, U16};
+use zerocopy::{AsBytes, FromBytes, FromZeroes, Unaligned, LE, U16};
 
 use crate::utils::str_from_fixedstr;
 use crate::Sf2Result;
 
-#[derive(Debug, FromZeroes, FromBytes, Unaligned)]
-#[repr(packed)]
+#[derive(Debug, FromZeroes, FromBytes, AsBytes, Unaligned)]
+#[repr(C, packed)]
 pub struct Sf2InstrumentHeader {
     pub instrument_name: [u8; 20],
     pub instrument_bag_index: U16<LE>,
@@ -0,0 +1,301 @@
+use crate::{
+    Sf2Generator, Sf2GeneratorAmount, Sf2GeneratorKind, Sf2Modulator, Sf2ModulatorDestination,
+    Sf2ModulatorSource, Sf2ModulatorTransform, Sf2Result, Sf2SoundFont, Sf2Zone,
+};
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+/// An owned, editable representation of a SoundFont.
+///
+/// Zone bag indices and sample offsets are not stored, they are computed
+/// when the model gets written out. Sample loop points are relative to the
+/// first sample point of their sample.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Sf2Model {
+    pub info: Sf2ModelInfo,
+    pub presets: Vec<Sf2ModelPreset>,
+    pub instruments: Vec<Sf2ModelInstrument>,
+    pub samples: Vec<Sf2ModelSample>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sf2ModelInfo {
+    pub format_version: (u16, u16),
+    pub sound_engine: String,
+    pub soundfont_name: String,
+    pub rom_name: Option<String>,
+    pub rom_version: Option<(u16, u16)>,
+    pub date: Option<String>,
+    pub author: Option<String>,
+    pub product: Option<String>,
+    pub copyright: Option<String>,
+    pub comment: Option<String>,
+    pub soundfont_tools: Option<Vec<String>>,
+}
+
+impl Default for Sf2ModelInfo {
+    fn default() -> Self {
+        Sf2ModelInfo {
+            format_version: (2, 4),
+            sound_engine: "EMU8000".to_owned(),
+            soundfont_name: "".to_owned(),
+            rom_name: None,
+            rom_version: None,
+            date: None,
+            author: None,
+            product: None,
+            copyright: None,
+            comment: None,
+            soundfont_tools: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Sf2ModelPreset {
+    pub preset_name: String,
+    pub preset: u16,
+    pub bank: u16,
+    pub library: u32,
+    pub genre: u32,
+    pub morphology: u32,
+    pub zones: Vec<Sf2ModelZone>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Sf2ModelInstrument {
+    pub instrument_name: String,
+    pub zones: Vec<Sf2ModelZone>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Sf2ModelZone {
+    pub generators: Vec<Sf2ModelGenerator>,
+    pub modulators: Vec<Sf2ModelModulator>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Sf2ModelGenerator {
+    pub generator_id: u16,
+    pub amount: u16,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Sf2ModelModulator {
+    pub source: u16,
+    pub destination: u16,
+    pub amount: i16,
+    pub amount_source: u16,
+    pub transform: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Sf2ModelSample {
+    pub sample_name: String,
+    pub samples_16: Vec<i16>,
+    pub samples_24_lsb: Option<Vec<u8>>,
+    pub start_loop: u32,
+    pub end_loop: u32,
+    pub sample_rate: u32,
+    pub original_pitch: u8,
+    pub pitch_correction: i8,
+    pub sample_link: u16,
+    pub sample_type: u16,
+    /// Start and end of a ROM sample within the ROM. ROM samples carry no
+    /// sample data of their own.
+    pub rom_range: Option<(u32, u32)>,
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+impl Sf2ModelGenerator {
+    pub fn new(generator_kind: Sf2GeneratorKind, amount: Sf2GeneratorAmount) -> Self {
+        Sf2ModelGenerator {
+            generator_id: generator_kind.id(),
+            amount: match amount {
+                Sf2GeneratorAmount::Signed(amount) => amount as u16,
+                Sf2GeneratorAmount::Unsigned(amount) => amount,
+                Sf2GeneratorAmount::Range { low, high } => u16::from_le_bytes([low, high]),
+            },
+        }
+    }
+}
+
+impl Sf2Generator for Sf2ModelGenerator {
+    fn generator_id(&self) -> u16 {
+        self.generator_id
+    }
+
+    fn raw_amount(&self) -> u16 {
+        self.amount
+    }
+}
+
+impl Sf2ModelModulator {
+    pub fn new(
+        source: Sf2ModulatorSource,
+        destination: Sf2ModulatorDestination,
+        amount: i16,
+        amount_source: Sf2ModulatorSource,
+        transform: Sf2ModulatorTransform,
+    ) -> Self {
+        Sf2ModelModulator {
+            source: source.to_raw(),
+            destination: destination.to_raw(),
+            amount,
+            amount_source: amount_source.to_raw(),
+            transform: transform as u16,
+        }
+    }
+}
+
+impl Sf2Modulator for Sf2ModelModulator {
+    fn raw_source(&self) -> u16 {
+        self.source
+    }
+
+    fn raw_destination(&self) -> u16 {
+        self.destination
+    }
+
+    fn amount(&self) -> i16 {
+        self.amount
+    }
+
+    fn raw_amount_source(&self) -> u16 {
+        self.amount_source
+    }
+
+    fn raw_transform(&self) -> u16 {
+        self.transform
+    }
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+impl<'a, G: Sf2Generator, M: Sf2Modulator> From<&Sf2Zone<'a, G, M>> for Sf2ModelZone {
+    fn from(zone: &Sf2Zone<'a, G, M>) -> Self {
+        Sf2ModelZone {
+            generators: zone
+                .generators()
+                .iter()
+                .map(|generator| Sf2ModelGenerator {
+                    generator_id: generator.generator_id(),
+                    amount: generator.raw_amount(),
+                })
+                .collect(),
+            modulators: zone
+                .modulators()
+                .iter()
+                .map(|modulator| Sf2ModelModulator {
+                    source: modulator.raw_source(),
+                    destination: modulator.raw_destination(),
+                    amount: modulator.amount(),
+                    amount_source: modulator.raw_amount_source(),
+                    transform: modulator.raw_transform(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Sf2Model {
+    pub fn from_soundfont<'a>(soundfont: &'a Sf2SoundFont<'a>) -> Sf2Result<Sf2Model> {
+        let info = {
+            let sf2_info = soundfont.info()?;
+
+            Sf2ModelInfo {
+                format_version: sf2_info.format_version()?,
+                sound_engine: sf2_info.sound_engine()?.to_owned(),
+                soundfont_name: sf2_info.soundfont_name()?.to_owned(),
+                rom_name: sf2_info.rom_name()?.map(str::to_owned),
+                rom_version: sf2_info.rom_version()?,
+                date: sf2_info.date()?.map(str::to_owned),
+                author: sf2_info.author()?.map(str::to_owned),
+                product: sf2_info.product()?.map(str::to_owned),
+                copyright: sf2_info.copyright()?.map(str::to_owned),
+                comment: sf2_info.comment()?.map(str::to_owned),
+                soundfont_tools: sf2_info
+                    .soundfont_tools()?
+                    .map(|tools| tools.into_iter().map(str::to_owned).collect()),
+            }
+        };
+
+        let presets = soundfont
+            .presets()?
+            .iter()
+            .map(|preset| {
+                let preset_header = preset.preset_header();
+
+                Ok(Sf2ModelPreset {
+                    preset_name: preset_header.preset_name()?.to_owned(),
+                    preset: preset_header.preset.get(),
+                    bank: preset_header.bank.get(),
+                    library: preset_header.library.get(),
+                    genre: preset_header.genre.get(),
+                    morphology: preset_header.morphology.get(),
+                    zones: preset.all_zones()?.iter().map(Sf2ModelZone::from).collect(),
+                })
+            })
+            .collect::<Sf2Result<Vec<_>>>()?;
+
+        let instruments = soundfont
+            .instruments()?
+            .iter()
+            .map(|instrument| {
+                Ok(Sf2ModelInstrument {
+                    instrument_name: instrument.instrument_header().instrument_name()?.to_owned(),
+                    zones: instrument
+                        .all_zones()?
+                        .iter()
+                        .map(Sf2ModelZone::from)
+                        .collect(),
+                })
+            })
+            .collect::<Sf2Result<Vec<_>>>()?;
+
+        let samples = soundfont
+            .sample_headers()?
+            .iter()
+            .map(|sample_header| {
+                let start = sample_header.start.get();
+
+                let is_rom = sample_header
+                    .sample_type()
+                    .is_ok_and(|sample_type| sample_type.is_rom());
+
+                let (samples_16, samples_24_lsb, rom_range) = if is_rom {
+                    (vec![], None, Some((start, sample_header.end.get())))
+                } else {
+                    let sample_data = soundfont.sample_data(sample_header)?;
+                    (
+                        sample_data.frames_16().collect(),
+                        sample_data.samples_24_lsb().map(<[u8]>::to_vec),
+                        None,
+                    )
+                };
+
+                Ok(Sf2ModelSample {
+                    sample_name: sample_header.sample_name()?.to_owned(),
+                    samples_16,
+                    samples_24_lsb,
+                    start_loop: sample_header.start_loop.get().wrapping_sub(start),
+                    end_loop: sample_header.end_loop.get().wrapping_sub(start),
+                    sample_rate: sample_header.sample_rate.get(),
+                    original_pitch: sample_header.original_pitch,
+                    pitch_correction: sample_header.pitch_correction,
+                    sample_link: sample_header.sample_link.get(),
+                    sample_type: sample_header.sample_type.get(),
+                    rom_range,
+                })
+            })
+            .collect::<Sf2Result<Vec<_>>>()?;
+
+        Ok(Sf2Model {
+            info,
+            presets,
+            instruments,
+            samples,
+        })
+    }
+}
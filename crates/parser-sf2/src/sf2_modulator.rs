@@ -0,0 +1,248 @@
+use std::fmt::{self, Display};
+
+use strum::{Display, EnumIter, FromRepr};
+
+use crate::{Sf2Error, Sf2GeneratorKind, Sf2Result};
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Display)]
+pub enum Sf2ControllerPalette {
+    #[strum(to_string = "general")]
+    General,
+
+    #[strum(to_string = "midi")]
+    Midi,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Display)]
+pub enum Sf2SourceDirection {
+    #[strum(to_string = "positive")]
+    Positive,
+
+    #[strum(to_string = "negative")]
+    Negative,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Display)]
+pub enum Sf2SourcePolarity {
+    #[strum(to_string = "unipolar")]
+    Unipolar,
+
+    #[strum(to_string = "bipolar")]
+    Bipolar,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Display, EnumIter, FromRepr)]
+#[repr(u8)]
+pub enum Sf2SourceCurveType {
+    #[strum(to_string = "linear")]
+    Linear = 0,
+
+    #[strum(to_string = "concave")]
+    Concave = 1,
+
+    #[strum(to_string = "convex")]
+    Convex = 2,
+
+    #[strum(to_string = "switch")]
+    Switch = 3,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Display, EnumIter, FromRepr)]
+#[repr(u8)]
+pub enum Sf2GeneralController {
+    #[strum(to_string = "No Controller")]
+    NoController = 0,
+
+    #[strum(to_string = "Note-On Velocity")]
+    NoteOnVelocity = 2,
+
+    #[strum(to_string = "Note-On Key Number")]
+    NoteOnKeyNumber = 3,
+
+    #[strum(to_string = "Poly Pressure")]
+    PolyPressure = 10,
+
+    #[strum(to_string = "Channel Pressure")]
+    ChannelPressure = 13,
+
+    #[strum(to_string = "Pitch Wheel")]
+    PitchWheel = 14,
+
+    #[strum(to_string = "Pitch Wheel Sensitivity")]
+    PitchWheelSensitivity = 16,
+
+    #[strum(to_string = "Link")]
+    Link = 127,
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Sf2ModulatorSource {
+    pub controller_palette: Sf2ControllerPalette,
+    pub index: u8,
+    pub direction: Sf2SourceDirection,
+    pub polarity: Sf2SourcePolarity,
+    pub curve_type: Sf2SourceCurveType,
+}
+
+impl Sf2ModulatorSource {
+    pub fn from_raw(raw_source: u16) -> Sf2Result<Sf2ModulatorSource> {
+        let curve_type = (raw_source >> 10) as u8;
+
+        Ok(Sf2ModulatorSource {
+            controller_palette: if raw_source & 0x0080 != 0 {
+                Sf2ControllerPalette::Midi
+            } else {
+                Sf2ControllerPalette::General
+            },
+            index: (raw_source & 0x007F) as u8,
+            direction: if raw_source & 0x0100 != 0 {
+                Sf2SourceDirection::Negative
+            } else {
+                Sf2SourceDirection::Positive
+            },
+            polarity: if raw_source & 0x0200 != 0 {
+                Sf2SourcePolarity::Bipolar
+            } else {
+                Sf2SourcePolarity::Unipolar
+            },
+            curve_type: Sf2SourceCurveType::from_repr(curve_type)
+                .ok_or(Sf2Error::UnknownModulatorCurveType { curve_type })?,
+        })
+    }
+
+    pub fn to_raw(&self) -> u16 {
+        let mut raw_source = (self.index as u16) & 0x007F;
+
+        if self.controller_palette == Sf2ControllerPalette::Midi {
+            raw_source |= 0x0080;
+        }
+
+        if self.direction == Sf2SourceDirection::Negative {
+            raw_source |= 0x0100;
+        }
+
+        if self.polarity == Sf2SourcePolarity::Bipolar {
+            raw_source |= 0x0200;
+        }
+
+        raw_source | ((self.curve_type as u16) << 10)
+    }
+
+    pub fn general_controller(&self) -> Option<Sf2GeneralController> {
+        match self.controller_palette {
+            Sf2ControllerPalette::General => Sf2GeneralController::from_repr(self.index),
+            Sf2ControllerPalette::Midi => None,
+        }
+    }
+
+    pub fn is_none(&self) -> bool {
+        self.general_controller() == Some(Sf2GeneralController::NoController)
+    }
+}
+
+impl Display for Sf2ModulatorSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(general_controller) = self.general_controller() {
+            write!(f, "{general_controller:}")?;
+        } else {
+            write!(f, "{}:{}", self.controller_palette, self.index)?;
+        }
+
+        write!(
+            f,
+            " ({}, {}, {})",
+            self.direction, self.polarity, self.curve_type
+        )
+    }
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Sf2ModulatorDestination {
+    Generator(Sf2GeneratorKind),
+    Link(u16),
+}
+
+impl Sf2ModulatorDestination {
+    pub fn from_raw(raw_destination: u16) -> Sf2Result<Sf2ModulatorDestination> {
+        if raw_destination & 0x8000 != 0 {
+            Ok(Sf2ModulatorDestination::Link(raw_destination & 0x7FFF))
+        } else {
+            Ok(Sf2ModulatorDestination::Generator(
+                Sf2GeneratorKind::from_id(raw_destination)?,
+            ))
+        }
+    }
+
+    pub fn to_raw(&self) -> u16 {
+        match self {
+            Sf2ModulatorDestination::Generator(generator_kind) => generator_kind.id(),
+            Sf2ModulatorDestination::Link(modulator_index) => 0x8000 | modulator_index,
+        }
+    }
+}
+
+impl Display for Sf2ModulatorDestination {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Sf2ModulatorDestination::Generator(generator_kind) => write!(f, "{generator_kind:}"),
+            Sf2ModulatorDestination::Link(modulator_index) => write!(f, "link:{modulator_index:}"),
+        }
+    }
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Display, EnumIter, FromRepr)]
+#[repr(u16)]
+pub enum Sf2ModulatorTransform {
+    #[strum(to_string = "linear")]
+    Linear = 0,
+
+    #[strum(to_string = "absolute value")]
+    AbsoluteValue = 2,
+}
+
+impl Sf2ModulatorTransform {
+    pub fn from_raw(raw_transform: u16) -> Sf2Result<Sf2ModulatorTransform> {
+        Sf2ModulatorTransform::from_repr(raw_transform)
+            .ok_or(Sf2Error::UnknownModulatorTransform { raw_transform })
+    }
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+/// Common accessors of the preset-level (`pmod`) and instrument-level (`imod`)
+/// modulator records, both sharing the same `sfModList` layout.
+pub trait Sf2Modulator {
+    fn raw_source(&self) -> u16;
+
+    fn raw_destination(&self) -> u16;
+
+    fn amount(&self) -> i16;
+
+    fn raw_amount_source(&self) -> u16;
+
+    fn raw_transform(&self) -> u16;
+
+    fn source(&self) -> Sf2Result<Sf2ModulatorSource> {
+        Sf2ModulatorSource::from_raw(self.raw_source())
+    }
+
+    fn destination(&self) -> Sf2Result<Sf2ModulatorDestination> {
+        Sf2ModulatorDestination::from_raw(self.raw_destination())
+    }
+
+    fn amount_source(&self) -> Sf2Result<Sf2ModulatorSource> {
+        Sf2ModulatorSource::from_raw(self.raw_amount_source())
+    }
+
+    fn transform(&self) -> Sf2Result<Sf2ModulatorTransform> {
+        Sf2ModulatorTransform::from_raw(self.raw_transform())
+    }
+}
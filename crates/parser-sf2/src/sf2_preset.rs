@@ -0,0 +1,79 @@
+use crate::{
+    Sf2Error, Sf2GeneratorKind, Sf2PresetGenerator, Sf2PresetHeader, Sf2PresetModulator, Sf2Result,
+    Sf2SoundFont, Sf2Zone,
+};
+
+pub struct Sf2Preset<'a> {
+    soundfont: &'a Sf2SoundFont<'a>,
+    preset_index: usize,
+    preset_header: &'a Sf2PresetHeader,
+}
+
+impl<'a> Sf2Preset<'a> {
+    pub(crate) fn new(
+        soundfont: &'a Sf2SoundFont<'a>,
+        preset_index: usize,
+        preset_header: &'a Sf2PresetHeader,
+    ) -> Sf2Preset<'a> {
+        Sf2Preset {
+            soundfont,
+            preset_index,
+            preset_header,
+        }
+    }
+
+    pub fn preset_index(&self) -> usize {
+        self.preset_index
+    }
+
+    pub fn preset_header(&self) -> &'a Sf2PresetHeader {
+        self.preset_header
+    }
+
+    pub fn all_zones(&self) -> Sf2Result<Vec<Sf2Zone<'a, Sf2PresetGenerator, Sf2PresetModulator>>> {
+        let preset_headers = self.soundfont.preset_headers_with_terminator()?;
+
+        let bag_start = preset_headers[self.preset_index].preset_bag_index.get() as usize;
+        let bag_end = preset_headers[self.preset_index + 1].preset_bag_index.get() as usize;
+
+        let bag_indices = self
+            .soundfont
+            .preset_zones_with_terminator()?
+            .get(bag_start..=bag_end)
+            .ok_or(Sf2Error::IndexOutOfRange {
+                chunk_id: "pbag".to_owned(),
+                index: bag_end,
+            })?
+            .iter()
+            .map(|preset_zone| {
+                (
+                    preset_zone.generator_index.get() as usize,
+                    preset_zone.modulator_index.get() as usize,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        Sf2Zone::from_bags(
+            self.soundfont,
+            &bag_indices,
+            ("pgen", self.soundfont.preset_generators()?),
+            ("pmod", self.soundfont.preset_modulators()?),
+        )
+    }
+
+    pub fn global_zone(
+        &self,
+    ) -> Sf2Result<Option<Sf2Zone<'a, Sf2PresetGenerator, Sf2PresetModulator>>> {
+        Ok(Sf2Zone::global_zone(
+            self.all_zones()?,
+            Sf2GeneratorKind::Instrument,
+        ))
+    }
+
+    pub fn zones(&self) -> Sf2Result<Vec<Sf2Zone<'a, Sf2PresetGenerator, Sf2PresetModulator>>> {
+        Ok(Sf2Zone::local_zones(
+            self.all_zones()?,
+            Sf2GeneratorKind::Instrument,
+        ))
+    }
+}
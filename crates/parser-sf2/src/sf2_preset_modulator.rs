@@ -1,11 +1,35 @@
-use zerocopy::{FromBytes, FromZeroes, Unaligned};
+use zerocopy::{AsBytes, FromBytes, FromZeroes, Unaligned, I16, LE, U16};
 
-#[derive(Debug, FromZeroes, FromBytes, Unaligned)]
-#[repr(packed)]
+use crate::Sf2Modulator;
+
+#[derive(Debug, FromZeroes, FromBytes, AsBytes, Unaligned)]
+#[repr(C, packed)]
 pub struct Sf2PresetModulator {
-    // SFModulator sfModSrcOper;
-    // SFGenerator sfModDestOper;
-    // SHORT modAmount;
-    // SFModulator sfModAmtSrcOper;
-    // SFTransform sfModTransOper;
+    pub source_operator: U16<LE>,
+    pub destination_operator: U16<LE>,
+    pub modulator_amount: I16<LE>,
+    pub amount_source_operator: U16<LE>,
+    pub transform_operator: U16<LE>,
+}
+
+impl Sf2Modulator for Sf2PresetModulator {
+    fn raw_source(&self) -> u16 {
+        self.source_operator.get()
+    }
+
+    fn raw_destination(&self) -> u16 {
+        self.destination_operator.get()
+    }
+
+    fn amount(&self) -> i16 {
+        self.modulator_amount.get()
+    }
+
+    fn raw_amount_source(&self) -> u16 {
+        self.amount_source_operator.get()
+    }
+
+    fn raw_transform(&self) -> u16 {
+        self.transform_operator.get()
+    }
 }
@@ -1,7 +1,7 @@
-use zerocopy::{FromBytes, FromZeroes, Unaligned, LE, U16};
+use zerocopy::{AsBytes, FromBytes, FromZeroes, Unaligned, LE, U16};
 
-#[derive(Debug, FromZeroes, FromBytes, Unaligned)]
-#[repr(packed)]
+#[derive(Debug, FromZeroes, FromBytes, AsBytes, Unaligned)]
+#[repr(C, packed)]
 pub struct Sf2PresetZone {
     pub generator_index: U16<LE>,
     pub modulator_index: U16<LE>,
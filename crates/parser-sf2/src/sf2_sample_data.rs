@@ -0,0 +1,53 @@
+use zerocopy::{I16, LE};
+
+#[derive(Debug, Copy, Clone)]
+pub struct Sf2SampleData<'a> {
+    samples_16: &'a [I16<LE>],
+    samples_24: Option<&'a [u8]>,
+}
+
+impl<'a> Sf2SampleData<'a> {
+    pub(crate) fn new(samples_16: &'a [I16<LE>], samples_24: Option<&'a [u8]>) -> Self {
+        Sf2SampleData {
+            samples_16,
+            samples_24,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples_16.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples_16.is_empty()
+    }
+
+    pub fn has_24bit(&self) -> bool {
+        self.samples_24.is_some()
+    }
+
+    /// The upper 16 bits of the sample points, as stored in the `smpl` chunk.
+    pub fn samples_16(&self) -> &'a [I16<LE>] {
+        self.samples_16
+    }
+
+    /// The lower 8 bits of the sample points, as stored in the `sm24` chunk.
+    pub fn samples_24_lsb(&self) -> Option<&'a [u8]> {
+        self.samples_24
+    }
+
+    pub fn frames_16(&self) -> impl Iterator<Item = i16> + 'a {
+        self.samples_16.iter().map(|sample| sample.get())
+    }
+
+    /// Sample points combined from the `smpl` and `sm24` chunks, sign-extended
+    /// into the 24-bit range of an `i32`.
+    pub fn frames_24(&self) -> Option<impl Iterator<Item = i32> + 'a> {
+        self.samples_24.map(|samples_24| {
+            self.samples_16
+                .iter()
+                .zip(samples_24)
+                .map(|(msb, &lsb)| ((msb.get() as i32) << 8) | (lsb as i32))
+        })
+    }
+}
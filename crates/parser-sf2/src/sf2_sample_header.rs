@@ -1,10 +1,63 @@
-use zerocopy::{FromBytes, FromZeroes, Unaligned, LE, U16, U32};
+use strum::{Display, EnumIter, FromRepr};
+use zerocopy::{AsBytes, FromBytes, FromZeroes, Unaligned, LE, U16, U32};
 
 use crate::utils::str_from_fixedstr;
-use crate::Sf2Result;
+use crate::{Sf2Error, Sf2Result};
 
-#[derive(Debug, FromZeroes, FromBytes, Unaligned)]
-#[repr(packed)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Display, EnumIter, FromRepr)]
+#[repr(u16)]
+pub enum Sf2SampleType {
+    #[strum(to_string = "monoSample")]
+    Mono = 0x0001,
+
+    #[strum(to_string = "rightSample")]
+    Right = 0x0002,
+
+    #[strum(to_string = "leftSample")]
+    Left = 0x0004,
+
+    #[strum(to_string = "linkedSample")]
+    Linked = 0x0008,
+
+    #[strum(to_string = "RomMonoSample")]
+    RomMono = 0x8001,
+
+    #[strum(to_string = "RomRightSample")]
+    RomRight = 0x8002,
+
+    #[strum(to_string = "RomLeftSample")]
+    RomLeft = 0x8004,
+
+    #[strum(to_string = "RomLinkedSample")]
+    RomLinked = 0x8008,
+}
+
+impl Sf2SampleType {
+    pub fn is_rom(&self) -> bool {
+        (*self as u16) & 0x8000 != 0
+    }
+
+    pub fn is_mono(&self) -> bool {
+        matches!(self, Sf2SampleType::Mono | Sf2SampleType::RomMono)
+    }
+
+    pub fn is_left(&self) -> bool {
+        matches!(self, Sf2SampleType::Left | Sf2SampleType::RomLeft)
+    }
+
+    pub fn is_right(&self) -> bool {
+        matches!(self, Sf2SampleType::Right | Sf2SampleType::RomRight)
+    }
+
+    pub fn is_linked(&self) -> bool {
+        matches!(self, Sf2SampleType::Linked | Sf2SampleType::RomLinked)
+    }
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+#[derive(Debug, FromZeroes, FromBytes, AsBytes, Unaligned)]
+#[repr(C, packed)]
 pub struct Sf2SampleHeader {
     pub sample_name: [u8; 20],
     pub start: U32<LE>,
@@ -15,11 +68,16 @@ pub struct Sf2SampleHeader {
     pub original_pitch: u8,
     pub pitch_correction: i8,
     pub sample_link: U16<LE>,
-    pub sample_type: U16<LE>, //TODO: SFSampleLink sfSampleType;
+    pub sample_type: U16<LE>,
 }
 
 impl Sf2SampleHeader {
     pub fn sample_name(&self) -> Sf2Result<&str> {
         str_from_fixedstr(&self.sample_name)
     }
+
+    pub fn sample_type(&self) -> Sf2Result<Sf2SampleType> {
+        let sample_type = self.sample_type.get();
+        Sf2SampleType::from_repr(sample_type).ok_or(Sf2Error::UnknownSampleType { sample_type })
+    }
 }
@@ -1,29 +1,37 @@
-use zerocopy::FromBytes;
+use zerocopy::{FromBytes, I16, LE};
 
-use parser_riff::RiffChunk;
+use parser_riff::{RiffChunk, RiffParseMode, RiffResult, RiffWarning};
 
 use crate::{
-    Sf2Error, Sf2Info, Sf2InstrumentGenerator, Sf2InstrumentHeader, Sf2InstrumentModulator,
-    Sf2InstrumentZone, Sf2PresetGenerator, Sf2PresetHeader, Sf2PresetModulator, Sf2PresetZone,
-    Sf2Result, Sf2SampleHeader,
+    Sf2Error, Sf2Info, Sf2Instrument, Sf2InstrumentGenerator, Sf2InstrumentHeader,
+    Sf2InstrumentModulator, Sf2InstrumentZone, Sf2Preset, Sf2PresetGenerator, Sf2PresetHeader,
+    Sf2PresetModulator, Sf2PresetZone, Sf2Result, Sf2SampleData, Sf2SampleHeader,
 };
 
 // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
 
 trait Sf2TypedSlice {
-    fn as_typed_slice<T: FromBytes>(&self) -> Sf2Result<&[T]>;
+    fn as_typed_slice_with_terminator<T: FromBytes>(&self) -> Sf2Result<&[T]>;
+
+    fn as_typed_slice<T: FromBytes>(&self) -> Sf2Result<&[T]> {
+        let typed_slice = self.as_typed_slice_with_terminator()?;
+        Ok(&typed_slice[..typed_slice.len() - 1])
+    }
 }
 
 impl<'a> Sf2TypedSlice for RiffChunk<'a> {
-    fn as_typed_slice<T: FromBytes>(&self) -> Sf2Result<&[T]> {
-        let (_, typed_slice) = T::slice_from(self.chunk_data()?)
-            .ok_or(Sf2Error::MalformedChunk {
-                chunk_id: self.chunk_id().to_owned(),
-            })?
-            .split_last()
-            .ok_or(Sf2Error::MissingTerminatorRecord {
+    fn as_typed_slice_with_terminator<T: FromBytes>(&self) -> Sf2Result<&[T]> {
+        let typed_slice = T::slice_from(self.chunk_data()?).ok_or(Sf2Error::MalformedChunk {
+            chunk_id: self.chunk_id().to_owned(),
+            offset: self.chunk_offset(),
+        })?;
+
+        if typed_slice.is_empty() {
+            return Err(Sf2Error::MissingTerminatorRecord {
                 chunk_id: self.chunk_id().to_owned(),
-            })?;
+                offset: self.chunk_offset(),
+            });
+        }
 
         Ok(typed_slice)
     }
@@ -46,6 +54,53 @@ impl<'a> Sf2SoundFont<'a> {
         Ok(Sf2SoundFont { root_chunk })
     }
 
+    /// Parses the RIFF structure in the given mode, see `RiffChunk::with_mode`.
+    pub fn with_mode(
+        buffer: &'a [u8],
+        parse_mode: RiffParseMode,
+    ) -> Sf2Result<(Sf2SoundFont<'a>, Vec<RiffWarning>)> {
+        let (root_chunk, warnings) = RiffChunk::with_mode(buffer, parse_mode)?;
+
+        if root_chunk.chunk_id() != "sfbk" {
+            return Err(Sf2Error::InvalidRootChunk);
+        }
+
+        Ok((Sf2SoundFont { root_chunk }, warnings))
+    }
+
+    /// Assembles a SoundFont from separately loaded top-level chunks (`INFO`,
+    /// `sdta`, `pdta`), each buffer holding a whole chunk including its header,
+    /// paired with the offset it was loaded from. Any of the chunks may be left
+    /// out, accessing data from the missing chunks results in
+    /// `RiffError::MissingSubchunk` errors.
+    pub fn from_chunks(chunk_buffers: &[(usize, &'a [u8])]) -> Sf2Result<Sf2SoundFont<'a>> {
+        let subchunks = chunk_buffers
+            .iter()
+            .map(|&(chunk_offset, chunk_buffer)| {
+                RiffChunk::with_offset(chunk_buffer, chunk_offset, RiffParseMode::Strict)
+                    .map(|(chunk, _)| chunk)
+            })
+            .collect::<RiffResult<Vec<_>>>()?;
+
+        let root_chunk = RiffChunk::Container {
+            chunk_type: "RIFF",
+            chunk_id: "sfbk",
+            chunk_size: 4 + subchunks
+                .iter()
+                .map(|subchunk| 8 + subchunk.chunk_size() + subchunk.chunk_padding() as u32)
+                .sum::<u32>(),
+            subchunks,
+            chunk_offset: 0,
+            chunk_padding: 0,
+        };
+
+        Ok(Sf2SoundFont { root_chunk })
+    }
+
+    pub(crate) fn root_chunk(&self) -> &RiffChunk<'a> {
+        &self.root_chunk
+    }
+
     pub fn preset_headers(&'a self) -> Sf2Result<&'a [Sf2PresetHeader]> {
         self.root_chunk
             .subchunk("pdta")?
@@ -109,7 +164,180 @@ impl<'a> Sf2SoundFont<'a> {
             .as_typed_slice()
     }
 
-    pub fn info(&self) -> Sf2Result<Sf2Info> {
+    pub fn sample_data_16_chunk(&'a self) -> Sf2Result<&'a [I16<LE>]> {
+        let chunk_smpl = self.root_chunk.subchunk("sdta")?.subchunk("smpl")?;
+
+        I16::<LE>::slice_from(chunk_smpl.chunk_data()?).ok_or(Sf2Error::MalformedChunk {
+            chunk_id: chunk_smpl.chunk_id().to_owned(),
+            offset: chunk_smpl.chunk_offset(),
+        })
+    }
+
+    pub fn sample_data_24_chunk(&'a self) -> Sf2Result<Option<&'a [u8]>> {
+        let sample_count = self.sample_data_16_chunk()?.len();
+
+        if let Some(chunk_sm24) = self.root_chunk.subchunk("sdta")?.subchunk_opt("sm24")? {
+            let chunk_data = chunk_sm24.chunk_data()?;
+
+            // The `sm24` chunk must be ignored when its size does not match
+            // the `smpl` chunk, allowing for a pad byte at the end.
+            if (sample_count..=sample_count.next_multiple_of(2)).contains(&chunk_data.len()) {
+                return Ok(Some(&chunk_data[..sample_count]));
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub fn sample_data(&'a self, sample_header: &Sf2SampleHeader) -> Sf2Result<Sf2SampleData<'a>> {
+        let start = sample_header.start.get();
+        let end = sample_header.end.get();
+
+        let sample_range = (start as usize)..(end as usize);
+
+        let samples_16 = self
+            .sample_data_16_chunk()?
+            .get(sample_range.clone())
+            .ok_or(Sf2Error::SampleDataOutOfBounds { start, end })?;
+
+        let samples_24 = self
+            .sample_data_24_chunk()?
+            .map(|samples_24| &samples_24[sample_range]);
+
+        Ok(Sf2SampleData::new(samples_16, samples_24))
+    }
+
+    pub(crate) fn preset_headers_with_terminator(&'a self) -> Sf2Result<&'a [Sf2PresetHeader]> {
+        self.root_chunk
+            .subchunk("pdta")?
+            .subchunk("phdr")?
+            .as_typed_slice_with_terminator()
+    }
+
+    pub(crate) fn preset_zones_with_terminator(&'a self) -> Sf2Result<&'a [Sf2PresetZone]> {
+        self.root_chunk
+            .subchunk("pdta")?
+            .subchunk("pbag")?
+            .as_typed_slice_with_terminator()
+    }
+
+    pub(crate) fn instrument_headers_with_terminator(
+        &'a self,
+    ) -> Sf2Result<&'a [Sf2InstrumentHeader]> {
+        self.root_chunk
+            .subchunk("pdta")?
+            .subchunk("inst")?
+            .as_typed_slice_with_terminator()
+    }
+
+    pub(crate) fn instrument_zones_with_terminator(&'a self) -> Sf2Result<&'a [Sf2InstrumentZone]> {
+        self.root_chunk
+            .subchunk("pdta")?
+            .subchunk("ibag")?
+            .as_typed_slice_with_terminator()
+    }
+
+    pub fn presets(&'a self) -> Sf2Result<Vec<Sf2Preset<'a>>> {
+        (0..self.preset_headers()?.len())
+            .map(|preset_index| self.preset(preset_index))
+            .collect()
+    }
+
+    pub fn preset(&'a self, preset_index: usize) -> Sf2Result<Sf2Preset<'a>> {
+        let preset_header =
+            self.preset_headers()?
+                .get(preset_index)
+                .ok_or(Sf2Error::IndexOutOfRange {
+                    chunk_id: "phdr".to_owned(),
+                    index: preset_index,
+                })?;
+
+        Ok(Sf2Preset::new(self, preset_index, preset_header))
+    }
+
+    pub fn find_preset(&'a self, bank: u16, preset: u16) -> Sf2Result<Option<Sf2Preset<'a>>> {
+        self.preset_headers()?
+            .iter()
+            .position(|preset_header| preset_header.bank_preset() == (bank, preset))
+            .map(|preset_index| self.preset(preset_index))
+            .transpose()
+    }
+
+    pub fn instruments(&'a self) -> Sf2Result<Vec<Sf2Instrument<'a>>> {
+        (0..self.instrument_headers()?.len())
+            .map(|instrument_index| self.instrument(instrument_index))
+            .collect()
+    }
+
+    pub fn instrument(&'a self, instrument_index: usize) -> Sf2Result<Sf2Instrument<'a>> {
+        let instrument_header =
+            self.instrument_headers()?
+                .get(instrument_index)
+                .ok_or(Sf2Error::IndexOutOfRange {
+                    chunk_id: "inst".to_owned(),
+                    index: instrument_index,
+                })?;
+
+        Ok(Sf2Instrument::new(
+            self,
+            instrument_index,
+            instrument_header,
+        ))
+    }
+
+    pub fn sample_header(&'a self, sample_index: usize) -> Sf2Result<&'a Sf2SampleHeader> {
+        self.sample_headers()?
+            .get(sample_index)
+            .ok_or(Sf2Error::IndexOutOfRange {
+                chunk_id: "shdr".to_owned(),
+                index: sample_index,
+            })
+    }
+
+    /// Follows the `sample_link` field of a sample to its stereo partner.
+    ///
+    /// Returns `None` for mono samples. The link of left and right samples must
+    /// be mutual with a partner of the opposite side. Linked samples form
+    /// a circular chain, for them the next sample in the chain is returned.
+    pub fn linked_sample_header(
+        &'a self,
+        sample_index: usize,
+    ) -> Sf2Result<Option<&'a Sf2SampleHeader>> {
+        let sample_header = self.sample_header(sample_index)?;
+        let sample_type = sample_header.sample_type()?;
+
+        if sample_type.is_mono() {
+            return Ok(None);
+        }
+
+        let linked_sample_index = sample_header.sample_link.get() as usize;
+        let linked_sample_header =
+            self.sample_headers()?
+                .get(linked_sample_index)
+                .ok_or(Sf2Error::BrokenSampleLink {
+                    sample_index,
+                    linked_sample_index,
+                })?;
+
+        if !sample_type.is_linked() {
+            let linked_sample_type = linked_sample_header.sample_type()?;
+
+            let is_mutual = linked_sample_header.sample_link.get() as usize == sample_index;
+            let is_opposite = (sample_type.is_left() && linked_sample_type.is_right())
+                || (sample_type.is_right() && linked_sample_type.is_left());
+
+            if !is_mutual || !is_opposite {
+                return Err(Sf2Error::BrokenSampleLink {
+                    sample_index,
+                    linked_sample_index,
+                });
+            }
+        }
+
+        Ok(Some(linked_sample_header))
+    }
+
+    pub fn info(&self) -> Sf2Result<Sf2Info<'_>> {
         #[rustfmt::skip]
         let chunk_info = self
             .root_chunk
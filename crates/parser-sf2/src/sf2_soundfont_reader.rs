@@ -0,0 +1,133 @@
+use std::cell::RefCell;
+use std::io::{Read, Seek};
+
+use parser_riff::RiffStreamChunk;
+
+use crate::{Sf2Error, Sf2Result, Sf2SampleHeader, Sf2SoundFont};
+
+/// SoundFont reader working over a `Read + Seek` stream instead of a buffer
+/// holding the whole file.
+///
+/// Only the `INFO` and `pdta` chunks get loaded into memory, sample data is
+/// read from the `sdta` chunk on demand.
+pub struct Sf2SoundFontReader<R: Read + Seek> {
+    reader: RefCell<R>,
+    root_chunk: RiffStreamChunk,
+    buffer_info: Vec<u8>,
+    buffer_pdta: Vec<u8>,
+}
+
+impl<R: Read + Seek> Sf2SoundFontReader<R> {
+    pub fn new(mut reader: R) -> Sf2Result<Sf2SoundFontReader<R>> {
+        let root_chunk = RiffStreamChunk::new(&mut reader)?;
+
+        if root_chunk.chunk_id() != "sfbk" {
+            return Err(Sf2Error::InvalidRootChunk);
+        }
+
+        let buffer_info = root_chunk.subchunk("INFO")?.read_chunk(&mut reader)?;
+        let buffer_pdta = root_chunk.subchunk("pdta")?.read_chunk(&mut reader)?;
+
+        Ok(Sf2SoundFontReader {
+            reader: RefCell::new(reader),
+            root_chunk,
+            buffer_info,
+            buffer_pdta,
+        })
+    }
+
+    pub fn root_chunk(&self) -> &RiffStreamChunk {
+        &self.root_chunk
+    }
+
+    /// The SoundFont view of the loaded `INFO` and `pdta` chunks. Sample data
+    /// is not available through it, use `read_sample_data_16` and
+    /// `read_sample_data_24` instead.
+    pub fn soundfont(&self) -> Sf2Result<Sf2SoundFont<'_>> {
+        Sf2SoundFont::from_chunks(&[
+            (
+                self.root_chunk.subchunk("INFO")?.chunk_offset() as usize,
+                &self.buffer_info,
+            ),
+            (
+                self.root_chunk.subchunk("pdta")?.chunk_offset() as usize,
+                &self.buffer_pdta,
+            ),
+        ])
+    }
+
+    fn read_sample_bytes(
+        &self,
+        chunk_id: &str,
+        sample_header: &Sf2SampleHeader,
+        bytes_per_sample: u32,
+    ) -> Sf2Result<Option<Vec<u8>>> {
+        let Some(chunk) = self.root_chunk.subchunk("sdta")?.subchunk_opt(chunk_id)? else {
+            return Ok(None);
+        };
+
+        let start = sample_header.start.get();
+        let end = sample_header.end.get();
+
+        let byte_range = start
+            .checked_mul(bytes_per_sample)
+            .zip(end.checked_mul(bytes_per_sample))
+            .filter(|(byte_start, byte_end)| {
+                (byte_start <= byte_end) && (*byte_end <= chunk.chunk_size())
+            })
+            .ok_or(Sf2Error::SampleDataOutOfBounds { start, end })?;
+
+        let mut reader = self.reader.borrow_mut();
+        Ok(Some(chunk.read_chunk_data_range(
+            &mut *reader,
+            byte_range.0..byte_range.1,
+        )?))
+    }
+
+    pub fn read_sample_data_16(&self, sample_header: &Sf2SampleHeader) -> Sf2Result<Vec<i16>> {
+        let sample_bytes =
+            self.read_sample_bytes("smpl", sample_header, 2)?
+                .ok_or(Sf2Error::MissingChunk {
+                    chunk_id: "smpl".to_owned(),
+                })?;
+
+        Ok(sample_bytes
+            .chunks_exact(2)
+            .map(|sample| i16::from_le_bytes([sample[0], sample[1]]))
+            .collect())
+    }
+
+    /// Sample points combined from the `smpl` and `sm24` chunks, or `None`
+    /// when the SoundFont has no `sm24` chunk.
+    pub fn read_sample_data_24(
+        &self,
+        sample_header: &Sf2SampleHeader,
+    ) -> Sf2Result<Option<Vec<i32>>> {
+        let chunk_sdta = self.root_chunk.subchunk("sdta")?;
+
+        if let (Some(chunk_smpl), Some(chunk_sm24)) = (
+            chunk_sdta.subchunk_opt("smpl")?,
+            chunk_sdta.subchunk_opt("sm24")?,
+        ) {
+            // The `sm24` chunk must be ignored when its size does not match
+            // the `smpl` chunk, allowing for a pad byte at the end.
+            let sample_count = chunk_smpl.chunk_size() / 2;
+            if !(sample_count..=sample_count.next_multiple_of(2)).contains(&chunk_sm24.chunk_size())
+            {
+                return Ok(None);
+            }
+        }
+
+        let Some(samples_24_lsb) = self.read_sample_bytes("sm24", sample_header, 1)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(
+            self.read_sample_data_16(sample_header)?
+                .into_iter()
+                .zip(samples_24_lsb)
+                .map(|(msb, lsb)| ((msb as i32) << 8) | (lsb as i32))
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,582 @@
+use std::collections::HashMap;
+use std::mem::size_of;
+
+use derive_more::Display;
+
+use crate::{
+    Sf2Generator, Sf2GeneratorKind, Sf2InstrumentGenerator, Sf2InstrumentHeader, Sf2InstrumentZone,
+    Sf2PresetGenerator, Sf2PresetHeader, Sf2PresetModulator, Sf2PresetZone, Sf2Result,
+    Sf2SampleHeader, Sf2SoundFont, Sf2Zone,
+};
+
+/// Number of sample points required after each sample by the specification.
+pub const SAMPLE_PADDING_LENGTH: u32 = 46;
+
+#[rustfmt::skip]
+#[derive(Debug, Display, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Sf2Severity {
+    /// The file is usable, but deviates from the specification.
+    #[display(fmt = "warning")]
+    Warning,
+
+    /// Parts of the file cannot be interpreted reliably.
+    #[display(fmt = "error")]
+    Error,
+}
+
+#[rustfmt::skip]
+#[derive(Debug, Display, Clone, PartialEq, Eq)]
+pub enum Sf2Violation {
+    #[display(fmt = "Unreadable chunk: {message:}")]
+    UnreadableChunk {
+        message: String,
+    },
+
+    #[display(fmt = "Record {record_index:} of '{chunk_id:}' chunk has a non-monotonic '{target_chunk_id:}' index {index:}")]
+    NonMonotonicIndex {
+        chunk_id: &'static str,
+        record_index: usize,
+        target_chunk_id: &'static str,
+        index: usize,
+    },
+
+    #[display(fmt = "Record {record_index:} of '{chunk_id:}' chunk has an out of range '{target_chunk_id:}' index {index:}")]
+    IndexOutOfRange {
+        chunk_id: &'static str,
+        record_index: usize,
+        target_chunk_id: &'static str,
+        index: usize,
+    },
+
+    #[display(fmt = "Zone {zone_index:} of '{name:}' does not end with a '{generator_kind:}' generator")]
+    MissingTerminalGenerator {
+        name: String,
+        zone_index: usize,
+        generator_kind: Sf2GeneratorKind,
+    },
+
+    #[display(fmt = "Record {record_index:} of '{chunk_id:}' chunk has unknown generator {generator_id:}")]
+    UnknownGenerator {
+        chunk_id: &'static str,
+        record_index: usize,
+        generator_id: u16,
+    },
+
+    #[display(fmt = "Sample '{sample_name:}' ({start:}..{end:}) is outside of the sample data")]
+    SampleOutOfRange {
+        sample_name: String,
+        start: u32,
+        end: u32,
+    },
+
+    #[display(fmt = "Loop of sample '{sample_name:}' ({start_loop:}..{end_loop:}) is outside of the sample")]
+    LoopOutOfRange {
+        sample_name: String,
+        start_loop: u32,
+        end_loop: u32,
+    },
+
+    #[display(fmt = "Sample '{sample_name:}' is followed by {padding_length:} padding points instead of 46")]
+    MissingSamplePadding {
+        sample_name: String,
+        padding_length: u32,
+    },
+
+    #[display(fmt = "Sample '{sample_name:}' has unknown sample type {sample_type:#06X}")]
+    UnknownSampleType {
+        sample_name: String,
+        sample_type: u16,
+    },
+
+    #[display(fmt = "Sample '{sample_name:}' has a dangling stereo link to sample {linked_sample_index:}")]
+    DanglingSampleLink {
+        sample_name: String,
+        linked_sample_index: usize,
+    },
+
+    #[display(fmt = "Preset '{preset_name:}' duplicates bank {bank:} preset {preset:}")]
+    DuplicatePreset {
+        preset_name: String,
+        bank: u16,
+        preset: u16,
+    },
+
+    #[display(fmt = "String of '{chunk_id:}' chunk is {length:} bytes long, longer than {max_length:} bytes")]
+    InfoStringTooLong {
+        chunk_id: String,
+        length: usize,
+        max_length: usize,
+    },
+
+    #[display(fmt = "String of '{chunk_id:}' chunk is not even-sized ({length:} bytes)")]
+    InfoStringOddSize {
+        chunk_id: String,
+        length: usize,
+    },
+}
+
+impl Sf2Violation {
+    pub fn severity(&self) -> Sf2Severity {
+        match self {
+            Sf2Violation::UnreadableChunk { .. }
+            | Sf2Violation::NonMonotonicIndex { .. }
+            | Sf2Violation::IndexOutOfRange { .. }
+            | Sf2Violation::SampleOutOfRange { .. }
+            | Sf2Violation::UnknownSampleType { .. }
+            | Sf2Violation::DanglingSampleLink { .. }
+            | Sf2Violation::InfoStringTooLong { .. } => Sf2Severity::Error,
+
+            Sf2Violation::MissingTerminalGenerator { .. }
+            | Sf2Violation::UnknownGenerator { .. }
+            | Sf2Violation::LoopOutOfRange { .. }
+            | Sf2Violation::MissingSamplePadding { .. }
+            | Sf2Violation::DuplicatePreset { .. }
+            | Sf2Violation::InfoStringOddSize { .. } => Sf2Severity::Warning,
+        }
+    }
+}
+
+/// A specification violation found by `Sf2SoundFont::validate`, located at
+/// the file offset of the offending record or chunk when known.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sf2Diagnostic {
+    pub offset: Option<usize>,
+    pub violation: Sf2Violation,
+}
+
+impl Sf2Diagnostic {
+    pub fn severity(&self) -> Sf2Severity {
+        self.violation.severity()
+    }
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+struct Sf2Validator<'a> {
+    soundfont: &'a Sf2SoundFont<'a>,
+    diagnostics: Vec<Sf2Diagnostic>,
+}
+
+impl<'a> Sf2Validator<'a> {
+    fn report(&mut self, offset: Option<usize>, violation: Sf2Violation) {
+        let diagnostic = Sf2Diagnostic { offset, violation };
+
+        // Unreadable chunks are run into by several checks.
+        if !self.diagnostics.contains(&diagnostic) {
+            self.diagnostics.push(diagnostic);
+        }
+    }
+
+    /// Unwraps the result of reading a chunk, reporting the failure.
+    fn check<T>(&mut self, result: Sf2Result<T>) -> Option<T> {
+        result
+            .map_err(|err| {
+                self.report(
+                    None,
+                    Sf2Violation::UnreadableChunk {
+                        message: err.to_string(),
+                    },
+                )
+            })
+            .ok()
+    }
+
+    /// File offset of a record in a `pdta` subchunk.
+    fn record_offset<T>(&self, chunk_id: &str, record_index: usize) -> Option<usize> {
+        let chunk = self
+            .soundfont
+            .root_chunk()
+            .subchunk("pdta")
+            .and_then(|chunk_pdta| chunk_pdta.subchunk(chunk_id))
+            .ok()?;
+
+        Some(chunk.chunk_offset() + 8 + record_index * size_of::<T>())
+    }
+
+    /// Checks a list of indices, including the one of the terminator record,
+    /// pointing into another chunk of `target_record_count` records.
+    fn check_indices<T>(
+        &mut self,
+        chunk_id: &'static str,
+        target_chunk_id: &'static str,
+        indices: &[usize],
+        target_record_count: usize,
+    ) {
+        for (record_index, &index) in indices.iter().enumerate() {
+            let offset = self.record_offset::<T>(chunk_id, record_index);
+
+            if index >= target_record_count {
+                self.report(
+                    offset,
+                    Sf2Violation::IndexOutOfRange {
+                        chunk_id,
+                        record_index,
+                        target_chunk_id,
+                        index,
+                    },
+                );
+            } else if (record_index > 0) && (index < indices[record_index - 1]) {
+                self.report(
+                    offset,
+                    Sf2Violation::NonMonotonicIndex {
+                        chunk_id,
+                        record_index,
+                        target_chunk_id,
+                        index,
+                    },
+                );
+            }
+        }
+    }
+
+    fn check_hydra_indices(&mut self) -> Option<()> {
+        let soundfont = self.soundfont;
+
+        let preset_headers = self.check(soundfont.preset_headers_with_terminator())?;
+        let preset_zones = self.check(soundfont.preset_zones_with_terminator())?;
+        let preset_generator_count = self.check(soundfont.preset_generators())?.len() + 1;
+        let preset_modulator_count = self.check(soundfont.preset_modulators())?.len() + 1;
+
+        let instrument_headers = self.check(soundfont.instrument_headers_with_terminator())?;
+        let instrument_zones = self.check(soundfont.instrument_zones_with_terminator())?;
+        let instrument_generator_count = self.check(soundfont.instrument_generators())?.len() + 1;
+        let instrument_modulator_count = self.check(soundfont.instrument_modulators())?.len() + 1;
+
+        self.check_indices::<Sf2PresetHeader>(
+            "phdr",
+            "pbag",
+            &preset_headers
+                .iter()
+                .map(|preset_header| preset_header.preset_bag_index.get() as usize)
+                .collect::<Vec<_>>(),
+            preset_zones.len(),
+        );
+
+        self.check_indices::<Sf2PresetZone>(
+            "pbag",
+            "pgen",
+            &preset_zones
+                .iter()
+                .map(|preset_zone| preset_zone.generator_index.get() as usize)
+                .collect::<Vec<_>>(),
+            preset_generator_count,
+        );
+
+        self.check_indices::<Sf2PresetZone>(
+            "pbag",
+            "pmod",
+            &preset_zones
+                .iter()
+                .map(|preset_zone| preset_zone.modulator_index.get() as usize)
+                .collect::<Vec<_>>(),
+            preset_modulator_count,
+        );
+
+        self.check_indices::<Sf2InstrumentHeader>(
+            "inst",
+            "ibag",
+            &instrument_headers
+                .iter()
+                .map(|instrument_header| instrument_header.instrument_bag_index.get() as usize)
+                .collect::<Vec<_>>(),
+            instrument_zones.len(),
+        );
+
+        self.check_indices::<Sf2InstrumentZone>(
+            "ibag",
+            "igen",
+            &instrument_zones
+                .iter()
+                .map(|instrument_zone| instrument_zone.generator_index.get() as usize)
+                .collect::<Vec<_>>(),
+            instrument_generator_count,
+        );
+
+        self.check_indices::<Sf2InstrumentZone>(
+            "ibag",
+            "imod",
+            &instrument_zones
+                .iter()
+                .map(|instrument_zone| instrument_zone.modulator_index.get() as usize)
+                .collect::<Vec<_>>(),
+            instrument_modulator_count,
+        );
+
+        Some(())
+    }
+
+    fn check_unknown_generators<G: Sf2Generator>(
+        &mut self,
+        chunk_id: &'static str,
+        generators: &[G],
+    ) {
+        for (record_index, generator) in generators.iter().enumerate() {
+            if generator.generator_kind().is_err() {
+                self.report(
+                    self.record_offset::<G>(chunk_id, record_index),
+                    Sf2Violation::UnknownGenerator {
+                        chunk_id,
+                        record_index,
+                        generator_id: generator.generator_id(),
+                    },
+                );
+            }
+        }
+    }
+
+    /// Every zone but the global zone must end with the terminal generator.
+    fn check_zones<Z, G: Sf2Generator, M>(
+        &mut self,
+        bag_chunk_id: &str,
+        bag_start: usize,
+        name: &str,
+        zones: &[Sf2Zone<'a, G, M>],
+        terminal_generator_kind: Sf2GeneratorKind,
+    ) {
+        for (zone_index, zone) in zones.iter().enumerate() {
+            let is_terminal =
+                |generator: &G| generator.generator_kind().ok() == Some(terminal_generator_kind);
+
+            let has_terminal = zone.generators().iter().any(is_terminal);
+            let ends_with_terminal = zone.generators().last().is_some_and(is_terminal);
+
+            if (has_terminal || (zone_index > 0)) && !ends_with_terminal {
+                self.report(
+                    self.record_offset::<Z>(bag_chunk_id, bag_start + zone_index),
+                    Sf2Violation::MissingTerminalGenerator {
+                        name: name.to_owned(),
+                        zone_index,
+                        generator_kind: terminal_generator_kind,
+                    },
+                );
+            }
+        }
+    }
+
+    fn check_presets(&mut self) -> Option<()> {
+        let soundfont = self.soundfont;
+
+        let preset_generators = self.check(soundfont.preset_generators())?;
+        self.check_unknown_generators("pgen", preset_generators);
+
+        let mut bank_presets = HashMap::new();
+
+        for preset in self.check(soundfont.presets())? {
+            let preset_header = preset.preset_header();
+            let preset_name = preset_header.preset_name().unwrap_or_default();
+
+            if bank_presets
+                .insert(preset_header.bank_preset(), preset.preset_index())
+                .is_some()
+            {
+                self.report(
+                    self.record_offset::<Sf2PresetHeader>("phdr", preset.preset_index()),
+                    Sf2Violation::DuplicatePreset {
+                        preset_name: preset_name.to_owned(),
+                        bank: preset_header.bank(),
+                        preset: preset_header.preset(),
+                    },
+                );
+            }
+
+            // Broken zone lists are already reported by the index checks.
+            if let Ok(zones) = preset.all_zones() {
+                self.check_zones::<Sf2PresetZone, Sf2PresetGenerator, Sf2PresetModulator>(
+                    "pbag",
+                    preset_header.preset_bag_index.get() as usize,
+                    preset_name,
+                    &zones,
+                    Sf2GeneratorKind::Instrument,
+                );
+            }
+        }
+
+        Some(())
+    }
+
+    fn check_instruments(&mut self) -> Option<()> {
+        let soundfont = self.soundfont;
+
+        let instrument_generators = self.check(soundfont.instrument_generators())?;
+        self.check_unknown_generators::<Sf2InstrumentGenerator>("igen", instrument_generators);
+
+        for instrument in self.check(soundfont.instruments())? {
+            let instrument_header = instrument.instrument_header();
+
+            if let Ok(zones) = instrument.all_zones() {
+                self.check_zones::<Sf2InstrumentZone, _, _>(
+                    "ibag",
+                    instrument_header.instrument_bag_index.get() as usize,
+                    instrument_header.instrument_name().unwrap_or_default(),
+                    &zones,
+                    Sf2GeneratorKind::SampleId,
+                );
+            }
+        }
+
+        Some(())
+    }
+
+    fn check_samples(&mut self) -> Option<()> {
+        let soundfont = self.soundfont;
+
+        let sample_headers = self.check(soundfont.sample_headers())?;
+        let sample_data_length = self.check(soundfont.sample_data_16_chunk())?.len() as u32;
+
+        // Sample starts in file order, for finding the padding after each sample.
+        let mut sample_starts = sample_headers
+            .iter()
+            .filter(|sample_header| {
+                sample_header
+                    .sample_type()
+                    .is_ok_and(|sample_type| !sample_type.is_rom())
+            })
+            .map(|sample_header| sample_header.start.get())
+            .collect::<Vec<_>>();
+        sample_starts.sort_unstable();
+
+        for (sample_index, sample_header) in sample_headers.iter().enumerate() {
+            let offset = self.record_offset::<Sf2SampleHeader>("shdr", sample_index);
+            let sample_name = sample_header.sample_name().unwrap_or_default().to_owned();
+
+            let start = sample_header.start.get();
+            let end = sample_header.end.get();
+            let start_loop = sample_header.start_loop.get();
+            let end_loop = sample_header.end_loop.get();
+
+            let sample_type = match sample_header.sample_type() {
+                Ok(sample_type) => sample_type,
+                Err(_) => {
+                    self.report(
+                        offset,
+                        Sf2Violation::UnknownSampleType {
+                            sample_name,
+                            sample_type: sample_header.sample_type.get(),
+                        },
+                    );
+                    continue;
+                }
+            };
+
+            if soundfont.linked_sample_header(sample_index).is_err() {
+                self.report(
+                    offset,
+                    Sf2Violation::DanglingSampleLink {
+                        sample_name: sample_name.clone(),
+                        linked_sample_index: sample_header.sample_link.get() as usize,
+                    },
+                );
+            }
+
+            // ROM samples point into the ROM, not into the sample data.
+            if sample_type.is_rom() {
+                continue;
+            }
+
+            if (start > end) || (end > sample_data_length) {
+                self.report(
+                    offset,
+                    Sf2Violation::SampleOutOfRange {
+                        sample_name,
+                        start,
+                        end,
+                    },
+                );
+                continue;
+            }
+
+            if !((start <= start_loop) && (start_loop < end_loop) && (end_loop <= end)) {
+                self.report(
+                    offset,
+                    Sf2Violation::LoopOutOfRange {
+                        sample_name: sample_name.clone(),
+                        start_loop,
+                        end_loop,
+                    },
+                );
+            }
+
+            // Zero-length samples end where they start, they must not be
+            // taken as the sample following themselves.
+            let padding_end = sample_starts
+                .iter()
+                .copied()
+                .find(|&sample_start| (sample_start >= end) && (sample_start > start))
+                .unwrap_or(sample_data_length);
+            let padding_length = padding_end - end;
+
+            if padding_length < SAMPLE_PADDING_LENGTH {
+                self.report(
+                    offset,
+                    Sf2Violation::MissingSamplePadding {
+                        sample_name,
+                        padding_length,
+                    },
+                );
+            }
+        }
+
+        Some(())
+    }
+
+    fn check_info_strings(&mut self) -> Option<()> {
+        let soundfont = self.soundfont;
+
+        let chunk_info = self.check(soundfont.root_chunk().subchunk("INFO").map_err(Into::into))?;
+        let subchunks = self.check(chunk_info.subchunks().map_err(Into::into))?;
+
+        for subchunk in subchunks {
+            let max_length = match subchunk.chunk_id() {
+                // Version chunks
+                "ifil" | "iver" => continue,
+                "ICMT" => 65536,
+                _ => 256,
+            };
+
+            let offset = Some(subchunk.chunk_offset());
+            let length = subchunk.chunk_size() as usize;
+
+            if length > max_length {
+                self.report(
+                    offset,
+                    Sf2Violation::InfoStringTooLong {
+                        chunk_id: subchunk.chunk_id().to_owned(),
+                        length,
+                        max_length,
+                    },
+                );
+            }
+
+            if length % 2 == 1 {
+                self.report(
+                    offset,
+                    Sf2Violation::InfoStringOddSize {
+                        chunk_id: subchunk.chunk_id().to_owned(),
+                        length,
+                    },
+                );
+            }
+        }
+
+        Some(())
+    }
+}
+
+impl<'a> Sf2SoundFont<'a> {
+    /// Cross-checks the whole file against the specification, reporting every
+    /// violation found instead of stopping at the first one.
+    pub fn validate(&'a self) -> Vec<Sf2Diagnostic> {
+        let mut validator = Sf2Validator {
+            soundfont: self,
+            diagnostics: Vec::new(),
+        };
+
+        validator.check_info_strings();
+        validator.check_hydra_indices();
+        validator.check_presets();
+        validator.check_instruments();
+        validator.check_samples();
+
+        validator.diagnostics
+    }
+}
@@ -0,0 +1,403 @@
+use std::io::{Cursor, Seek, Write};
+
+use zerocopy::{AsBytes, FromZeroes, I16, LE, U16, U32};
+
+use parser_riff::RiffWriter;
+
+use crate::{
+    Sf2Error, Sf2InstrumentGenerator, Sf2InstrumentHeader, Sf2InstrumentModulator,
+    Sf2InstrumentZone, Sf2Model, Sf2ModelGenerator, Sf2ModelModulator, Sf2ModelZone,
+    Sf2PresetGenerator, Sf2PresetHeader, Sf2PresetModulator, Sf2PresetZone, Sf2Result,
+    Sf2SampleHeader, Sf2Version, SAMPLE_PADDING_LENGTH,
+};
+
+fn write_zstr_chunk<W: Write + Seek>(
+    riff_writer: &mut RiffWriter<W>,
+    chunk_id: &str,
+    value: &str,
+) -> Sf2Result<()> {
+    // Comments may be up to 65536 bytes long, other strings up to 256 bytes,
+    // including the terminating zero.
+    let max_length = match chunk_id {
+        "ICMT" => 65536,
+        _ => 256,
+    };
+
+    if value.len() >= max_length {
+        return Err(Sf2Error::StringTooLong {
+            string: value.to_owned(),
+            max_length: max_length - 1,
+        });
+    }
+
+    // Zero-terminated strings are padded to an even length with extra zeroes.
+    let mut chunk_data = value.as_bytes().to_vec();
+    chunk_data.push(0);
+
+    if chunk_data.len() % 2 == 1 {
+        chunk_data.push(0);
+    }
+
+    Ok(riff_writer.write_chunk(chunk_id, &chunk_data)?)
+}
+
+fn write_version_chunk<W: Write + Seek>(
+    riff_writer: &mut RiffWriter<W>,
+    chunk_id: &str,
+    (major, minor): (u16, u16),
+) -> Sf2Result<()> {
+    let version = Sf2Version {
+        major: U16::new(major),
+        minor: U16::new(minor),
+    };
+
+    Ok(riff_writer.write_chunk(chunk_id, version.as_bytes())?)
+}
+
+/// A string in a fixed-size field, zero-padded when shorter than the field.
+/// Strings filling the whole field are left without a terminator, as readers
+/// of the format accept them.
+fn fixedstr<const N: usize>(value: &str) -> Sf2Result<[u8; N]> {
+    if value.len() > N {
+        return Err(Sf2Error::StringTooLong {
+            string: value.to_owned(),
+            max_length: N,
+        });
+    }
+
+    let mut fixedstr = [0; N];
+    fixedstr[..value.len()].copy_from_slice(value.as_bytes());
+
+    Ok(fixedstr)
+}
+
+fn record_index(chunk_id: &str, index: usize) -> Sf2Result<U16<LE>> {
+    u16::try_from(index)
+        .map(U16::new)
+        .map_err(|_| Sf2Error::TooManyRecords {
+            chunk_id: chunk_id.to_owned(),
+        })
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+/// The flattened bag, generator and modulator lists of either the preset or
+/// the instrument level, with bag indices computed along the way.
+#[derive(Default)]
+struct ZoneLists<'a> {
+    bags: Vec<(usize, usize)>,
+    generators: Vec<&'a Sf2ModelGenerator>,
+    modulators: Vec<&'a Sf2ModelModulator>,
+}
+
+impl<'a> ZoneLists<'a> {
+    fn push_zones(&mut self, zones: &'a [Sf2ModelZone]) -> usize {
+        let bag_index = self.bags.len();
+
+        for zone in zones {
+            self.bags
+                .push((self.generators.len(), self.modulators.len()));
+            self.generators.extend(&zone.generators);
+            self.modulators.extend(&zone.modulators);
+        }
+
+        bag_index
+    }
+
+    fn push_terminator(&mut self) {
+        self.bags
+            .push((self.generators.len(), self.modulators.len()));
+    }
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+impl Sf2Model {
+    pub fn to_bytes(&self) -> Sf2Result<Vec<u8>> {
+        let mut cursor = Cursor::new(Vec::new());
+        self.write(&mut cursor)?;
+        Ok(cursor.into_inner())
+    }
+
+    pub fn write<W: Write + Seek>(&self, writer: &mut W) -> Sf2Result<()> {
+        let mut riff_writer = RiffWriter::new(writer);
+
+        riff_writer.begin_container("RIFF", "sfbk")?;
+        self.write_info(&mut riff_writer)?;
+        let sample_headers = self.write_sdta(&mut riff_writer)?;
+        self.write_pdta(&mut riff_writer, &sample_headers)?;
+        riff_writer.end_chunk()?;
+
+        riff_writer.finish()?;
+        Ok(())
+    }
+
+    fn write_info<W: Write + Seek>(&self, riff_writer: &mut RiffWriter<W>) -> Sf2Result<()> {
+        let info = &self.info;
+
+        riff_writer.begin_container("LIST", "INFO")?;
+
+        write_version_chunk(riff_writer, "ifil", info.format_version)?;
+        write_zstr_chunk(riff_writer, "isng", &info.sound_engine)?;
+        write_zstr_chunk(riff_writer, "INAM", &info.soundfont_name)?;
+
+        if let Some(rom_name) = &info.rom_name {
+            write_zstr_chunk(riff_writer, "irom", rom_name)?;
+        }
+
+        if let Some(rom_version) = info.rom_version {
+            write_version_chunk(riff_writer, "iver", rom_version)?;
+        }
+
+        for (chunk_id, value) in [
+            ("ICRD", &info.date),
+            ("IENG", &info.author),
+            ("IPRD", &info.product),
+            ("ICOP", &info.copyright),
+            ("ICMT", &info.comment),
+        ] {
+            if let Some(value) = value {
+                write_zstr_chunk(riff_writer, chunk_id, value)?;
+            }
+        }
+
+        if let Some(soundfont_tools) = &info.soundfont_tools {
+            write_zstr_chunk(riff_writer, "ISFT", &soundfont_tools.join(":"))?;
+        }
+
+        riff_writer.end_chunk()?;
+        Ok(())
+    }
+
+    /// Writes the sample data, returning the sample headers pointing into it.
+    fn write_sdta<W: Write + Seek>(
+        &self,
+        riff_writer: &mut RiffWriter<W>,
+    ) -> Sf2Result<Vec<Sf2SampleHeader>> {
+        let sample_padding = [I16::<LE>::ZERO; SAMPLE_PADDING_LENGTH as usize];
+
+        let mut sample_headers: Vec<Sf2SampleHeader> = Vec::new();
+        let mut sample_position: usize = 0;
+
+        riff_writer.begin_container("LIST", "sdta")?;
+        riff_writer.begin_chunk("smpl")?;
+
+        for sample in &self.samples {
+            if let Some(samples_24_lsb) = &sample.samples_24_lsb {
+                if samples_24_lsb.len() != sample.samples_16.len() {
+                    return Err(Sf2Error::SampleLengthMismatch {
+                        sample_name: sample.sample_name.clone(),
+                        samples_16_length: sample.samples_16.len(),
+                        samples_24_length: samples_24_lsb.len(),
+                    });
+                }
+            }
+
+            let sample_offset = |position: usize| {
+                u32::try_from(position).map_err(|_| Sf2Error::TooManyRecords {
+                    chunk_id: "smpl".to_owned(),
+                })
+            };
+
+            // ROM samples point into the ROM and take no room in `smpl`.
+            let (start, end) = match sample.rom_range {
+                Some(rom_range) => rom_range,
+                None => {
+                    let samples_16 = sample
+                        .samples_16
+                        .iter()
+                        .copied()
+                        .map(I16::<LE>::new)
+                        .collect::<Vec<_>>();
+
+                    riff_writer.write_data(samples_16.as_bytes())?;
+                    riff_writer.write_data(sample_padding.as_bytes())?;
+
+                    let start = sample_offset(sample_position)?;
+                    let end = sample_offset(sample_position + samples_16.len())?;
+                    sample_position += samples_16.len() + SAMPLE_PADDING_LENGTH as usize;
+
+                    (start, end)
+                }
+            };
+
+            sample_headers.push(Sf2SampleHeader {
+                sample_name: fixedstr(&sample.sample_name)?,
+                start: U32::new(start),
+                end: U32::new(end),
+                start_loop: U32::new(start.wrapping_add(sample.start_loop)),
+                end_loop: U32::new(start.wrapping_add(sample.end_loop)),
+                sample_rate: U32::new(sample.sample_rate),
+                original_pitch: sample.original_pitch,
+                pitch_correction: sample.pitch_correction,
+                sample_link: U16::new(sample.sample_link),
+                sample_type: U16::new(sample.sample_type),
+            });
+        }
+
+        riff_writer.end_chunk()?;
+
+        if self
+            .samples
+            .iter()
+            .any(|sample| sample.samples_24_lsb.is_some())
+        {
+            riff_writer.begin_chunk("sm24")?;
+
+            for sample in self
+                .samples
+                .iter()
+                .filter(|sample| sample.rom_range.is_none())
+            {
+                let mut samples_24_lsb = sample.samples_24_lsb.clone().unwrap_or_default();
+                samples_24_lsb.resize(sample.samples_16.len() + SAMPLE_PADDING_LENGTH as usize, 0);
+                riff_writer.write_data(&samples_24_lsb)?;
+            }
+
+            riff_writer.end_chunk()?;
+        }
+
+        riff_writer.end_chunk()?;
+
+        sample_headers.push(Sf2SampleHeader {
+            sample_name: fixedstr("EOS")?,
+            ..Sf2SampleHeader::new_zeroed()
+        });
+
+        Ok(sample_headers)
+    }
+
+    fn write_pdta<W: Write + Seek>(
+        &self,
+        riff_writer: &mut RiffWriter<W>,
+        sample_headers: &[Sf2SampleHeader],
+    ) -> Sf2Result<()> {
+        // Preset level
+        let mut preset_lists = ZoneLists::default();
+        let mut preset_headers: Vec<Sf2PresetHeader> = Vec::new();
+
+        for preset in &self.presets {
+            let bag_index = preset_lists.push_zones(&preset.zones);
+
+            preset_headers.push(Sf2PresetHeader {
+                preset_name: fixedstr(&preset.preset_name)?,
+                preset: U16::new(preset.preset),
+                bank: U16::new(preset.bank),
+                preset_bag_index: record_index("pbag", bag_index)?,
+                library: U32::new(preset.library),
+                genre: U32::new(preset.genre),
+                morphology: U32::new(preset.morphology),
+            });
+        }
+
+        preset_headers.push(Sf2PresetHeader {
+            preset_name: fixedstr("EOP")?,
+            preset_bag_index: record_index("pbag", preset_lists.bags.len())?,
+            ..Sf2PresetHeader::new_zeroed()
+        });
+
+        preset_lists.push_terminator();
+
+        let preset_zones = preset_lists
+            .bags
+            .iter()
+            .map(|&(generator_index, modulator_index)| {
+                Ok(Sf2PresetZone {
+                    generator_index: record_index("pgen", generator_index)?,
+                    modulator_index: record_index("pmod", modulator_index)?,
+                })
+            })
+            .collect::<Sf2Result<Vec<_>>>()?;
+
+        let mut preset_modulators = preset_lists
+            .modulators
+            .iter()
+            .map(|modulator| Sf2PresetModulator {
+                source_operator: U16::new(modulator.source),
+                destination_operator: U16::new(modulator.destination),
+                modulator_amount: I16::new(modulator.amount),
+                amount_source_operator: U16::new(modulator.amount_source),
+                transform_operator: U16::new(modulator.transform),
+            })
+            .collect::<Vec<_>>();
+        preset_modulators.push(Sf2PresetModulator::new_zeroed());
+
+        let mut preset_generators = preset_lists
+            .generators
+            .iter()
+            .map(|generator| Sf2PresetGenerator {
+                generator_operator: U16::new(generator.generator_id),
+                generator_amount: U16::new(generator.amount),
+            })
+            .collect::<Vec<_>>();
+        preset_generators.push(Sf2PresetGenerator::new_zeroed());
+
+        // Instrument level
+        let mut instrument_lists = ZoneLists::default();
+        let mut instrument_headers: Vec<Sf2InstrumentHeader> = Vec::new();
+
+        for instrument in &self.instruments {
+            let bag_index = instrument_lists.push_zones(&instrument.zones);
+
+            instrument_headers.push(Sf2InstrumentHeader {
+                instrument_name: fixedstr(&instrument.instrument_name)?,
+                instrument_bag_index: record_index("ibag", bag_index)?,
+            });
+        }
+
+        instrument_headers.push(Sf2InstrumentHeader {
+            instrument_name: fixedstr("EOI")?,
+            instrument_bag_index: record_index("ibag", instrument_lists.bags.len())?,
+        });
+
+        instrument_lists.push_terminator();
+
+        let instrument_zones = instrument_lists
+            .bags
+            .iter()
+            .map(|&(generator_index, modulator_index)| {
+                Ok(Sf2InstrumentZone {
+                    generator_index: record_index("igen", generator_index)?,
+                    modulator_index: record_index("imod", modulator_index)?,
+                })
+            })
+            .collect::<Sf2Result<Vec<_>>>()?;
+
+        let mut instrument_modulators = instrument_lists
+            .modulators
+            .iter()
+            .map(|modulator| Sf2InstrumentModulator {
+                source_operator: U16::new(modulator.source),
+                destination_operator: U16::new(modulator.destination),
+                modulator_amount: I16::new(modulator.amount),
+                amount_source_operator: U16::new(modulator.amount_source),
+                transform_operator: U16::new(modulator.transform),
+            })
+            .collect::<Vec<_>>();
+        instrument_modulators.push(Sf2InstrumentModulator::new_zeroed());
+
+        let mut instrument_generators = instrument_lists
+            .generators
+            .iter()
+            .map(|generator| Sf2InstrumentGenerator {
+                generator_operator: U16::new(generator.generator_id),
+                generator_amount: U16::new(generator.amount),
+            })
+            .collect::<Vec<_>>();
+        instrument_generators.push(Sf2InstrumentGenerator::new_zeroed());
+
+        riff_writer.begin_container("LIST", "pdta")?;
+        riff_writer.write_chunk("phdr", preset_headers.as_bytes())?;
+        riff_writer.write_chunk("pbag", preset_zones.as_bytes())?;
+        riff_writer.write_chunk("pmod", preset_modulators.as_bytes())?;
+        riff_writer.write_chunk("pgen", preset_generators.as_bytes())?;
+        riff_writer.write_chunk("inst", instrument_headers.as_bytes())?;
+        riff_writer.write_chunk("ibag", instrument_zones.as_bytes())?;
+        riff_writer.write_chunk("imod", instrument_modulators.as_bytes())?;
+        riff_writer.write_chunk("igen", instrument_generators.as_bytes())?;
+        riff_writer.write_chunk("shdr", sample_headers.as_bytes())?;
+        riff_writer.end_chunk()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{
+    Sf2Error, Sf2Generator, Sf2GeneratorAmount, Sf2GeneratorKind, Sf2Instrument,
+    Sf2InstrumentGenerator, Sf2InstrumentModulator, Sf2PresetGenerator, Sf2PresetModulator,
+    Sf2Result, Sf2SampleHeader, Sf2SoundFont,
+};
+
+pub struct Sf2Zone<'a, G, M> {
+    soundfont: &'a Sf2SoundFont<'a>,
+    generators: &'a [G],
+    modulators: &'a [M],
+}
+
+impl<'a, G: Sf2Generator, M> Sf2Zone<'a, G, M> {
+    /// Splits the generator and modulator lists along a range of bag records,
+    /// where `bag_indices` also includes the bag following the last zone.
+    pub(crate) fn from_bags(
+        soundfont: &'a Sf2SoundFont<'a>,
+        bag_indices: &[(usize, usize)],
+        generators: (&'static str, &'a [G]),
+        modulators: (&'static str, &'a [M]),
+    ) -> Sf2Result<Vec<Sf2Zone<'a, G, M>>> {
+        let (generators_chunk_id, generators) = generators;
+        let (modulators_chunk_id, modulators) = modulators;
+
+        bag_indices
+            .windows(2)
+            .map(|bag_pair| {
+                let (generator_start, modulator_start) = bag_pair[0];
+                let (generator_end, modulator_end) = bag_pair[1];
+
+                Ok(Sf2Zone {
+                    soundfont,
+                    generators: generators.get(generator_start..generator_end).ok_or(
+                        Sf2Error::IndexOutOfRange {
+                            chunk_id: generators_chunk_id.to_owned(),
+                            index: generator_end,
+                        },
+                    )?,
+                    modulators: modulators.get(modulator_start..modulator_end).ok_or(
+                        Sf2Error::IndexOutOfRange {
+                            chunk_id: modulators_chunk_id.to_owned(),
+                            index: modulator_end,
+                        },
+                    )?,
+                })
+            })
+            .collect()
+    }
+
+    /// Picks the global zone, if any.
+    ///
+    /// Only the first zone may be a global zone, which is recognized by its
+    /// last generator not being the terminal generator (`instrument` for preset
+    /// zones, `sampleID` for instrument zones).
+    pub(crate) fn global_zone(
+        zones: Vec<Sf2Zone<'a, G, M>>,
+        terminal_generator_kind: Sf2GeneratorKind,
+    ) -> Option<Sf2Zone<'a, G, M>> {
+        zones
+            .into_iter()
+            .next()
+            .filter(|zone| !zone.ends_with(terminal_generator_kind))
+    }
+
+    /// Picks the zones ending with the terminal generator. Any non-first zone
+    /// lacking the terminal generator is ignored, as the specification requires.
+    pub(crate) fn local_zones(
+        zones: Vec<Sf2Zone<'a, G, M>>,
+        terminal_generator_kind: Sf2GeneratorKind,
+    ) -> Vec<Sf2Zone<'a, G, M>> {
+        zones
+            .into_iter()
+            .filter(|zone| zone.ends_with(terminal_generator_kind))
+            .collect()
+    }
+
+    fn ends_with(&self, generator_kind: Sf2GeneratorKind) -> bool {
+        self.generators
+            .last()
+            .and_then(|generator| generator.generator_kind().ok())
+            == Some(generator_kind)
+    }
+
+    pub fn generators(&self) -> &'a [G] {
+        self.generators
+    }
+
+    pub fn modulators(&self) -> &'a [M] {
+        self.modulators
+    }
+
+    pub fn generator(&self, generator_kind: Sf2GeneratorKind) -> Option<&'a G> {
+        self.generators
+            .iter()
+            .rev()
+            .find(|generator| generator.generator_kind().ok() == Some(generator_kind))
+    }
+
+    pub fn generator_amount(&self, generator_kind: Sf2GeneratorKind) -> Option<Sf2GeneratorAmount> {
+        self.generator(generator_kind)
+            .map(|generator| generator_kind.decode_amount(generator.raw_amount()))
+    }
+
+    pub fn key_range(&self) -> Option<(u8, u8)> {
+        self.generator_amount(Sf2GeneratorKind::KeyRange)
+            .and_then(|amount| amount.as_range())
+    }
+
+    pub fn velocity_range(&self) -> Option<(u8, u8)> {
+        self.generator_amount(Sf2GeneratorKind::VelRange)
+            .and_then(|amount| amount.as_range())
+    }
+
+    pub fn matches(&self, key: u8, velocity: u8) -> bool {
+        let (key_low, key_high) = self.key_range().unwrap_or((0, 127));
+        let (velocity_low, velocity_high) = self.velocity_range().unwrap_or((0, 127));
+
+        (key_low..=key_high).contains(&key) && (velocity_low..=velocity_high).contains(&velocity)
+    }
+}
+
+impl<'a> Sf2Zone<'a, Sf2PresetGenerator, Sf2PresetModulator> {
+    pub fn instrument_index(&self) -> Option<u16> {
+        self.generator_amount(Sf2GeneratorKind::Instrument)
+            .and_then(|amount| amount.as_unsigned())
+    }
+
+    pub fn instrument(&self) -> Sf2Result<Option<Sf2Instrument<'a>>> {
+        self.instrument_index()
+            .map(|instrument_index| self.soundfont.instrument(instrument_index as usize))
+            .transpose()
+    }
+}
+
+impl<'a> Sf2Zone<'a, Sf2InstrumentGenerator, Sf2InstrumentModulator> {
+    pub fn sample_index(&self) -> Option<u16> {
+        self.generator_amount(Sf2GeneratorKind::SampleId)
+            .and_then(|amount| amount.as_unsigned())
+    }
+
+    pub fn sample_header(&self) -> Sf2Result<Option<&'a Sf2SampleHeader>> {
+        self.sample_index()
+            .map(|sample_index| self.soundfont.sample_header(sample_index as usize))
+            .transpose()
+    }
+}
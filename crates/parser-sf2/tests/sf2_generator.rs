@@ -0,0 +1,102 @@
+use parser_sf2::{
+    Sf2Error, Sf2Generator, Sf2GeneratorAmount, Sf2GeneratorKind, Sf2Model, Sf2ModelGenerator,
+    Sf2ModelPreset, Sf2ModelZone, Sf2SoundFont,
+};
+
+#[test]
+fn generator_kinds_from_ids() {
+    assert_eq!(
+        Sf2GeneratorKind::from_id(0).unwrap(),
+        Sf2GeneratorKind::StartAddrsOffset
+    );
+    assert_eq!(
+        Sf2GeneratorKind::from_id(43).unwrap(),
+        Sf2GeneratorKind::KeyRange
+    );
+    assert_eq!(
+        Sf2GeneratorKind::from_id(60).unwrap(),
+        Sf2GeneratorKind::EndOper
+    );
+    assert_eq!(Sf2GeneratorKind::InitialAttenuation.id(), 48);
+    assert_eq!(Sf2GeneratorKind::KeyRange.to_string(), "keyRange");
+
+    assert!(matches!(
+        Sf2GeneratorKind::from_id(61),
+        Err(Sf2Error::UnknownGenerator { generator_id: 61 })
+    ));
+}
+
+#[test]
+fn amounts_follow_generator_kinds() {
+    // Ranges are stored with their low byte first.
+    assert_eq!(
+        Sf2GeneratorKind::KeyRange.decode_amount(0x7F24),
+        Sf2GeneratorAmount::Range {
+            low: 0x24,
+            high: 0x7F
+        }
+    );
+    assert_eq!(
+        Sf2GeneratorKind::SampleId.decode_amount(0xFFFF),
+        Sf2GeneratorAmount::Unsigned(65535)
+    );
+    assert_eq!(
+        Sf2GeneratorKind::FineTune.decode_amount(0xFFFF),
+        Sf2GeneratorAmount::Signed(-1)
+    );
+
+    assert_eq!(
+        Sf2GeneratorKind::InitialFilterFc.default_amount(),
+        Sf2GeneratorAmount::Signed(13500)
+    );
+    assert_eq!(
+        Sf2GeneratorKind::AttackVolEnv.default_amount(),
+        Sf2GeneratorAmount::Signed(-12000)
+    );
+    assert_eq!(
+        Sf2GeneratorKind::VelRange.default_amount(),
+        Sf2GeneratorAmount::Range { low: 0, high: 127 }
+    );
+}
+
+#[test]
+fn unknown_generators_are_reported_not_decoded() {
+    let model = Sf2Model {
+        presets: vec![Sf2ModelPreset {
+            preset_name: "Unknown".to_owned(),
+            zones: vec![Sf2ModelZone {
+                generators: vec![
+                    Sf2ModelGenerator {
+                        generator_id: 99,
+                        amount: 0x1234,
+                    },
+                    Sf2ModelGenerator::new(Sf2GeneratorKind::Pan, Sf2GeneratorAmount::Signed(-500)),
+                ],
+                modulators: vec![],
+            }],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let buffer = model.to_bytes().unwrap();
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    let preset_generators = soundfont.preset_generators().unwrap();
+
+    assert_eq!(preset_generators[0].generator_id(), 99);
+    assert_eq!(preset_generators[0].raw_amount(), 0x1234);
+    assert!(matches!(
+        preset_generators[0].generator_amount(),
+        Err(Sf2Error::UnknownGenerator { generator_id: 99 })
+    ));
+
+    // Records following an unknown one still decode.
+    assert_eq!(
+        preset_generators[1].generator_kind().unwrap(),
+        Sf2GeneratorKind::Pan
+    );
+    assert_eq!(
+        preset_generators[1].generator_amount().unwrap(),
+        Sf2GeneratorAmount::Signed(-500)
+    );
+}
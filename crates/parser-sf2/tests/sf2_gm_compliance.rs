@@ -0,0 +1,105 @@
+use parser_sf2::{Sf2GmCompliance, Sf2GmPresetSlot, Sf2Model, Sf2ModelPreset, Sf2SoundFont};
+
+fn preset(bank: u16, preset: u16, preset_name: &str) -> Sf2ModelPreset {
+    Sf2ModelPreset {
+        preset_name: preset_name.to_owned(),
+        preset,
+        bank,
+        ..Default::default()
+    }
+}
+
+fn gm_compliance(presets: Vec<Sf2ModelPreset>) -> Sf2GmCompliance {
+    let model = Sf2Model {
+        presets,
+        ..Default::default()
+    };
+
+    let buffer = model.to_bytes().unwrap();
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+
+    Sf2GmCompliance::new(&soundfont).unwrap()
+}
+
+#[test]
+fn complete_bank() {
+    let mut presets = (0..128)
+        .map(|program| preset(0, program, "Melodic"))
+        .collect::<Vec<_>>();
+    presets.push(preset(128, 0, "Standard"));
+
+    let gm_compliance = gm_compliance(presets);
+
+    assert!(gm_compliance.is_compliant());
+    assert_eq!(gm_compliance.covered.len(), 129);
+    assert!(gm_compliance.missing.is_empty());
+    assert!(gm_compliance.non_standard.is_empty());
+}
+
+#[test]
+fn incomplete_bank() {
+    let mut presets = (0..128)
+        .filter(|&program| program != 5)
+        .map(|program| preset(0, program, "Melodic"))
+        .collect::<Vec<_>>();
+    presets.push(preset(8, 0, "Piano 1w"));
+    presets.push(preset(127, 0, "Standard Kit"));
+    presets.push(preset(128, 16, "Power"));
+
+    let gm_compliance = gm_compliance(presets);
+
+    assert!(!gm_compliance.is_compliant());
+    assert_eq!(gm_compliance.missing, [(0, 5), (128, 0)]);
+    assert_eq!(
+        gm_compliance.non_standard,
+        [
+            Sf2GmPresetSlot {
+                bank: 8,
+                preset: 0,
+                preset_name: "Piano 1w".to_owned(),
+            },
+            Sf2GmPresetSlot {
+                bank: 127,
+                preset: 0,
+                preset_name: "Standard Kit".to_owned(),
+            },
+            Sf2GmPresetSlot {
+                bank: 128,
+                preset: 16,
+                preset_name: "Power".to_owned(),
+            },
+        ]
+    );
+    assert_eq!(
+        gm_compliance.misplaced_drum_kits,
+        [Sf2GmPresetSlot {
+            bank: 127,
+            preset: 0,
+            preset_name: "Standard Kit".to_owned(),
+        }]
+    );
+}
+
+#[test]
+fn drum_kit_names_do_not_affect_compliance() {
+    let mut presets = (0..128)
+        .map(|program| preset(0, program, "Melodic"))
+        .collect::<Vec<_>>();
+    presets.push(preset(128, 0, "Standard"));
+    presets.push(preset(1, 0, "Toolkit"));
+    presets.push(preset(1, 1, "Drum Kit"));
+    presets.push(preset(1, 2, "Brush Drum Set"));
+    presets.push(preset(128, 8, "Room Kit"));
+
+    let gm_compliance = gm_compliance(presets);
+
+    assert!(gm_compliance.is_compliant());
+    assert!(gm_compliance.misplaced_drum_kits.is_empty());
+
+    let drum_kit_names = gm_compliance
+        .drum_kit_names
+        .iter()
+        .map(|preset_slot| preset_slot.preset_name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(drum_kit_names, ["Drum Kit", "Brush Drum Set"]);
+}
@@ -0,0 +1,119 @@
+use parser_sf2::{
+    Sf2ControllerPalette, Sf2Error, Sf2GeneralController, Sf2GeneratorKind, Sf2Model,
+    Sf2ModelModulator, Sf2ModelPreset, Sf2ModelZone, Sf2Modulator, Sf2ModulatorDestination,
+    Sf2ModulatorSource, Sf2ModulatorTransform, Sf2SoundFont, Sf2SourceCurveType,
+    Sf2SourceDirection, Sf2SourcePolarity,
+};
+
+#[test]
+fn sources_decode_and_encode() {
+    // Note-on velocity, negative unipolar concave: the default velocity to
+    // attenuation modulator.
+    let source = Sf2ModulatorSource::from_raw(0x0502).unwrap();
+    assert_eq!(source.controller_palette, Sf2ControllerPalette::General);
+    assert_eq!(
+        source.general_controller(),
+        Some(Sf2GeneralController::NoteOnVelocity)
+    );
+    assert_eq!(source.direction, Sf2SourceDirection::Negative);
+    assert_eq!(source.polarity, Sf2SourcePolarity::Unipolar);
+    assert_eq!(source.curve_type, Sf2SourceCurveType::Concave);
+    assert_eq!(source.to_raw(), 0x0502);
+
+    // MIDI controller 10, positive bipolar linear
+    let source = Sf2ModulatorSource::from_raw(0x028A).unwrap();
+    assert_eq!(source.controller_palette, Sf2ControllerPalette::Midi);
+    assert_eq!(source.index, 10);
+    assert_eq!(source.general_controller(), None);
+    assert_eq!(source.polarity, Sf2SourcePolarity::Bipolar);
+    assert_eq!(source.to_raw(), 0x028A);
+
+    assert!(Sf2ModulatorSource::from_raw(0).unwrap().is_none());
+    assert!(matches!(
+        Sf2ModulatorSource::from_raw(0x1002),
+        Err(Sf2Error::UnknownModulatorCurveType { curve_type: 4 })
+    ));
+}
+
+#[test]
+fn destinations_and_transforms() {
+    assert_eq!(
+        Sf2ModulatorDestination::from_raw(48).unwrap(),
+        Sf2ModulatorDestination::Generator(Sf2GeneratorKind::InitialAttenuation)
+    );
+    assert_eq!(
+        Sf2ModulatorDestination::from_raw(0x8003).unwrap(),
+        Sf2ModulatorDestination::Link(3)
+    );
+    assert_eq!(Sf2ModulatorDestination::Link(3).to_raw(), 0x8003);
+    assert!(matches!(
+        Sf2ModulatorDestination::from_raw(99),
+        Err(Sf2Error::UnknownGenerator { generator_id: 99 })
+    ));
+
+    assert_eq!(
+        Sf2ModulatorTransform::from_raw(2).unwrap(),
+        Sf2ModulatorTransform::AbsoluteValue
+    );
+    assert!(matches!(
+        Sf2ModulatorTransform::from_raw(1),
+        Err(Sf2Error::UnknownModulatorTransform { raw_transform: 1 })
+    ));
+}
+
+#[test]
+fn modulator_records() {
+    let model = Sf2Model {
+        presets: vec![Sf2ModelPreset {
+            preset_name: "Modulated".to_owned(),
+            zones: vec![Sf2ModelZone {
+                generators: vec![],
+                modulators: vec![
+                    Sf2ModelModulator {
+                        source: 0x0081,
+                        destination: Sf2GeneratorKind::VibLfoToPitch.id(),
+                        amount: 50,
+                        amount_source: 0,
+                        transform: 0,
+                    },
+                    Sf2ModelModulator {
+                        source: 0x0081,
+                        destination: Sf2GeneratorKind::Pan.id(),
+                        amount: -200,
+                        amount_source: 0x0002,
+                        transform: 5,
+                    },
+                ],
+            }],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let buffer = model.to_bytes().unwrap();
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    let preset_modulators = soundfont.preset_modulators().unwrap();
+
+    let modulator = &preset_modulators[0];
+    assert_eq!(modulator.source().unwrap().index, 1);
+    assert_eq!(
+        modulator.destination().unwrap(),
+        Sf2ModulatorDestination::Generator(Sf2GeneratorKind::VibLfoToPitch)
+    );
+    assert_eq!(modulator.amount(), 50);
+    assert!(modulator.amount_source().unwrap().is_none());
+    assert_eq!(
+        modulator.transform().unwrap(),
+        Sf2ModulatorTransform::Linear
+    );
+
+    // An unknown field leaves the raw values and the other fields readable.
+    let modulator = &preset_modulators[1];
+    assert_eq!(modulator.amount(), -200);
+    assert_eq!(modulator.raw_transform(), 5);
+    assert!(modulator.transform().is_err());
+    assert_eq!(
+        modulator.amount_source().unwrap().general_controller(),
+        Some(Sf2GeneralController::NoteOnVelocity)
+    );
+}
@@ -0,0 +1,110 @@
+use std::io::Cursor;
+
+use parser_sf2::{
+    Sf2GeneratorAmount, Sf2GeneratorKind, Sf2Model, Sf2ModelGenerator, Sf2ModelInstrument,
+    Sf2ModelPreset, Sf2ModelSample, Sf2ModelZone, Sf2SampleType, Sf2SoundFont, Sf2SoundFontReader,
+};
+
+fn test_model() -> Sf2Model {
+    Sf2Model {
+        presets: vec![Sf2ModelPreset {
+            preset_name: "Sine".to_owned(),
+            zones: vec![Sf2ModelZone {
+                generators: vec![Sf2ModelGenerator::new(
+                    Sf2GeneratorKind::Instrument,
+                    Sf2GeneratorAmount::Unsigned(0),
+                )],
+                modulators: vec![],
+            }],
+            ..Default::default()
+        }],
+        instruments: vec![Sf2ModelInstrument {
+            instrument_name: "Sine".to_owned(),
+            zones: vec![Sf2ModelZone {
+                generators: vec![Sf2ModelGenerator::new(
+                    Sf2GeneratorKind::SampleId,
+                    Sf2GeneratorAmount::Unsigned(1),
+                )],
+                modulators: vec![],
+            }],
+        }],
+        samples: vec![
+            Sf2ModelSample {
+                sample_name: "Silence".to_owned(),
+                samples_16: vec![0; 10],
+                sample_rate: 44100,
+                sample_type: Sf2SampleType::Mono as u16,
+                ..Default::default()
+            },
+            Sf2ModelSample {
+                sample_name: "Sine".to_owned(),
+                samples_16: (0..64)
+                    .map(|i| ((i as f64 / 64.0 * std::f64::consts::TAU).sin() * 32767.0) as i16)
+                    .collect(),
+                samples_24_lsb: Some((0..64).collect()),
+                start_loop: 0,
+                end_loop: 64,
+                sample_rate: 44100,
+                original_pitch: 60,
+                sample_type: Sf2SampleType::Mono as u16,
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn reader_matches_buffer_parser() {
+    let buffer = test_model().to_bytes().unwrap();
+
+    let buffer_soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    let reader = Sf2SoundFontReader::new(Cursor::new(buffer.clone())).unwrap();
+    let reader_soundfont = reader.soundfont().unwrap();
+
+    assert_eq!(
+        reader_soundfont.info().unwrap().soundfont_name().unwrap(),
+        buffer_soundfont.info().unwrap().soundfont_name().unwrap(),
+    );
+
+    let preset = reader_soundfont.find_preset(0, 0).unwrap().unwrap();
+    let instrument = preset.zones().unwrap()[0].instrument().unwrap().unwrap();
+    let sample_header = instrument.zones().unwrap()[0]
+        .sample_header()
+        .unwrap()
+        .unwrap();
+    assert_eq!(sample_header.sample_name().unwrap(), "Sine");
+
+    let buffer_sample_data = buffer_soundfont.sample_data(sample_header).unwrap();
+
+    assert_eq!(
+        reader.read_sample_data_16(sample_header).unwrap(),
+        buffer_sample_data.frames_16().collect::<Vec<_>>(),
+    );
+    assert_eq!(
+        reader.read_sample_data_24(sample_header).unwrap().unwrap(),
+        buffer_sample_data.frames_24().unwrap().collect::<Vec<_>>(),
+    );
+
+    assert!(reader_soundfont.sample_data(sample_header).is_err());
+}
+
+#[test]
+fn trailing_data_is_ignored() {
+    let mut buffer = test_model().to_bytes().unwrap();
+    buffer.push(0);
+
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    assert_eq!(
+        soundfont
+            .preset(0)
+            .unwrap()
+            .preset_header()
+            .preset_name()
+            .unwrap(),
+        "Sine"
+    );
+
+    buffer.extend(b"junk");
+    assert!(Sf2SoundFont::new(&buffer).is_ok());
+}
@@ -0,0 +1,185 @@
+use std::io::Cursor;
+
+use parser_riff::RiffWriter;
+use parser_sf2::{Sf2Error, Sf2Model, Sf2ModelSample, Sf2SampleType, Sf2SoundFont};
+
+fn test_model() -> Sf2Model {
+    Sf2Model {
+        samples: vec![
+            Sf2ModelSample {
+                sample_name: "Extremes".to_owned(),
+                samples_16: vec![-2, -1, 0, 1, i16::MAX, i16::MIN],
+                samples_24_lsb: Some(vec![0x00, 0xFF, 0x80, 0x01, 0xFF, 0x00]),
+                sample_rate: 44100,
+                original_pitch: 60,
+                sample_type: Sf2SampleType::Mono as u16,
+                ..Default::default()
+            },
+            Sf2ModelSample {
+                sample_name: "Ramp".to_owned(),
+                samples_16: (0..10).collect(),
+                samples_24_lsb: Some(vec![0x40; 10]),
+                sample_rate: 44100,
+                original_pitch: 60,
+                sample_type: Sf2SampleType::Mono as u16,
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    }
+}
+
+/// An `sdta` chunk with the given contents of its `smpl` and `sm24` chunks.
+fn sdta_chunk(samples_16: &[i16], samples_24: &[u8]) -> Vec<u8> {
+    let mut writer = RiffWriter::new(Cursor::new(Vec::new()));
+
+    writer.begin_container("LIST", "sdta").unwrap();
+    writer
+        .write_chunk(
+            "smpl",
+            &samples_16
+                .iter()
+                .flat_map(|sample| sample.to_le_bytes())
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+    writer.write_chunk("sm24", samples_24).unwrap();
+    writer.end_chunk().unwrap();
+
+    writer.finish().unwrap().into_inner()
+}
+
+fn find_chunk_data(buffer: &[u8], chunk_id: &[u8; 4]) -> usize {
+    buffer
+        .windows(4)
+        .position(|window| window == chunk_id)
+        .unwrap()
+        + 8
+}
+
+#[test]
+fn frames_24_combine_lsb() {
+    let buffer = test_model().to_bytes().unwrap();
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+
+    let sample_data = soundfont
+        .sample_data(soundfont.sample_header(0).unwrap())
+        .unwrap();
+    assert!(sample_data.has_24bit());
+    assert_eq!(sample_data.len(), 6);
+    assert_eq!(
+        sample_data.samples_24_lsb(),
+        Some(&[0x00, 0xFF, 0x80, 0x01, 0xFF, 0x00][..])
+    );
+
+    assert_eq!(
+        sample_data.frames_16().collect::<Vec<_>>(),
+        [-2, -1, 0, 1, i16::MAX, i16::MIN]
+    );
+    assert_eq!(
+        sample_data.frames_24().unwrap().collect::<Vec<_>>(),
+        [-512, -1, 128, 257, 8388607, -8388608]
+    );
+
+    // The second sample starts after the padding of the first one.
+    let sample_data = soundfont
+        .sample_data(soundfont.sample_header(1).unwrap())
+        .unwrap();
+    assert_eq!(
+        sample_data.frames_24().unwrap().collect::<Vec<_>>(),
+        (0..10).map(|index| (index << 8) | 0x40).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn sm24_size_mismatch() {
+    let samples_16 = [1, -1, 2];
+
+    // An odd-sized `sm24` chunk followed by its pad byte
+    let buffer = sdta_chunk(&samples_16, &[1, 2, 3]);
+    let soundfont = Sf2SoundFont::from_chunks(&[(0, &buffer)]).unwrap();
+    assert_eq!(
+        soundfont.sample_data_24_chunk().unwrap(),
+        Some(&[1, 2, 3][..])
+    );
+
+    // The pad byte counted in the chunk size is tolerated.
+    let buffer = sdta_chunk(&samples_16, &[1, 2, 3, 0]);
+    let soundfont = Sf2SoundFont::from_chunks(&[(0, &buffer)]).unwrap();
+    assert_eq!(
+        soundfont.sample_data_24_chunk().unwrap(),
+        Some(&[1, 2, 3][..])
+    );
+
+    // Any other size makes the `sm24` chunk ignored.
+    for samples_24 in [&[1, 2][..], &[1, 2, 3, 4, 5][..], &[][..]] {
+        let buffer = sdta_chunk(&samples_16, samples_24);
+        let soundfont = Sf2SoundFont::from_chunks(&[(0, &buffer)]).unwrap();
+        assert_eq!(soundfont.sample_data_24_chunk().unwrap(), None);
+    }
+
+    // With an even sample count there is no pad byte to allow for.
+    let buffer = sdta_chunk(&[1, -1], &[1, 2, 0]);
+    let soundfont = Sf2SoundFont::from_chunks(&[(0, &buffer)]).unwrap();
+    assert_eq!(soundfont.sample_data_24_chunk().unwrap(), None);
+}
+
+#[test]
+fn ignored_sm24_leaves_16bit_data() {
+    let buffer = test_model().to_bytes().unwrap();
+
+    // The `pdta` chunk with its header, as written by the model
+    let pdta_offset = find_chunk_data(&buffer, b"pdta") - 16;
+    let pdta_size =
+        u32::from_le_bytes(buffer[pdta_offset + 4..pdta_offset + 8].try_into().unwrap());
+    let pdta_chunk = &buffer[pdta_offset..pdta_offset + 8 + pdta_size as usize];
+
+    // Both samples with their padding, and an `sm24` chunk too short for them
+    let samples_16 = (0..108).collect::<Vec<_>>();
+    let sdta_chunk = sdta_chunk(&samples_16, &[0x40; 100]);
+
+    let soundfont =
+        Sf2SoundFont::from_chunks(&[(0, &sdta_chunk), (pdta_offset, pdta_chunk)]).unwrap();
+
+    let sample_data = soundfont
+        .sample_data(soundfont.sample_header(1).unwrap())
+        .unwrap();
+    assert!(!sample_data.has_24bit());
+    assert!(sample_data.frames_24().is_none());
+    assert_eq!(
+        sample_data.frames_16().collect::<Vec<_>>(),
+        (52..62).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn sample_headers_out_of_range() {
+    let mut buffer = test_model().to_bytes().unwrap();
+    let shdr_offset = find_chunk_data(&buffer, b"shdr");
+
+    // Second sample ending past the end of the `smpl` chunk
+    let end_offset = shdr_offset + 46 + 24;
+    buffer[end_offset..end_offset + 4].copy_from_slice(&10_000u32.to_le_bytes());
+
+    // First sample starting after its end
+    let start_offset = shdr_offset + 20;
+    buffer[start_offset..start_offset + 4].copy_from_slice(&7u32.to_le_bytes());
+
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+
+    for (sample_index, expected_start, expected_end) in [(0, 7, 6), (1, 52, 10_000)] {
+        let error = soundfont
+            .sample_data(soundfont.sample_header(sample_index).unwrap())
+            .err()
+            .unwrap();
+
+        assert!(
+            matches!(
+                error,
+                Sf2Error::SampleDataOutOfBounds { start, end }
+                    if (start == expected_start) && (end == expected_end)
+            ),
+            "{error:?}"
+        );
+    }
+}
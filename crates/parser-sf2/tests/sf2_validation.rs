@@ -0,0 +1,241 @@
+use parser_sf2::{
+    Sf2GeneratorAmount, Sf2GeneratorKind, Sf2Model, Sf2ModelGenerator, Sf2ModelInfo,
+    Sf2ModelInstrument, Sf2ModelPreset, Sf2ModelSample, Sf2ModelZone, Sf2SampleType, Sf2Severity,
+    Sf2SoundFont, Sf2Violation,
+};
+
+fn generator(generator_kind: Sf2GeneratorKind, amount: u16) -> Sf2ModelGenerator {
+    Sf2ModelGenerator::new(generator_kind, Sf2GeneratorAmount::Unsigned(amount))
+}
+
+fn zone(generators: Vec<Sf2ModelGenerator>) -> Sf2ModelZone {
+    Sf2ModelZone {
+        generators,
+        modulators: vec![],
+    }
+}
+
+fn sample(sample_name: &str, sample_link: u16, sample_type: Sf2SampleType) -> Sf2ModelSample {
+    Sf2ModelSample {
+        sample_name: sample_name.to_owned(),
+        samples_16: vec![0; 100],
+        start_loop: 20,
+        end_loop: 80,
+        sample_rate: 44100,
+        original_pitch: 60,
+        sample_link,
+        sample_type: sample_type as u16,
+        ..Default::default()
+    }
+}
+
+fn test_model() -> Sf2Model {
+    Sf2Model {
+        info: Sf2ModelInfo {
+            soundfont_name: "Validation test".to_owned(),
+            ..Default::default()
+        },
+        presets: vec![Sf2ModelPreset {
+            preset_name: "Piano".to_owned(),
+            zones: vec![
+                zone(vec![generator(Sf2GeneratorKind::ReverbEffectsSend, 200)]),
+                zone(vec![generator(Sf2GeneratorKind::Instrument, 0)]),
+            ],
+            ..Default::default()
+        }],
+        instruments: vec![Sf2ModelInstrument {
+            instrument_name: "Piano".to_owned(),
+            zones: vec![
+                zone(vec![generator(Sf2GeneratorKind::SampleId, 0)]),
+                zone(vec![generator(Sf2GeneratorKind::SampleId, 1)]),
+            ],
+        }],
+        samples: vec![
+            sample("Piano L", 1, Sf2SampleType::Left),
+            sample("Piano R", 0, Sf2SampleType::Right),
+        ],
+    }
+}
+
+fn find_chunk_data(buffer: &[u8], chunk_id: &[u8; 4]) -> usize {
+    buffer
+        .windows(4)
+        .position(|window| window == chunk_id)
+        .unwrap()
+        + 8
+}
+
+#[test]
+fn valid_soundfont() {
+    let buffer = test_model().to_bytes().unwrap();
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+
+    assert_eq!(soundfont.validate(), []);
+}
+
+#[test]
+fn hydra_violations() {
+    let mut model = test_model();
+
+    model.presets.push(model.presets[0].clone());
+    model.instruments[0].zones[1] = zone(vec![
+        generator(Sf2GeneratorKind::SampleId, 1),
+        Sf2ModelGenerator {
+            generator_id: 99,
+            amount: 0,
+        },
+    ]);
+    model.samples[0].end_loop = 120;
+    model.samples[1].sample_link = 5;
+
+    let buffer = model.to_bytes().unwrap();
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+
+    let violations = soundfont
+        .validate()
+        .into_iter()
+        .map(|diagnostic| diagnostic.violation)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        violations,
+        [
+            Sf2Violation::DuplicatePreset {
+                preset_name: "Piano".to_owned(),
+                bank: 0,
+                preset: 0,
+            },
+            Sf2Violation::UnknownGenerator {
+                chunk_id: "igen",
+                record_index: 2,
+                generator_id: 99,
+            },
+            Sf2Violation::MissingTerminalGenerator {
+                name: "Piano".to_owned(),
+                zone_index: 1,
+                generator_kind: Sf2GeneratorKind::SampleId,
+            },
+            Sf2Violation::DanglingSampleLink {
+                sample_name: "Piano L".to_owned(),
+                linked_sample_index: 1,
+            },
+            Sf2Violation::LoopOutOfRange {
+                sample_name: "Piano L".to_owned(),
+                start_loop: 20,
+                end_loop: 120,
+            },
+            Sf2Violation::DanglingSampleLink {
+                sample_name: "Piano R".to_owned(),
+                linked_sample_index: 5,
+            },
+        ]
+    );
+}
+
+#[test]
+fn broken_indices() {
+    let mut buffer = test_model().to_bytes().unwrap();
+
+    // Second `pbag` record pointing past the end of the `pgen` chunk
+    let pbag_offset = find_chunk_data(&buffer, b"pbag");
+    buffer[pbag_offset + 4..pbag_offset + 6].copy_from_slice(&5u16.to_le_bytes());
+
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    let diagnostics = soundfont.validate();
+
+    assert_eq!(diagnostics.len(), 2);
+
+    assert_eq!(diagnostics[0].offset, Some(pbag_offset + 4));
+    assert_eq!(diagnostics[0].severity(), Sf2Severity::Error);
+    assert_eq!(
+        diagnostics[0].violation,
+        Sf2Violation::IndexOutOfRange {
+            chunk_id: "pbag",
+            record_index: 1,
+            target_chunk_id: "pgen",
+            index: 5,
+        }
+    );
+
+    assert_eq!(diagnostics[1].offset, Some(pbag_offset + 8));
+    assert_eq!(
+        diagnostics[1].violation,
+        Sf2Violation::NonMonotonicIndex {
+            chunk_id: "pbag",
+            record_index: 2,
+            target_chunk_id: "pgen",
+            index: 2,
+        }
+    );
+}
+
+#[test]
+fn sample_and_info_violations() {
+    let mut buffer = test_model().to_bytes().unwrap();
+
+    // First sample extended into its padding
+    let shdr_offset = find_chunk_data(&buffer, b"shdr");
+    let end = u32::from_le_bytes(
+        buffer[shdr_offset + 24..shdr_offset + 28]
+            .try_into()
+            .unwrap(),
+    );
+    buffer[shdr_offset + 24..shdr_offset + 28].copy_from_slice(&(end + 10).to_le_bytes());
+
+    // Odd-sized name string, the pad byte keeps the RIFF structure intact
+    let inam_offset = find_chunk_data(&buffer, b"INAM");
+    let inam_size = u32::from_le_bytes(buffer[inam_offset - 4..inam_offset].try_into().unwrap());
+    buffer[inam_offset - 4..inam_offset].copy_from_slice(&(inam_size - 1).to_le_bytes());
+
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+
+    let diagnostics = soundfont.validate();
+
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0].offset, Some(inam_offset - 8));
+    assert_eq!(diagnostics[0].severity(), Sf2Severity::Warning);
+    assert_eq!(
+        diagnostics[0].violation,
+        Sf2Violation::InfoStringOddSize {
+            chunk_id: "INAM".to_owned(),
+            length: inam_size as usize - 1,
+        }
+    );
+    assert_eq!(diagnostics[1].offset, Some(shdr_offset));
+    assert_eq!(
+        diagnostics[1].violation,
+        Sf2Violation::MissingSamplePadding {
+            sample_name: "Piano L".to_owned(),
+            padding_length: 36,
+        }
+    );
+}
+
+#[test]
+fn zero_length_samples_have_padding() {
+    let mut model = test_model();
+    model.samples.insert(
+        1,
+        Sf2ModelSample {
+            samples_16: vec![],
+            start_loop: 0,
+            end_loop: 0,
+            ..sample("Empty", 0, Sf2SampleType::Mono)
+        },
+    );
+    model.samples[0].sample_link = 2;
+    model.samples[2].sample_link = 0;
+    model.instruments[0].zones[1] = zone(vec![generator(Sf2GeneratorKind::SampleId, 2)]);
+
+    let buffer = model.to_bytes().unwrap();
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+
+    let diagnostics = soundfont.validate();
+    assert!(
+        diagnostics.iter().all(|diagnostic| !matches!(
+            diagnostic.violation,
+            Sf2Violation::MissingSamplePadding { .. }
+        )),
+        "{diagnostics:?}"
+    );
+}
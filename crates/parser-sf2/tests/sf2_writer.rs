@@ -0,0 +1,446 @@
+use parser_sf2::{
+    Sf2ControllerPalette, Sf2Error, Sf2GeneralController, Sf2GeneratorAmount, Sf2GeneratorKind,
+    Sf2Model, Sf2ModelGenerator, Sf2ModelInfo, Sf2ModelInstrument, Sf2ModelModulator,
+    Sf2ModelPreset, Sf2ModelSample, Sf2ModelZone, Sf2ModulatorDestination, Sf2ModulatorSource,
+    Sf2ModulatorTransform, Sf2SampleType, Sf2SoundFont, Sf2SourceCurveType, Sf2SourceDirection,
+    Sf2SourcePolarity, Sf2Violation,
+};
+
+fn generator(generator_kind: Sf2GeneratorKind, amount: Sf2GeneratorAmount) -> Sf2ModelGenerator {
+    Sf2ModelGenerator::new(generator_kind, amount)
+}
+
+fn test_model() -> Sf2Model {
+    let velocity_to_attenuation = Sf2ModelModulator::new(
+        Sf2ModulatorSource {
+            controller_palette: Sf2ControllerPalette::General,
+            index: Sf2GeneralController::NoteOnVelocity as u8,
+            direction: Sf2SourceDirection::Negative,
+            polarity: Sf2SourcePolarity::Unipolar,
+            curve_type: Sf2SourceCurveType::Concave,
+        },
+        Sf2ModulatorDestination::Generator(Sf2GeneratorKind::InitialAttenuation),
+        960,
+        Sf2ModulatorSource::from_raw(0).unwrap(),
+        Sf2ModulatorTransform::Linear,
+    );
+
+    let modulation_wheel_to_pitch = Sf2ModelModulator::new(
+        Sf2ModulatorSource {
+            controller_palette: Sf2ControllerPalette::Midi,
+            index: 1,
+            direction: Sf2SourceDirection::Positive,
+            polarity: Sf2SourcePolarity::Bipolar,
+            curve_type: Sf2SourceCurveType::Linear,
+        },
+        Sf2ModulatorDestination::Generator(Sf2GeneratorKind::ModLfoToPitch),
+        50,
+        Sf2ModulatorSource::from_raw(0).unwrap(),
+        Sf2ModulatorTransform::AbsoluteValue,
+    );
+
+    Sf2Model {
+        info: Sf2ModelInfo {
+            format_version: (2, 4),
+            sound_engine: "EMU8000".to_owned(),
+            soundfont_name: "Writer test".to_owned(),
+            rom_name: None,
+            rom_version: None,
+            date: Some("October 17, 2026".to_owned()),
+            author: Some("sf2_xt".to_owned()),
+            product: None,
+            copyright: Some("Public domain".to_owned()),
+            comment: Some("Odd".to_owned()),
+            soundfont_tools: Some(vec!["sf2_xt".to_owned()]),
+        },
+        presets: vec![
+            Sf2ModelPreset {
+                preset_name: "Stereo Piano".to_owned(),
+                preset: 0,
+                bank: 0,
+                zones: vec![
+                    Sf2ModelZone {
+                        generators: vec![generator(
+                            Sf2GeneratorKind::ReverbEffectsSend,
+                            Sf2GeneratorAmount::Signed(200),
+                        )],
+                        modulators: vec![modulation_wheel_to_pitch],
+                    },
+                    Sf2ModelZone {
+                        generators: vec![
+                            generator(
+                                Sf2GeneratorKind::KeyRange,
+                                Sf2GeneratorAmount::Range { low: 0, high: 63 },
+                            ),
+                            generator(
+                                Sf2GeneratorKind::Instrument,
+                                Sf2GeneratorAmount::Unsigned(0),
+                            ),
+                        ],
+                        modulators: vec![],
+                    },
+                    Sf2ModelZone {
+                        generators: vec![
+                            generator(
+                                Sf2GeneratorKind::KeyRange,
+                                Sf2GeneratorAmount::Range { low: 64, high: 127 },
+                            ),
+                            generator(
+                                Sf2GeneratorKind::CoarseTune,
+                                Sf2GeneratorAmount::Signed(-12),
+                            ),
+                            generator(
+                                Sf2GeneratorKind::Instrument,
+                                Sf2GeneratorAmount::Unsigned(0),
+                            ),
+                        ],
+                        modulators: vec![],
+                    },
+                ],
+                ..Default::default()
+            },
+            Sf2ModelPreset {
+                preset_name: "Standard Kit".to_owned(),
+                preset: 0,
+                bank: 128,
+                library: 1,
+                genre: 2,
+                morphology: 3,
+                zones: vec![Sf2ModelZone {
+                    generators: vec![generator(
+                        Sf2GeneratorKind::Instrument,
+                        Sf2GeneratorAmount::Unsigned(1),
+                    )],
+                    modulators: vec![],
+                }],
+            },
+        ],
+        instruments: vec![
+            Sf2ModelInstrument {
+                instrument_name: "Piano".to_owned(),
+                zones: vec![
+                    Sf2ModelZone {
+                        generators: vec![generator(
+                            Sf2GeneratorKind::SampleModes,
+                            Sf2GeneratorAmount::Unsigned(1),
+                        )],
+                        modulators: vec![velocity_to_attenuation],
+                    },
+                    Sf2ModelZone {
+                        generators: vec![
+                            generator(Sf2GeneratorKind::Pan, Sf2GeneratorAmount::Signed(-500)),
+                            generator(Sf2GeneratorKind::SampleId, Sf2GeneratorAmount::Unsigned(0)),
+                        ],
+                        modulators: vec![],
+                    },
+                    Sf2ModelZone {
+                        generators: vec![
+                            generator(Sf2GeneratorKind::Pan, Sf2GeneratorAmount::Signed(500)),
+                            generator(Sf2GeneratorKind::SampleId, Sf2GeneratorAmount::Unsigned(1)),
+                        ],
+                        modulators: vec![],
+                    },
+                ],
+            },
+            Sf2ModelInstrument {
+                instrument_name: "Snare".to_owned(),
+                zones: vec![Sf2ModelZone {
+                    generators: vec![
+                        generator(
+                            Sf2GeneratorKind::KeyRange,
+                            Sf2GeneratorAmount::Range { low: 38, high: 38 },
+                        ),
+                        generator(Sf2GeneratorKind::SampleId, Sf2GeneratorAmount::Unsigned(2)),
+                    ],
+                    modulators: vec![],
+                }],
+            },
+        ],
+        samples: vec![
+            Sf2ModelSample {
+                sample_name: "Piano L".to_owned(),
+                samples_16: (0..100).map(|i| i * 100).collect(),
+                samples_24_lsb: Some((0..100).map(|i| i as u8).collect()),
+                start_loop: 20,
+                end_loop: 80,
+                sample_rate: 44100,
+                original_pitch: 60,
+                pitch_correction: -3,
+                sample_link: 1,
+                sample_type: Sf2SampleType::Left as u16,
+                rom_range: None,
+            },
+            Sf2ModelSample {
+                sample_name: "Piano R".to_owned(),
+                samples_16: (0..100).map(|i| -i * 100).collect(),
+                samples_24_lsb: Some((0..100).map(|i| 255 - i as u8).collect()),
+                start_loop: 20,
+                end_loop: 80,
+                sample_rate: 44100,
+                original_pitch: 60,
+                pitch_correction: -3,
+                sample_link: 0,
+                sample_type: Sf2SampleType::Right as u16,
+                rom_range: None,
+            },
+            Sf2ModelSample {
+                sample_name: "Snare with 20 chars".to_owned(),
+                samples_16: vec![i16::MIN, i16::MAX, 0, 1, -1],
+                samples_24_lsb: Some(vec![0xFF, 0x00, 0x80, 0x01, 0x7F]),
+                start_loop: 0,
+                end_loop: 0,
+                sample_rate: 22050,
+                original_pitch: 38,
+                pitch_correction: 0,
+                sample_link: 0,
+                sample_type: Sf2SampleType::Mono as u16,
+                rom_range: None,
+            },
+        ],
+    }
+}
+
+#[test]
+fn round_trip_is_lossless() {
+    let model = test_model();
+    let buffer = model.to_bytes().unwrap();
+
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    let parsed_model = Sf2Model::from_soundfont(&soundfont).unwrap();
+
+    assert_eq!(parsed_model, model);
+}
+
+#[test]
+fn rewrite_is_byte_identical() {
+    let buffer = test_model().to_bytes().unwrap();
+
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    let rewritten_buffer = Sf2Model::from_soundfont(&soundfont)
+        .unwrap()
+        .to_bytes()
+        .unwrap();
+
+    assert_eq!(rewritten_buffer, buffer);
+}
+
+#[test]
+fn bag_indices_and_terminal_records() {
+    let buffer = test_model().to_bytes().unwrap();
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+
+    assert_eq!(soundfont.preset_headers().unwrap().len(), 2);
+    assert_eq!(soundfont.preset_zones().unwrap().len(), 4);
+    assert_eq!(soundfont.preset_generators().unwrap().len(), 7);
+    assert_eq!(soundfont.preset_modulators().unwrap().len(), 1);
+    assert_eq!(soundfont.instrument_headers().unwrap().len(), 2);
+    assert_eq!(soundfont.instrument_zones().unwrap().len(), 4);
+    assert_eq!(soundfont.instrument_generators().unwrap().len(), 7);
+    assert_eq!(soundfont.instrument_modulators().unwrap().len(), 1);
+    assert_eq!(soundfont.sample_headers().unwrap().len(), 3);
+
+    let preset = soundfont.find_preset(0, 0).unwrap().unwrap();
+    assert!(preset.global_zone().unwrap().is_some());
+
+    let preset_zones = preset.zones().unwrap();
+    assert_eq!(preset_zones.len(), 2);
+    assert_eq!(preset_zones[1].key_range(), Some((64, 127)));
+
+    let instrument = preset_zones[1].instrument().unwrap().unwrap();
+    assert_eq!(
+        instrument.instrument_header().instrument_name().unwrap(),
+        "Piano"
+    );
+    assert!(instrument.global_zone().unwrap().is_some());
+
+    let instrument_zones = instrument.zones().unwrap();
+    assert_eq!(instrument_zones.len(), 2);
+
+    let sample_header = instrument_zones[1].sample_header().unwrap().unwrap();
+    assert_eq!(sample_header.sample_name().unwrap(), "Piano R");
+
+    let drum_preset = soundfont.find_preset(128, 0).unwrap().unwrap();
+    assert!(drum_preset.global_zone().unwrap().is_none());
+    assert_eq!(drum_preset.preset_header().library.get(), 1);
+}
+
+#[test]
+fn samples_are_padded() {
+    let buffer = test_model().to_bytes().unwrap();
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+
+    let sample_headers = soundfont.sample_headers().unwrap();
+    assert_eq!(sample_headers[0].start.get(), 0);
+    assert_eq!(sample_headers[0].end.get(), 100);
+    assert_eq!(sample_headers[0].start_loop.get(), 20);
+    assert_eq!(sample_headers[1].start.get(), 146);
+    assert_eq!(sample_headers[1].end_loop.get(), 226);
+    assert_eq!(sample_headers[2].start.get(), 292);
+
+    let samples_16 = soundfont.sample_data_16_chunk().unwrap();
+    assert_eq!(samples_16.len(), 343);
+    assert!(samples_16[100..146].iter().all(|sample| sample.get() == 0));
+    assert!(samples_16[297..].iter().all(|sample| sample.get() == 0));
+
+    let samples_24 = soundfont.sample_data_24_chunk().unwrap().unwrap();
+    assert_eq!(samples_24.len(), 343);
+
+    let snare_frames = soundfont
+        .sample_data(&sample_headers[2])
+        .unwrap()
+        .frames_24()
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(snare_frames, [-8388353, 8388352, 128, 257, -129]);
+
+    assert!(soundfont.linked_sample_header(0).unwrap().is_some());
+    assert!(soundfont.linked_sample_header(2).unwrap().is_none());
+}
+
+#[test]
+fn strings_must_fit() {
+    // Fixed-size names may fill their whole field, without a terminator.
+    let mut model = test_model();
+    model.presets[0].preset_name = "Preset with 20 chars".to_owned();
+    model.instruments[0].instrument_name = "Instr. with 20 chars".to_owned();
+    model.samples[2].sample_name = "Sample with 20 chars".to_owned();
+
+    let buffer = model.to_bytes().unwrap();
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    assert_eq!(Sf2Model::from_soundfont(&soundfont).unwrap(), model);
+
+    model.presets[0].preset_name = "Preset with 21 chars.".to_owned();
+    assert!(matches!(
+        model.to_bytes(),
+        Err(Sf2Error::StringTooLong { max_length: 20, .. })
+    ));
+
+    // INFO strings are limited to 256 bytes, terminator included.
+    let mut model = test_model();
+    model.info.author = Some("a".repeat(255));
+    let buffer = model.to_bytes().unwrap();
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    assert!(soundfont
+        .validate()
+        .iter()
+        .all(|diagnostic| !matches!(diagnostic.violation, Sf2Violation::InfoStringTooLong { .. })));
+
+    model.info.author = Some("a".repeat(256));
+    assert!(matches!(
+        model.to_bytes(),
+        Err(Sf2Error::StringTooLong {
+            max_length: 255,
+            ..
+        })
+    ));
+
+    // Comments may be longer.
+    let mut model = test_model();
+    model.info.comment = Some("a".repeat(1000));
+    assert!(model.to_bytes().is_ok());
+}
+
+#[test]
+fn info_strings_are_terminated_and_padded() {
+    let buffer = test_model().to_bytes().unwrap();
+
+    let find_chunk = |chunk_id: &[u8; 4]| {
+        let offset = buffer
+            .windows(4)
+            .position(|window| window == chunk_id)
+            .unwrap();
+        let size = u32::from_le_bytes(buffer[offset + 4..offset + 8].try_into().unwrap());
+        &buffer[offset + 8..offset + 8 + size as usize]
+    };
+
+    // Strings of odd length are of even length with their terminator.
+    assert_eq!(find_chunk(b"ICMT"), b"Odd\0");
+    assert_eq!(find_chunk(b"isng"), b"EMU8000\0");
+    // Others get padded with a second zero.
+    assert_eq!(find_chunk(b"IENG"), b"sf2_xt\0\0");
+    assert_eq!(find_chunk(b"ICOP"), b"Public domain\0");
+    assert_eq!(find_chunk(b"ISFT"), b"sf2_xt\0\0");
+
+    // Tools are separated by colons, without a trailing one.
+    let mut model = test_model();
+    model.info.soundfont_tools = Some(vec!["Editor 1.0".to_owned(), "sf2_xt".to_owned()]);
+    let buffer = model.to_bytes().unwrap();
+    let offset = buffer
+        .windows(4)
+        .position(|window| window == b"ISFT")
+        .unwrap();
+    assert_eq!(&buffer[offset + 4..offset + 8], &18u32.to_le_bytes());
+    assert_eq!(&buffer[offset + 8..offset + 26], b"Editor 1.0:sf2_xt\0");
+
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    assert_eq!(
+        soundfont.info().unwrap().soundfont_tools().unwrap(),
+        Some(vec!["Editor 1.0", "sf2_xt"])
+    );
+}
+
+#[test]
+fn samples_24_lsb_must_match_samples() {
+    let mut model = test_model();
+    model.samples[1].samples_24_lsb = Some(vec![0; 99]);
+
+    assert!(matches!(
+        model.to_bytes(),
+        Err(Sf2Error::SampleLengthMismatch {
+            samples_16_length: 100,
+            samples_24_length: 99,
+            ..
+        })
+    ));
+
+    // Samples without 24-bit data get zeroes in a file with an `sm24` chunk.
+    let mut model = test_model();
+    model.samples[1].samples_24_lsb = None;
+
+    let buffer = model.to_bytes().unwrap();
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    let sample_data = soundfont
+        .sample_data(soundfont.sample_header(1).unwrap())
+        .unwrap();
+    assert!(sample_data
+        .samples_24_lsb()
+        .unwrap()
+        .iter()
+        .all(|&lsb| lsb == 0));
+}
+
+#[test]
+fn rom_samples_have_no_data() {
+    let mut model = test_model();
+    model.samples.push(Sf2ModelSample {
+        sample_name: "ROM piano".to_owned(),
+        start_loop: 10,
+        end_loop: 90,
+        sample_rate: 44100,
+        original_pitch: 60,
+        sample_type: Sf2SampleType::RomMono as u16,
+        rom_range: Some((1000, 1100)),
+        ..Default::default()
+    });
+
+    let buffer = model.to_bytes().unwrap();
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+
+    // The ROM sample keeps its offsets into the ROM and adds nothing to the
+    // sample data of the others.
+    let sample_header = soundfont.sample_header(3).unwrap();
+    assert_eq!(sample_header.start.get(), 1000);
+    assert_eq!(sample_header.end.get(), 1100);
+    assert_eq!(sample_header.start_loop.get(), 1010);
+    assert_eq!(sample_header.end_loop.get(), 1090);
+    assert_eq!(
+        soundfont.sample_data_16_chunk().unwrap().len(),
+        100 + 46 + 100 + 46 + 5 + 46
+    );
+    assert_eq!(
+        soundfont.sample_data_24_chunk().unwrap().unwrap().len(),
+        100 + 46 + 100 + 46 + 5 + 46
+    );
+
+    assert_eq!(Sf2Model::from_soundfont(&soundfont).unwrap(), model);
+}
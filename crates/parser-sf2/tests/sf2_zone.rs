@@ -0,0 +1,264 @@
+use parser_sf2::{
+    Sf2Error, Sf2Generator, Sf2GeneratorAmount, Sf2GeneratorKind, Sf2Model, Sf2ModelGenerator,
+    Sf2ModelInstrument, Sf2ModelModulator, Sf2ModelPreset, Sf2ModelSample, Sf2ModelZone,
+    Sf2SoundFont,
+};
+
+fn generator(generator_kind: Sf2GeneratorKind, amount: Sf2GeneratorAmount) -> Sf2ModelGenerator {
+    Sf2ModelGenerator::new(generator_kind, amount)
+}
+
+fn key_range(low: u8, high: u8) -> Sf2ModelGenerator {
+    generator(
+        Sf2GeneratorKind::KeyRange,
+        Sf2GeneratorAmount::Range { low, high },
+    )
+}
+
+fn modulator() -> Sf2ModelModulator {
+    Sf2ModelModulator {
+        source: 0x0502,
+        destination: Sf2GeneratorKind::InitialAttenuation.id(),
+        amount: 960,
+        amount_source: 0,
+        transform: 0,
+    }
+}
+
+fn test_model() -> Sf2Model {
+    Sf2Model {
+        presets: vec![
+            Sf2ModelPreset {
+                preset_name: "Piano".to_owned(),
+                zones: vec![
+                    Sf2ModelZone {
+                        generators: vec![generator(
+                            Sf2GeneratorKind::ReverbEffectsSend,
+                            Sf2GeneratorAmount::Signed(200),
+                        )],
+                        modulators: vec![modulator()],
+                    },
+                    Sf2ModelZone {
+                        generators: vec![
+                            key_range(0, 59),
+                            generator(
+                                Sf2GeneratorKind::Instrument,
+                                Sf2GeneratorAmount::Unsigned(0),
+                            ),
+                        ],
+                        modulators: vec![],
+                    },
+                    // Not a global zone, as it is not the first one
+                    Sf2ModelZone {
+                        generators: vec![generator(
+                            Sf2GeneratorKind::ChorusEffectsSend,
+                            Sf2GeneratorAmount::Signed(100),
+                        )],
+                        modulators: vec![],
+                    },
+                    Sf2ModelZone {
+                        generators: vec![
+                            key_range(60, 127),
+                            generator(
+                                Sf2GeneratorKind::Instrument,
+                                Sf2GeneratorAmount::Unsigned(0),
+                            ),
+                        ],
+                        modulators: vec![modulator(), modulator()],
+                    },
+                ],
+                ..Default::default()
+            },
+            Sf2ModelPreset {
+                preset_name: "Organ".to_owned(),
+                preset: 1,
+                zones: vec![Sf2ModelZone {
+                    generators: vec![generator(
+                        Sf2GeneratorKind::Instrument,
+                        Sf2GeneratorAmount::Unsigned(0),
+                    )],
+                    modulators: vec![],
+                }],
+                ..Default::default()
+            },
+        ],
+        instruments: vec![Sf2ModelInstrument {
+            instrument_name: "Piano".to_owned(),
+            zones: vec![
+                // A global zone made of modulators only
+                Sf2ModelZone {
+                    generators: vec![],
+                    modulators: vec![modulator()],
+                },
+                Sf2ModelZone {
+                    generators: vec![generator(
+                        Sf2GeneratorKind::SampleId,
+                        Sf2GeneratorAmount::Unsigned(0),
+                    )],
+                    modulators: vec![],
+                },
+            ],
+        }],
+        samples: vec![Sf2ModelSample {
+            sample_name: "Piano".to_owned(),
+            samples_16: vec![0; 100],
+            sample_rate: 44100,
+            original_pitch: 60,
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
+
+fn find_chunk_data(buffer: &[u8], chunk_id: &[u8; 4]) -> usize {
+    buffer
+        .windows(4)
+        .position(|window| window == chunk_id)
+        .unwrap()
+        + 8
+}
+
+fn generator_kinds<G: Sf2Generator>(generators: &[G]) -> Vec<Sf2GeneratorKind> {
+    generators
+        .iter()
+        .map(|generator| generator.generator_kind().unwrap())
+        .collect()
+}
+
+#[test]
+fn zones_follow_bag_indices() {
+    let buffer = test_model().to_bytes().unwrap();
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+
+    let preset = soundfont.preset(0).unwrap();
+    let zones = preset.all_zones().unwrap();
+
+    let generator_counts = zones
+        .iter()
+        .map(|zone| zone.generators().len())
+        .collect::<Vec<_>>();
+    assert_eq!(generator_counts, [1, 2, 1, 2]);
+
+    let modulator_counts = zones
+        .iter()
+        .map(|zone| zone.modulators().len())
+        .collect::<Vec<_>>();
+    assert_eq!(modulator_counts, [1, 0, 0, 2]);
+
+    // Consecutive zones share their boundaries, covering all records but the
+    // terminal ones.
+    let preset_generators = soundfont.preset_generators().unwrap();
+    let zone_generators = zones
+        .iter()
+        .flat_map(|zone| generator_kinds(zone.generators()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        zone_generators,
+        generator_kinds(&preset_generators[..preset_generators.len() - 1])
+    );
+
+    assert_eq!(
+        generator_kinds(zones[3].generators()),
+        [Sf2GeneratorKind::KeyRange, Sf2GeneratorKind::Instrument]
+    );
+    assert_eq!(zones[3].key_range(), Some((60, 127)));
+
+    // The second preset starts at the bag following the first one.
+    let zones = soundfont.preset(1).unwrap().all_zones().unwrap();
+    assert_eq!(zones.len(), 1);
+    assert_eq!(zones[0].instrument_index(), Some(0));
+}
+
+#[test]
+fn global_zones() {
+    let buffer = test_model().to_bytes().unwrap();
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+
+    let preset = soundfont.preset(0).unwrap();
+    let global_zone = preset.global_zone().unwrap().unwrap();
+    assert_eq!(
+        global_zone.generator_amount(Sf2GeneratorKind::ReverbEffectsSend),
+        Some(Sf2GeneratorAmount::Signed(200))
+    );
+    assert_eq!(global_zone.instrument_index(), None);
+    assert_eq!(global_zone.modulators().len(), 1);
+
+    // A first zone ending with the terminal generator is a local zone.
+    let preset = soundfont.preset(1).unwrap();
+    assert!(preset.global_zone().unwrap().is_none());
+    assert_eq!(preset.zones().unwrap().len(), 1);
+
+    // A global zone without any generator.
+    let instrument = soundfont.instrument(0).unwrap();
+    let global_zone = instrument.global_zone().unwrap().unwrap();
+    assert!(global_zone.generators().is_empty());
+    assert_eq!(global_zone.modulators().len(), 1);
+    assert_eq!(global_zone.sample_index(), None);
+
+    let zones = instrument.zones().unwrap();
+    assert_eq!(zones.len(), 1);
+    assert_eq!(zones[0].sample_index(), Some(0));
+}
+
+#[test]
+fn non_first_zone_without_terminal_generator_is_ignored() {
+    let buffer = test_model().to_bytes().unwrap();
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+
+    let preset = soundfont.preset(0).unwrap();
+    let zones = preset.zones().unwrap();
+
+    let key_ranges = zones
+        .iter()
+        .map(|zone| zone.key_range())
+        .collect::<Vec<_>>();
+    assert_eq!(key_ranges, [Some((0, 59)), Some((60, 127))]);
+
+    assert!(zones.iter().all(|zone| zone
+        .generator(Sf2GeneratorKind::ChorusEffectsSend)
+        .is_none()));
+
+    assert!(zones[0].matches(59, 100));
+    assert!(!zones[0].matches(60, 100));
+    assert!(zones[1].matches(60, 0));
+}
+
+#[test]
+fn bag_indices_out_of_range() {
+    let buffer = test_model().to_bytes().unwrap();
+    let pbag_offset = find_chunk_data(&buffer, b"pbag");
+
+    let assert_index_out_of_range = |buffer: &[u8], expected_chunk_id: &str, expected_index| {
+        let soundfont = Sf2SoundFont::new(buffer).unwrap();
+        let error = soundfont.preset(0).unwrap().all_zones().err().unwrap();
+
+        assert!(
+            matches!(
+                &error,
+                Sf2Error::IndexOutOfRange { chunk_id, index }
+                    if (chunk_id == expected_chunk_id) && (*index == expected_index)
+            ),
+            "{error:?}"
+        );
+    };
+
+    // Third bag starting before the second one
+    let mut non_monotonic = buffer.clone();
+    non_monotonic[pbag_offset + 8..pbag_offset + 10].copy_from_slice(&0u16.to_le_bytes());
+    assert_index_out_of_range(&non_monotonic, "pgen", 0);
+
+    let mut non_monotonic = buffer.clone();
+    non_monotonic[pbag_offset + 10..pbag_offset + 12].copy_from_slice(&0u16.to_le_bytes());
+    assert_index_out_of_range(&non_monotonic, "pmod", 0);
+
+    // Fourth bag ending past the end of the `pgen` chunk
+    let mut past_end = buffer.clone();
+    past_end[pbag_offset + 16..pbag_offset + 18].copy_from_slice(&100u16.to_le_bytes());
+    assert_index_out_of_range(&past_end, "pgen", 100);
+
+    // Preset header pointing past the end of the `pbag` chunk
+    let phdr_offset = find_chunk_data(&buffer, b"phdr");
+    let mut past_end = buffer.clone();
+    past_end[phdr_offset + 38 + 24..phdr_offset + 38 + 26].copy_from_slice(&100u16.to_le_bytes());
+    assert_index_out_of_range(&past_end, "pbag", 100);
+}
@@ -0,0 +1,21 @@
+use parser_sf2::Sf2Error;
+
+use derive_more::{Display, Error, From};
+
+#[rustfmt::skip]
+#[derive(Debug, Display, Error, From)]
+pub enum SynthError {
+    #[display(fmt = "Invalid channel {channel:}")]
+    InvalidChannel {
+        channel: usize,
+    },
+
+    #[display(fmt = "Preset {bank:03}:{preset:03} not found")]
+    PresetNotFound {
+        bank: u16,
+        preset: u16,
+    },
+
+    #[from]
+    Sf2Error(Sf2Error),
+}
@@ -0,0 +1,28 @@
+mod error;
+mod synth;
+mod synth_chorus;
+mod synth_controllers;
+mod synth_envelope;
+mod synth_filter;
+mod synth_generators;
+mod synth_lfo;
+mod synth_modulators;
+mod synth_reverb;
+mod synth_sampler;
+mod synth_voice;
+
+pub use error::SynthError;
+pub use synth::{Synth, CHANNEL_COUNT, DRUM_BANK, DRUM_CHANNEL};
+pub use synth_chorus::{SynthChorus, SynthChorusParameters};
+pub use synth_controllers::SynthControllers;
+pub use synth_envelope::{
+    timecents_to_seconds, SynthEnvelope, SynthEnvelopeKind, SynthEnvelopeStage,
+};
+pub use synth_filter::{SynthFilter, FILTER_CONTROL_PERIOD};
+pub use synth_generators::SynthGenerators;
+pub use synth_lfo::{absolute_cents_to_hertz, SynthLfo};
+pub use synth_modulators::{default_modulators, SynthModulator, SynthModulators};
+pub use synth_reverb::{SynthReverb, SynthReverbParameters};
+pub use synth_sampler::{SynthInterpolation, SynthLoopMode, SynthSampler};
+
+pub type SynthResult<T> = Result<T, SynthError>;
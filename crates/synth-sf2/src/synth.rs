@@ -0,0 +1,519 @@
+use midi_defs::controller;
+use midi_defs::message::MidiMessage;
+use parser_sf2::{Sf2Generator, Sf2SoundFont, Sf2Zone};
+
+use crate::synth_voice::SynthVoice;
+use crate::{
+    SynthChorus, SynthChorusParameters, SynthControllers, SynthError, SynthGenerators,
+    SynthInterpolation, SynthModulators, SynthResult, SynthReverb, SynthReverbParameters,
+};
+
+pub const CHANNEL_COUNT: usize = 16;
+
+/// Channel 10 of General MIDI, playing drum kits from bank 128.
+pub const DRUM_CHANNEL: usize = 9;
+pub const DRUM_BANK: u16 = 128;
+
+#[derive(Debug, Copy, Clone, Default)]
+struct SynthChannel {
+    preset_index: Option<usize>,
+    bank_msb: u8,
+    bank_lsb: u8,
+    controllers: SynthControllers,
+    is_sustain_held: bool,
+}
+
+impl SynthChannel {
+    fn new() -> SynthChannel {
+        SynthChannel::default()
+    }
+
+    /// The bank selected by the bank select controllers, the LSB is only
+    /// taken into account when set.
+    fn bank(&self) -> u16 {
+        match self.bank_lsb {
+            0 => self.bank_msb as u16,
+            bank_lsb => ((self.bank_msb as u16) << 7) | bank_lsb as u16,
+        }
+    }
+}
+
+/// Zones apply to notes within their key and velocity ranges. Local zones
+/// lacking a range inherit it from the global zone.
+fn zone_matches<G: Sf2Generator, M>(
+    global_zone: Option<&Sf2Zone<'_, G, M>>,
+    zone: &Sf2Zone<'_, G, M>,
+    key: u8,
+    velocity: u8,
+) -> bool {
+    let global_key_range = global_zone.and_then(|global_zone| global_zone.key_range());
+    let global_velocity_range = global_zone.and_then(|global_zone| global_zone.velocity_range());
+
+    let (key_low, key_high) = zone.key_range().or(global_key_range).unwrap_or((0, 127));
+    let (velocity_low, velocity_high) = zone
+        .velocity_range()
+        .or(global_velocity_range)
+        .unwrap_or((0, 127));
+
+    (key_low..=key_high).contains(&key) && (velocity_low..=velocity_high).contains(&velocity)
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+/// Offline SoundFont synthesizer rendering into interleaved stereo buffers.
+///
+/// Notes are played on `CHANNEL_COUNT` channels, each with its own preset.
+/// Events take effect at the start of the next `render` call, callers have
+/// to split their buffers at event boundaries for sample-accurate timing.
+///
+/// The voices feed the built-in reverb and chorus through their effect
+/// sends, both effects can be turned off for dry renders.
+pub struct Synth<'a> {
+    soundfont: &'a Sf2SoundFont<'a>,
+    sample_rate: u32,
+    interpolation: SynthInterpolation,
+    channels: [SynthChannel; CHANNEL_COUNT],
+    voices: Vec<SynthVoice<'a>>,
+
+    reverb: SynthReverb,
+    chorus: SynthChorus,
+    is_reverb_enabled: bool,
+    is_chorus_enabled: bool,
+    reverb_input: Vec<f32>,
+    chorus_input: Vec<f32>,
+}
+
+impl<'a> Synth<'a> {
+    pub fn new(soundfont: &'a Sf2SoundFont<'a>, sample_rate: u32) -> Synth<'a> {
+        Synth {
+            soundfont,
+            sample_rate,
+            interpolation: SynthInterpolation::default(),
+            channels: [SynthChannel::new(); CHANNEL_COUNT],
+            voices: Vec::new(),
+
+            reverb: SynthReverb::new(sample_rate, SynthReverbParameters::default()),
+            chorus: SynthChorus::new(sample_rate, SynthChorusParameters::default()),
+            is_reverb_enabled: true,
+            is_chorus_enabled: true,
+            reverb_input: Vec::new(),
+            chorus_input: Vec::new(),
+        }
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    pub fn interpolation(&self) -> SynthInterpolation {
+        self.interpolation
+    }
+
+    /// Sets the interpolation method of the subsequent notes, already
+    /// sounding notes keep their original one.
+    pub fn set_interpolation(&mut self, interpolation: SynthInterpolation) {
+        self.interpolation = interpolation;
+    }
+
+    pub fn active_voice_count(&self) -> usize {
+        self.voices.len()
+    }
+
+    /// Whether any voice is sounding or an effect is still ringing out.
+    pub fn is_active(&self) -> bool {
+        !self.voices.is_empty()
+            || (self.is_reverb_enabled && self.reverb.is_ringing())
+            || (self.is_chorus_enabled && self.chorus.is_ringing())
+    }
+
+    pub fn is_reverb_enabled(&self) -> bool {
+        self.is_reverb_enabled
+    }
+
+    pub fn set_reverb_enabled(&mut self, is_reverb_enabled: bool) {
+        self.is_reverb_enabled = is_reverb_enabled;
+    }
+
+    pub fn reverb_parameters(&self) -> SynthReverbParameters {
+        self.reverb.parameters()
+    }
+
+    pub fn set_reverb_parameters(&mut self, parameters: SynthReverbParameters) {
+        self.reverb.set_parameters(parameters);
+    }
+
+    pub fn is_chorus_enabled(&self) -> bool {
+        self.is_chorus_enabled
+    }
+
+    pub fn set_chorus_enabled(&mut self, is_chorus_enabled: bool) {
+        self.is_chorus_enabled = is_chorus_enabled;
+    }
+
+    pub fn chorus_parameters(&self) -> SynthChorusParameters {
+        self.chorus.parameters()
+    }
+
+    pub fn set_chorus_parameters(&mut self, parameters: SynthChorusParameters) {
+        self.chorus.set_parameters(parameters);
+    }
+
+    fn check_channel(channel: usize) -> SynthResult<()> {
+        if channel < CHANNEL_COUNT {
+            Ok(())
+        } else {
+            Err(SynthError::InvalidChannel { channel })
+        }
+    }
+
+    /// Selects the preset played by the subsequent notes of a channel.
+    /// Already sounding notes keep playing with their original preset.
+    pub fn select_preset(&mut self, channel: usize, bank: u16, preset: u16) -> SynthResult<()> {
+        Self::check_channel(channel)?;
+
+        let sf2_preset = self
+            .soundfont
+            .find_preset(bank, preset)?
+            .ok_or(SynthError::PresetNotFound { bank, preset })?;
+
+        self.channels[channel].preset_index = Some(sf2_preset.preset_index());
+        Ok(())
+    }
+
+    /// Selects a preset of the bank chosen by the bank select controllers,
+    /// or bank 128 on the drum channel. Missing variation presets fall back
+    /// to the presets of the capital bank, like GS and XG modules do.
+    pub fn program_change(&mut self, channel: usize, program: u8) -> SynthResult<()> {
+        Self::check_channel(channel)?;
+
+        let synth_channel = &self.channels[channel];
+        let program = program as u16;
+
+        let bank_candidates = if channel == DRUM_CHANNEL {
+            vec![(DRUM_BANK, program), (DRUM_BANK, 0)]
+        } else {
+            vec![
+                (synth_channel.bank(), program),
+                (synth_channel.bank_msb as u16, program),
+                (0, program),
+            ]
+        };
+
+        for &(bank, preset) in &bank_candidates {
+            if self.select_preset(channel, bank, preset).is_ok() {
+                return Ok(());
+            }
+        }
+
+        let (bank, preset) = bank_candidates[0];
+        Err(SynthError::PresetNotFound { bank, preset })
+    }
+
+    pub fn note_on(&mut self, channel: usize, key: u8, velocity: u8) -> SynthResult<()> {
+        Self::check_channel(channel)?;
+
+        if velocity == 0 {
+            return self.note_off(channel, key);
+        }
+
+        let Some(preset_index) = self.channels[channel].preset_index else {
+            return Ok(());
+        };
+
+        let preset = self.soundfont.preset(preset_index)?;
+        let preset_global_zone = preset.global_zone()?;
+
+        let mut new_voices = Vec::new();
+
+        for preset_zone in preset.zones()? {
+            if !zone_matches(preset_global_zone.as_ref(), &preset_zone, key, velocity) {
+                continue;
+            }
+
+            // Zones referring to broken instruments or samples are skipped,
+            // the other zones of the note still sound.
+            let Ok(Some(instrument)) = preset_zone.instrument() else {
+                continue;
+            };
+
+            let (Ok(instrument_global_zone), Ok(instrument_zones)) =
+                (instrument.global_zone(), instrument.zones())
+            else {
+                continue;
+            };
+
+            for instrument_zone in instrument_zones {
+                if !zone_matches(
+                    instrument_global_zone.as_ref(),
+                    &instrument_zone,
+                    key,
+                    velocity,
+                ) {
+                    continue;
+                }
+
+                let Ok(Some(sample_header)) = instrument_zone.sample_header() else {
+                    continue;
+                };
+
+                // ROM samples are not part of the file.
+                let Ok(sample_type) = sample_header.sample_type() else {
+                    continue;
+                };
+
+                if sample_type.is_rom() {
+                    continue;
+                }
+
+                let Ok(sample_data) = self.soundfont.sample_data(sample_header) else {
+                    continue;
+                };
+
+                let modulators = SynthModulators::new(
+                    &[
+                        preset_global_zone
+                            .as_ref()
+                            .map_or(&[][..], |zone| zone.modulators()),
+                        preset_zone.modulators(),
+                    ],
+                    &[
+                        instrument_global_zone
+                            .as_ref()
+                            .map_or(&[][..], |zone| zone.modulators()),
+                        instrument_zone.modulators(),
+                    ],
+                );
+
+                let generators = SynthGenerators::new(
+                    &[
+                        preset_global_zone
+                            .as_ref()
+                            .map_or(&[][..], |zone| zone.generators()),
+                        preset_zone.generators(),
+                    ],
+                    &[
+                        instrument_global_zone
+                            .as_ref()
+                            .map_or(&[][..], |zone| zone.generators()),
+                        instrument_zone.generators(),
+                    ],
+                );
+
+                new_voices.push(SynthVoice::new(
+                    channel,
+                    key,
+                    velocity,
+                    generators,
+                    modulators,
+                    &self.channels[channel].controllers,
+                    sample_header,
+                    sample_data,
+                    self.interpolation,
+                    self.sample_rate,
+                ));
+            }
+        }
+
+        // A note of an exclusive class terminates the sounding notes of the
+        // same class on its channel, like an open and a closed hi-hat.
+        for new_voice in &new_voices {
+            if new_voice.exclusive_class() != 0 {
+                for voice in &mut self.voices {
+                    if (voice.channel() == channel)
+                        && (voice.exclusive_class() == new_voice.exclusive_class())
+                    {
+                        voice.kill();
+                    }
+                }
+            }
+        }
+
+        self.voices.extend(new_voices);
+        Ok(())
+    }
+
+    pub fn note_off(&mut self, channel: usize, key: u8) -> SynthResult<()> {
+        Self::check_channel(channel)?;
+
+        let is_sustain_held = self.channels[channel].is_sustain_held;
+
+        for voice in &mut self.voices {
+            if (voice.channel() == channel) && (voice.key() == key) && !voice.is_released() {
+                if is_sustain_held {
+                    voice.sustain();
+                } else {
+                    voice.release();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn all_notes_off(&mut self, channel: usize) -> SynthResult<()> {
+        Self::check_channel(channel)?;
+
+        for voice in &mut self.voices {
+            if voice.channel() == channel {
+                voice.release();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Silences the voices of a channel immediately, skipping their release phase.
+    pub fn all_sound_off(&mut self, channel: usize) -> SynthResult<()> {
+        Self::check_channel(channel)?;
+
+        self.voices.retain(|voice| voice.channel() != channel);
+        Ok(())
+    }
+
+    /// Reevaluates the modulators of the voices of a channel after a change
+    /// of its controllers.
+    fn update_controllers(&mut self, channel: usize) {
+        let controllers = &self.channels[channel].controllers;
+
+        for voice in &mut self.voices {
+            if voice.channel() == channel {
+                voice.update_controllers(controllers);
+            }
+        }
+    }
+
+    /// Sets the pitch wheel position of a channel, from 0 to 16383 with
+    /// 8192 being the center position. The bend range is set by RPN 0,
+    /// two semitones by default.
+    pub fn pitch_bend(&mut self, channel: usize, pitch_bend: u16) -> SynthResult<()> {
+        Self::check_channel(channel)?;
+
+        self.channels[channel].controllers.pitch_wheel = pitch_bend.min(0x3FFF);
+        self.update_controllers(channel);
+        Ok(())
+    }
+
+    pub fn channel_pressure(&mut self, channel: usize, pressure: u8) -> SynthResult<()> {
+        Self::check_channel(channel)?;
+
+        self.channels[channel].controllers.channel_pressure = pressure & 0x7F;
+        self.update_controllers(channel);
+        Ok(())
+    }
+
+    pub fn key_pressure(&mut self, channel: usize, key: u8, pressure: u8) -> SynthResult<()> {
+        Self::check_channel(channel)?;
+
+        self.channels[channel].controllers.key_pressures[key as usize & 0x7F] = pressure & 0x7F;
+        self.update_controllers(channel);
+        Ok(())
+    }
+
+    /// Holds or releases the sustain pedal of a channel. Notes released while
+    /// the pedal is held keep sounding until the pedal gets released.
+    pub fn sustain(&mut self, channel: usize, is_sustain_held: bool) -> SynthResult<()> {
+        Self::check_channel(channel)?;
+
+        self.channels[channel].is_sustain_held = is_sustain_held;
+
+        if !is_sustain_held {
+            for voice in &mut self.voices {
+                if (voice.channel() == channel) && voice.is_sustained() {
+                    voice.release();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Handles the MIDI channel mode messages and the controllers the
+    /// synthesizer acts on, other controllers only feed the modulators.
+    pub fn control_change(&mut self, channel: usize, controller: u8, value: u8) -> SynthResult<()> {
+        Self::check_channel(channel)?;
+
+        if controller < 120 {
+            self.channels[channel]
+                .controllers
+                .set_controller(controller, value);
+            self.update_controllers(channel);
+        }
+
+        match controller {
+            controller::BANK_SELECT_MSB => {
+                self.channels[channel].bank_msb = value;
+                Ok(())
+            }
+            controller::BANK_SELECT_LSB => {
+                self.channels[channel].bank_lsb = value;
+                Ok(())
+            }
+            controller::SUSTAIN => self.sustain(channel, value >= 64),
+            controller::ALL_SOUND_OFF => self.all_sound_off(channel),
+            controller::RESET_ALL_CONTROLLERS => {
+                self.channels[channel].controllers.reset();
+                self.update_controllers(channel);
+                self.sustain(channel, false)
+            }
+            controller::ALL_NOTES_OFF => self.all_notes_off(channel),
+            _ => Ok(()),
+        }
+    }
+
+    /// Handles the channel messages the synthesizer acts on, any other
+    /// message is ignored.
+    pub fn process_message(&mut self, message: &MidiMessage) -> SynthResult<()> {
+        match *message {
+            MidiMessage::NoteOff { channel, key, .. } => self.note_off(channel as usize, key),
+            MidiMessage::NoteOn {
+                channel,
+                key,
+                velocity,
+            } => self.note_on(channel as usize, key, velocity),
+            MidiMessage::ControlChange {
+                channel,
+                controller,
+                value,
+            } => self.control_change(channel as usize, controller, value),
+            MidiMessage::ProgramChange { channel, program } => {
+                self.program_change(channel as usize, program)
+            }
+            MidiMessage::PolyPressure {
+                channel,
+                key,
+                pressure,
+            } => self.key_pressure(channel as usize, key, pressure),
+            MidiMessage::ChannelPressure { channel, pressure } => {
+                self.channel_pressure(channel as usize, pressure)
+            }
+            MidiMessage::PitchBend { channel, value } => self.pitch_bend(channel as usize, value),
+            _ => Ok(()),
+        }
+    }
+
+    /// Renders into an interleaved stereo buffer, overwriting its contents.
+    pub fn render(&mut self, output: &mut [f32]) {
+        output.fill(0.0);
+
+        let frame_count = output.len() / 2;
+
+        self.reverb_input.clear();
+        self.reverb_input.resize(frame_count, 0.0);
+        self.chorus_input.clear();
+        self.chorus_input.resize(frame_count, 0.0);
+
+        for voice in &mut self.voices {
+            voice.render(output, &mut self.reverb_input, &mut self.chorus_input);
+        }
+
+        self.voices.retain(|voice| !voice.is_finished());
+
+        if self.is_chorus_enabled {
+            self.chorus.process(&self.chorus_input, output);
+        }
+
+        if self.is_reverb_enabled {
+            self.reverb.process(&self.reverb_input, output);
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use std::f64::consts::PI;
+
+/// Shortest delay of the chorus voices, in milliseconds.
+const BASE_DELAY: f64 = 5.0;
+
+/// Upper limit of the modulation depth, in milliseconds.
+const MAX_DEPTH: f64 = 20.0;
+
+const MAX_VOICE_COUNT: usize = 16;
+
+/// Parameters of the chorus.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SynthChorusParameters {
+    /// Number of delayed copies of the input, up to 16, spread across the
+    /// stereo field.
+    pub voice_count: usize,
+    /// Output level of the chorus.
+    pub level: f32,
+    /// Modulation frequency in hertz.
+    pub rate: f32,
+    /// Modulation depth in milliseconds, up to 20.
+    pub depth: f32,
+}
+
+impl Default for SynthChorusParameters {
+    fn default() -> Self {
+        SynthChorusParameters {
+            voice_count: 3,
+            level: 1.0,
+            rate: 0.3,
+            depth: 8.0,
+        }
+    }
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+/// A stereo chorus mixing delayed copies of its mono input, the delay of
+/// each copy swept by a sine LFO of its own phase.
+#[derive(Debug, Clone)]
+pub struct SynthChorus {
+    parameters: SynthChorusParameters,
+    sample_rate: u32,
+
+    delay_line: Vec<f32>,
+    write_index: usize,
+    // LFO phase in cycles.
+    phase: f64,
+
+    silent_frames: usize,
+}
+
+impl SynthChorus {
+    pub fn new(sample_rate: u32, parameters: SynthChorusParameters) -> SynthChorus {
+        let delay_line_length =
+            ((BASE_DELAY + MAX_DEPTH) * sample_rate as f64 / 1000.0).ceil() as usize + 2;
+
+        SynthChorus {
+            parameters,
+            sample_rate,
+
+            delay_line: vec![0.0; delay_line_length],
+            write_index: 0,
+            phase: 0.0,
+
+            silent_frames: usize::MAX,
+        }
+    }
+
+    pub fn parameters(&self) -> SynthChorusParameters {
+        self.parameters
+    }
+
+    /// Changes the parameters, keeping the sound already in the chorus.
+    pub fn set_parameters(&mut self, parameters: SynthChorusParameters) {
+        self.parameters = parameters;
+    }
+
+    /// Whether the chorus is still sounding after its last input.
+    pub fn is_ringing(&self) -> bool {
+        self.silent_frames < self.delay_line.len()
+    }
+
+    fn delayed_sample(&self, delay: f64) -> f32 {
+        let length = self.delay_line.len();
+
+        let delay_index = delay as usize;
+        let fraction = (delay - delay_index as f64) as f32;
+
+        let sample_at =
+            |offset: usize| self.delay_line[(self.write_index + length - offset) % length];
+
+        sample_at(delay_index) * (1.0 - fraction) + sample_at(delay_index + 1) * fraction
+    }
+
+    /// Mixes the chorused mono input into an interleaved stereo buffer.
+    pub fn process(&mut self, input: &[f32], output: &mut [f32]) {
+        let SynthChorusParameters {
+            voice_count,
+            level,
+            rate,
+            depth,
+        } = self.parameters;
+
+        let voice_count = voice_count.clamp(1, MAX_VOICE_COUNT);
+        let gain = level.max(0.0) / voice_count as f32;
+
+        let frames_per_millisecond = self.sample_rate as f64 / 1000.0;
+        let base_delay = BASE_DELAY * frames_per_millisecond;
+        let depth = (depth as f64).clamp(0.0, MAX_DEPTH) * frames_per_millisecond;
+        let phase_increment = (rate as f64).max(0.0) / self.sample_rate as f64;
+
+        for (&input, frame) in input.iter().zip(output.chunks_exact_mut(2)) {
+            if input != 0.0 {
+                self.silent_frames = 0;
+            } else if self.is_ringing() {
+                self.silent_frames += 1;
+            } else {
+                self.phase = (self.phase + phase_increment).fract();
+                continue;
+            }
+
+            self.delay_line[self.write_index] = input;
+
+            for voice in 0..voice_count {
+                let voice_phase = self.phase + voice as f64 / voice_count as f64;
+                let modulation = 0.5 * (1.0 + f64::sin(2.0 * PI * voice_phase));
+                let sample = self.delayed_sample(base_delay + depth * modulation) * gain;
+
+                // Voices are spread evenly from left to right.
+                let pan = if voice_count > 1 {
+                    voice as f32 / (voice_count - 1) as f32
+                } else {
+                    0.5
+                };
+
+                frame[0] += sample * (1.0 - pan);
+                frame[1] += sample * pan;
+            }
+
+            self.write_index = (self.write_index + 1) % self.delay_line.len();
+            self.phase = (self.phase + phase_increment).fract();
+        }
+    }
+}
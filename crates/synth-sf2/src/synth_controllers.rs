@@ -0,0 +1,81 @@
+use midi_defs::{controller, rpn};
+
+/// The MIDI controller state of a channel, the inputs of the modulators.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SynthControllers {
+    pub controllers: [u8; 128],
+    pub key_pressures: [u8; 128],
+    pub channel_pressure: u8,
+    pub pitch_wheel: u16,
+    /// Pitch wheel range in semitones, set through RPN 0.
+    pub pitch_wheel_sensitivity: u8,
+}
+
+impl Default for SynthControllers {
+    fn default() -> Self {
+        let mut controllers = SynthControllers {
+            controllers: [0; 128],
+            key_pressures: [0; 128],
+            channel_pressure: 0,
+            pitch_wheel: 0x2000,
+            pitch_wheel_sensitivity: 2,
+        };
+
+        controllers.controllers[controller::CHANNEL_VOLUME_MSB as usize] = 100;
+        controllers.controllers[controller::PAN_MSB as usize] = 64;
+        controllers.reset();
+        controllers
+    }
+}
+
+impl SynthControllers {
+    /// Resets the controllers affected by the Reset All Controllers message,
+    /// leaving volume, pan, bank select and the effect sends untouched.
+    pub fn reset(&mut self) {
+        for controller in [
+            controller::MODULATION_WHEEL_MSB,
+            controller::MODULATION_WHEEL_LSB,
+            controller::EXPRESSION_LSB,
+            controller::SUSTAIN,
+            controller::PORTAMENTO,
+            controller::SOSTENUTO,
+            controller::SOFT_PEDAL,
+        ] {
+            self.controllers[controller as usize] = 0;
+        }
+
+        self.controllers[controller::EXPRESSION_MSB as usize] = 127;
+        self.controllers[controller::NRPN_LSB as usize] = 127;
+        self.controllers[controller::NRPN_MSB as usize] = 127;
+        self.controllers[controller::RPN_LSB as usize] = 127;
+        self.controllers[controller::RPN_MSB as usize] = 127;
+
+        self.key_pressures = [0; 128];
+        self.channel_pressure = 0;
+        self.pitch_wheel = 0x2000;
+    }
+
+    /// The registered parameter selected for data entry.
+    pub fn rpn(&self) -> u16 {
+        ((self.controllers[controller::RPN_MSB as usize] as u16) << 7)
+            | self.controllers[controller::RPN_LSB as usize] as u16
+    }
+
+    /// Stores a controller value, handling the data entry of the registered
+    /// parameters the modulators rely on.
+    pub fn set_controller(&mut self, controller: u8, value: u8) {
+        self.controllers[controller as usize & 0x7F] = value;
+
+        match controller {
+            // Selecting a non-registered parameter deselects the registered one.
+            controller::NRPN_LSB | controller::NRPN_MSB => {
+                self.controllers[controller::RPN_LSB as usize] = 127;
+                self.controllers[controller::RPN_MSB as usize] = 127;
+            }
+            controller::DATA_ENTRY_MSB if self.rpn() == rpn::PITCH_BEND_SENSITIVITY => {
+                self.pitch_wheel_sensitivity = value;
+            }
+            _ => {}
+        }
+    }
+}
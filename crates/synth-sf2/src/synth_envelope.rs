@@ -0,0 +1,280 @@
+use parser_sf2::Sf2GeneratorKind;
+
+use crate::SynthGenerators;
+
+/// Converts a time in timecents into seconds. The most negative value,
+/// -32768, conventionally stands for no time at all.
+pub fn timecents_to_seconds(timecents: i32) -> f64 {
+    if timecents <= -32768 {
+        0.0
+    } else {
+        f64::powf(2.0, timecents as f64 / 1200.0)
+    }
+}
+
+/// Attenuation of the volume envelope at its lowest level, in centibels.
+const VOLUME_ENVELOPE_RANGE: f64 = 960.0;
+
+/// The envelopes defined by the SoundFont 2.04 specification.
+///
+/// The volume envelope drives the amplitude of a voice, its attack is linear
+/// in amplitude while its decay and release are linear in decibels. The
+/// modulation envelope is linear in all of its stages, its output is
+/// scaled by the generators routing it into pitch and filter cutoff.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SynthEnvelopeKind {
+    Volume,
+    Modulation,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SynthEnvelopeStage {
+    Delay,
+    Attack,
+    Hold,
+    Decay,
+    Sustain,
+    Release,
+    Finished,
+}
+
+/// A sample-accurate DAHDSR envelope.
+///
+/// The envelope works on a normalized level, 1.0 being its peak and 0.0 its
+/// silence. The decay and release durations are the times of a full sweep
+/// between these two, a decay to a higher sustain level takes proportionally
+/// less time.
+#[derive(Debug, Clone)]
+pub struct SynthEnvelope {
+    kind: SynthEnvelopeKind,
+
+    // Durations in sample frames.
+    delay_length: f64,
+    attack_length: f64,
+    hold_length: f64,
+    decay_length: f64,
+    release_length: f64,
+    sustain_level: f64,
+
+    stage: SynthEnvelopeStage,
+    stage_position: usize,
+    stage_start_level: f64,
+    level: f64,
+}
+
+/// Ramps linearly over the given length, returning `f64::INFINITY` for
+/// zero-length ramps that are immediately over.
+fn ramp(position: usize, length: f64) -> f64 {
+    if length > 0.0 {
+        position as f64 / length
+    } else {
+        f64::INFINITY
+    }
+}
+
+impl SynthEnvelope {
+    /// The volume envelope of a voice playing the given key, from
+    /// generators 33–40.
+    pub fn volume(generators: &SynthGenerators, key: u8, sample_rate: u32) -> SynthEnvelope {
+        let sustain = generators
+            .get(Sf2GeneratorKind::SustainVolEnv)
+            .clamp(0, 1440);
+
+        SynthEnvelope::new(
+            SynthEnvelopeKind::Volume,
+            generators,
+            [
+                Sf2GeneratorKind::DelayVolEnv,
+                Sf2GeneratorKind::AttackVolEnv,
+                Sf2GeneratorKind::HoldVolEnv,
+                Sf2GeneratorKind::DecayVolEnv,
+                Sf2GeneratorKind::ReleaseVolEnv,
+                Sf2GeneratorKind::KeynumToVolEnvHold,
+                Sf2GeneratorKind::KeynumToVolEnvDecay,
+            ],
+            1.0 - sustain as f64 / VOLUME_ENVELOPE_RANGE,
+            key,
+            sample_rate,
+        )
+    }
+
+    /// The modulation envelope of a voice playing the given key, from
+    /// generators 25–32.
+    pub fn modulation(generators: &SynthGenerators, key: u8, sample_rate: u32) -> SynthEnvelope {
+        let sustain = generators
+            .get(Sf2GeneratorKind::SustainModEnv)
+            .clamp(0, 1000);
+
+        SynthEnvelope::new(
+            SynthEnvelopeKind::Modulation,
+            generators,
+            [
+                Sf2GeneratorKind::DelayModEnv,
+                Sf2GeneratorKind::AttackModEnv,
+                Sf2GeneratorKind::HoldModEnv,
+                Sf2GeneratorKind::DecayModEnv,
+                Sf2GeneratorKind::ReleaseModEnv,
+                Sf2GeneratorKind::KeynumToModEnvHold,
+                Sf2GeneratorKind::KeynumToModEnvDecay,
+            ],
+            1.0 - sustain as f64 / 1000.0,
+            key,
+            sample_rate,
+        )
+    }
+
+    fn new(
+        kind: SynthEnvelopeKind,
+        generators: &SynthGenerators,
+        generator_kinds: [Sf2GeneratorKind; 7],
+        sustain_level: f64,
+        key: u8,
+        sample_rate: u32,
+    ) -> SynthEnvelope {
+        let [delay, attack, hold, decay, release, keynum_to_hold, keynum_to_decay] =
+            generator_kinds;
+
+        let length = |generator_kind: Sf2GeneratorKind, max_timecents: i32, key_scaling: i32| {
+            let timecents = match generators.get(generator_kind) {
+                timecents @ ..=-32768 => timecents,
+                timecents => (timecents + key_scaling).clamp(-12000, max_timecents),
+            };
+
+            timecents_to_seconds(timecents) * sample_rate as f64
+        };
+
+        // Hold and decay times are scaled relative to middle C.
+        let key_offset = 60 - key as i32;
+
+        let mut envelope = SynthEnvelope {
+            kind,
+
+            delay_length: length(delay, 5000, 0).round(),
+            attack_length: length(attack, 8000, 0),
+            hold_length: length(hold, 5000, generators.get(keynum_to_hold) * key_offset).round(),
+            decay_length: length(decay, 8000, generators.get(keynum_to_decay) * key_offset),
+            release_length: length(release, 8000, 0),
+            sustain_level: sustain_level.max(0.0),
+
+            stage: SynthEnvelopeStage::Delay,
+            stage_position: 0,
+            stage_start_level: 0.0,
+            level: 0.0,
+        };
+
+        envelope.update();
+        envelope
+    }
+
+    pub fn kind(&self) -> SynthEnvelopeKind {
+        self.kind
+    }
+
+    pub fn stage(&self) -> SynthEnvelopeStage {
+        self.stage
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.stage == SynthEnvelopeStage::Finished
+    }
+
+    fn enter_stage(&mut self, stage: SynthEnvelopeStage, level: f64) {
+        self.stage = stage;
+        self.stage_position = 0;
+        self.stage_start_level = level;
+        self.level = level;
+    }
+
+    /// Moves past the stages which are over at the current position.
+    fn update(&mut self) {
+        loop {
+            match self.stage {
+                SynthEnvelopeStage::Delay => {
+                    if (self.stage_position as f64) < self.delay_length {
+                        return;
+                    }
+                    self.enter_stage(SynthEnvelopeStage::Attack, 0.0);
+                }
+                SynthEnvelopeStage::Attack => {
+                    let level = ramp(self.stage_position, self.attack_length);
+                    if level < 1.0 {
+                        self.level = level;
+                        return;
+                    }
+                    self.enter_stage(SynthEnvelopeStage::Hold, 1.0);
+                }
+                SynthEnvelopeStage::Hold => {
+                    if (self.stage_position as f64) < self.hold_length {
+                        return;
+                    }
+                    self.enter_stage(SynthEnvelopeStage::Decay, 1.0);
+                }
+                SynthEnvelopeStage::Decay => {
+                    let level = 1.0 - ramp(self.stage_position, self.decay_length);
+                    if level > self.sustain_level {
+                        self.level = level;
+                        return;
+                    }
+                    self.enter_stage(SynthEnvelopeStage::Sustain, self.sustain_level);
+                }
+                SynthEnvelopeStage::Sustain => {
+                    // A fully attenuated sustain is silent for good.
+                    if (self.kind == SynthEnvelopeKind::Volume) && (self.level <= 0.0) {
+                        self.enter_stage(SynthEnvelopeStage::Finished, 0.0);
+                    }
+                    return;
+                }
+                SynthEnvelopeStage::Release => {
+                    let level =
+                        self.stage_start_level - ramp(self.stage_position, self.release_length);
+                    if level > 0.0 {
+                        self.level = level;
+                        return;
+                    }
+                    self.enter_stage(SynthEnvelopeStage::Finished, 0.0);
+                }
+                SynthEnvelopeStage::Finished => return,
+            }
+        }
+    }
+
+    /// The current output of the envelope: a gain factor for the volume
+    /// envelope, the normalized level for the modulation envelope.
+    pub fn value(&self) -> f32 {
+        match (self.kind, self.stage) {
+            (_, SynthEnvelopeStage::Delay | SynthEnvelopeStage::Finished) => 0.0,
+            (SynthEnvelopeKind::Volume, SynthEnvelopeStage::Attack) => self.level as f32,
+            (SynthEnvelopeKind::Volume, _) => {
+                f64::powf(10.0, -(1.0 - self.level) * VOLUME_ENVELOPE_RANGE / 200.0) as f32
+            }
+            (SynthEnvelopeKind::Modulation, _) => self.level as f32,
+        }
+    }
+
+    /// Returns the output for the current sample frame, then advances the
+    /// envelope by one frame.
+    pub fn next_value(&mut self) -> f32 {
+        let value = self.value();
+
+        self.stage_position += 1;
+        self.update();
+
+        value
+    }
+
+    /// Enters the release stage, starting from the current level.
+    pub fn release(&mut self) {
+        let level = match (self.kind, self.stage) {
+            (_, SynthEnvelopeStage::Release | SynthEnvelopeStage::Finished) => return,
+            (_, SynthEnvelopeStage::Delay) => 0.0,
+            // The release continues from the same amplitude, in decibels.
+            (SynthEnvelopeKind::Volume, SynthEnvelopeStage::Attack) => {
+                1.0 + f64::log10(self.level) * 200.0 / VOLUME_ENVELOPE_RANGE
+            }
+            _ => self.level,
+        };
+
+        self.enter_stage(SynthEnvelopeStage::Release, level);
+        self.update();
+    }
+}
@@ -0,0 +1,135 @@
+use std::f64::consts::PI;
+
+use crate::absolute_cents_to_hertz;
+
+/// Number of sample frames between filter coefficient updates, the
+/// coefficients are interpolated across them.
+pub const FILTER_CONTROL_PERIOD: usize = 64;
+
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+struct Coefficients {
+    b0: f64,
+    b1: f64,
+    b2: f64,
+    a1: f64,
+    a2: f64,
+}
+
+impl Coefficients {
+    fn low_pass(cutoff: f64, resonance: f64, sample_rate: u32) -> Coefficients {
+        // Cutoff in absolute cents, resonance in centibels.
+        let frequency = absolute_cents_to_hertz(cutoff.clamp(1500.0, 13500.0).round() as i32)
+            .min(sample_rate as f64 * 0.45);
+
+        // The gain of the filter at its cutoff frequency equals its quality
+        // factor, which is the height of the resonance peak above DC gain.
+        let q = f64::powf(10.0, resonance.clamp(0.0, 960.0) / 200.0);
+
+        let omega = 2.0 * PI * frequency / sample_rate as f64;
+        let alpha = omega.sin() / (2.0 * q);
+        let cos_omega = omega.cos();
+        let a0 = 1.0 + alpha;
+
+        Coefficients {
+            b0: (1.0 - cos_omega) / 2.0 / a0,
+            b1: (1.0 - cos_omega) / a0,
+            b2: (1.0 - cos_omega) / 2.0 / a0,
+            a1: -2.0 * cos_omega / a0,
+            a2: (1.0 - alpha) / a0,
+        }
+    }
+
+    fn step_towards(&self, target: &Coefficients, steps: usize) -> Coefficients {
+        let step = |current: f64, target: f64| (target - current) / steps as f64;
+
+        Coefficients {
+            b0: step(self.b0, target.b0),
+            b1: step(self.b1, target.b1),
+            b2: step(self.b2, target.b2),
+            a1: step(self.a1, target.a1),
+            a2: step(self.a2, target.a2),
+        }
+    }
+
+    fn add(&mut self, delta: &Coefficients) {
+        self.b0 += delta.b0;
+        self.b1 += delta.b1;
+        self.b2 += delta.b2;
+        self.a1 += delta.a1;
+        self.a2 += delta.a2;
+    }
+}
+
+/// The two-pole resonant low-pass filter of the SoundFont 2.04 specification.
+///
+/// Its cutoff frequency is given in absolute cents by `initialFilterFc`,
+/// its resonance in centibels above the DC gain by `initialFilterQ`.
+#[derive(Debug, Clone)]
+pub struct SynthFilter {
+    sample_rate: u32,
+
+    coefficients: Coefficients,
+    coefficient_deltas: Coefficients,
+    remaining_steps: usize,
+    is_initialized: bool,
+
+    x1: f64,
+    x2: f64,
+    y1: f64,
+    y2: f64,
+}
+
+impl SynthFilter {
+    pub fn new(sample_rate: u32) -> SynthFilter {
+        SynthFilter {
+            sample_rate,
+
+            coefficients: Coefficients::default(),
+            coefficient_deltas: Coefficients::default(),
+            remaining_steps: 0,
+            is_initialized: false,
+
+            x1: 0.0,
+            x2: 0.0,
+            y1: 0.0,
+            y2: 0.0,
+        }
+    }
+
+    /// Sets the cutoff frequency in absolute cents and the resonance in
+    /// centibels. Apart from the first call, the filter moves to the new
+    /// parameters gradually over the next `FILTER_CONTROL_PERIOD` frames.
+    pub fn set_parameters(&mut self, cutoff: f64, resonance: f64) {
+        let target = Coefficients::low_pass(cutoff, resonance, self.sample_rate);
+
+        if self.is_initialized {
+            self.coefficient_deltas = self
+                .coefficients
+                .step_towards(&target, FILTER_CONTROL_PERIOD);
+            self.remaining_steps = FILTER_CONTROL_PERIOD;
+        } else {
+            self.coefficients = target;
+            self.remaining_steps = 0;
+            self.is_initialized = true;
+        }
+    }
+
+    pub fn process(&mut self, input: f32) -> f32 {
+        if self.remaining_steps > 0 {
+            self.coefficients.add(&self.coefficient_deltas);
+            self.remaining_steps -= 1;
+        }
+
+        let Coefficients { b0, b1, b2, a1, a2 } = self.coefficients;
+
+        let x0 = input as f64;
+        let y0 = b0 * x0 + b1 * self.x1 + b2 * self.x2 - a1 * self.y1 - a2 * self.y2;
+
+        self.x2 = self.x1;
+        self.x1 = x0;
+        self.y2 = self.y1;
+        self.y1 = y0;
+
+        y0 as f32
+    }
+}
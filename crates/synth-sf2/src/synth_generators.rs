@@ -0,0 +1,116 @@
+use parser_sf2::{Sf2Generator, Sf2GeneratorAmount, Sf2GeneratorKind};
+use strum::IntoEnumIterator;
+
+pub(crate) const GENERATOR_COUNT: usize = Sf2GeneratorKind::EndOper as usize + 1;
+
+fn amount_value(amount: Sf2GeneratorAmount) -> i32 {
+    match amount {
+        Sf2GeneratorAmount::Signed(amount) => amount as i32,
+        Sf2GeneratorAmount::Unsigned(amount) => amount as i32,
+        Sf2GeneratorAmount::Range { low, high } => u16::from_le_bytes([low, high]) as i32,
+    }
+}
+
+/// Generators which are only valid at the instrument level, preset-level
+/// occurrences of them must be ignored.
+fn is_instrument_only(generator_kind: Sf2GeneratorKind) -> bool {
+    matches!(
+        generator_kind,
+        Sf2GeneratorKind::StartAddrsOffset
+            | Sf2GeneratorKind::EndAddrsOffset
+            | Sf2GeneratorKind::StartloopAddrsOffset
+            | Sf2GeneratorKind::EndloopAddrsOffset
+            | Sf2GeneratorKind::StartAddrsCoarseOffset
+            | Sf2GeneratorKind::EndAddrsCoarseOffset
+            | Sf2GeneratorKind::StartloopAddrsCoarseOffset
+            | Sf2GeneratorKind::EndloopAddrsCoarseOffset
+            | Sf2GeneratorKind::Keynum
+            | Sf2GeneratorKind::Velocity
+            | Sf2GeneratorKind::SampleModes
+            | Sf2GeneratorKind::ExclusiveClass
+            | Sf2GeneratorKind::OverridingRootKey
+    )
+}
+
+/// Generators which are not summed across the preset and instrument levels,
+/// as they select zones, instruments and samples instead of shaping the sound.
+fn is_non_additive(generator_kind: Sf2GeneratorKind) -> bool {
+    matches!(
+        generator_kind,
+        Sf2GeneratorKind::Instrument
+            | Sf2GeneratorKind::SampleId
+            | Sf2GeneratorKind::KeyRange
+            | Sf2GeneratorKind::VelRange
+    )
+}
+
+/// The effective generator values of a voice.
+///
+/// Built following section 9.4 of the SoundFont 2.04 specification: the
+/// instrument-level generators override the defaults (local zones overriding
+/// the global zone), then the preset-level generators get added on top of
+/// them as offsets (local zones again overriding the global zone).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SynthGenerators {
+    amounts: [i32; GENERATOR_COUNT],
+}
+
+impl Default for SynthGenerators {
+    fn default() -> Self {
+        let mut amounts = [0; GENERATOR_COUNT];
+
+        for generator_kind in Sf2GeneratorKind::iter() {
+            amounts[generator_kind as usize] = amount_value(generator_kind.default_amount());
+        }
+
+        SynthGenerators { amounts }
+    }
+}
+
+impl SynthGenerators {
+    pub fn new<PG: Sf2Generator, IG: Sf2Generator>(
+        preset_generators: &[&[PG]],
+        instrument_generators: &[&[IG]],
+    ) -> SynthGenerators {
+        let mut synth_generators = SynthGenerators::default();
+
+        for generators in instrument_generators {
+            for generator in generators.iter() {
+                if let Ok(generator_kind) = generator.generator_kind() {
+                    synth_generators.set(
+                        generator_kind,
+                        amount_value(generator_kind.decode_amount(generator.raw_amount())),
+                    );
+                }
+            }
+        }
+
+        let mut preset_offsets = [None; GENERATOR_COUNT];
+
+        for generators in preset_generators {
+            for generator in generators.iter() {
+                if let Ok(generator_kind) = generator.generator_kind() {
+                    if !is_instrument_only(generator_kind) && !is_non_additive(generator_kind) {
+                        preset_offsets[generator_kind as usize] = Some(amount_value(
+                            generator_kind.decode_amount(generator.raw_amount()),
+                        ));
+                    }
+                }
+            }
+        }
+
+        for (amount, preset_offset) in synth_generators.amounts.iter_mut().zip(preset_offsets) {
+            *amount += preset_offset.unwrap_or(0);
+        }
+
+        synth_generators
+    }
+
+    pub fn get(&self, generator_kind: Sf2GeneratorKind) -> i32 {
+        self.amounts[generator_kind as usize]
+    }
+
+    pub fn set(&mut self, generator_kind: Sf2GeneratorKind, amount: i32) {
+        self.amounts[generator_kind as usize] = amount;
+    }
+}
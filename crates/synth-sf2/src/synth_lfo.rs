@@ -0,0 +1,80 @@
+use parser_sf2::Sf2GeneratorKind;
+
+use crate::{timecents_to_seconds, SynthGenerators};
+
+/// Converts a frequency in absolute cents into hertz, 0 cents being the
+/// frequency of MIDI key 0.
+pub fn absolute_cents_to_hertz(absolute_cents: i32) -> f64 {
+    8.176 * f64::powf(2.0, absolute_cents as f64 / 1200.0)
+}
+
+/// A triangle low-frequency oscillator of the SoundFont 2.04 specification.
+///
+/// After its delay it starts from zero and rises first, swinging between
+/// -1.0 and 1.0. The modulation LFO can be routed into pitch, filter cutoff
+/// and volume, the vibrato LFO into pitch only.
+#[derive(Debug, Clone)]
+pub struct SynthLfo {
+    delay_length: usize,
+    // Cycles per sample frame.
+    frequency: f64,
+    position: usize,
+}
+
+impl SynthLfo {
+    /// The modulation LFO of a voice, from generators 21–22.
+    pub fn modulation(generators: &SynthGenerators, sample_rate: u32) -> SynthLfo {
+        SynthLfo::new(
+            generators.get(Sf2GeneratorKind::DelayModLfo),
+            generators.get(Sf2GeneratorKind::FreqModLfo),
+            sample_rate,
+        )
+    }
+
+    /// The vibrato LFO of a voice, from generators 23–24.
+    pub fn vibrato(generators: &SynthGenerators, sample_rate: u32) -> SynthLfo {
+        SynthLfo::new(
+            generators.get(Sf2GeneratorKind::DelayVibLfo),
+            generators.get(Sf2GeneratorKind::FreqVibLfo),
+            sample_rate,
+        )
+    }
+
+    fn new(delay: i32, frequency: i32, sample_rate: u32) -> SynthLfo {
+        let delay = match delay {
+            delay @ ..=-32768 => delay,
+            delay => delay.clamp(-12000, 5000),
+        };
+
+        SynthLfo {
+            delay_length: (timecents_to_seconds(delay) * sample_rate as f64).round() as usize,
+            frequency: absolute_cents_to_hertz(frequency.clamp(-16000, 4500)) / sample_rate as f64,
+            position: 0,
+        }
+    }
+
+    /// The output for the current sample frame.
+    pub fn value(&self) -> f32 {
+        let Some(position) = self.position.checked_sub(self.delay_length) else {
+            return 0.0;
+        };
+
+        let phase = (position as f64 * self.frequency).fract();
+
+        (if phase < 0.25 {
+            4.0 * phase
+        } else if phase < 0.75 {
+            2.0 - 4.0 * phase
+        } else {
+            4.0 * phase - 4.0
+        }) as f32
+    }
+
+    /// Returns the output for the current sample frame, then advances the
+    /// oscillator by one frame.
+    pub fn next_value(&mut self) -> f32 {
+        let value = self.value();
+        self.position += 1;
+        value
+    }
+}
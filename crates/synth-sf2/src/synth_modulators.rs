@@ -0,0 +1,325 @@
+use parser_sf2::{
+    Sf2ControllerPalette, Sf2GeneralController, Sf2GeneratorKind, Sf2Modulator,
+    Sf2ModulatorDestination, Sf2ModulatorSource, Sf2ModulatorTransform, Sf2SourceCurveType,
+    Sf2SourceDirection, Sf2SourcePolarity,
+};
+use strum::IntoEnumIterator;
+
+use crate::synth_generators::GENERATOR_COUNT;
+use crate::{SynthControllers, SynthGenerators};
+
+/// MIDI controllers which are not allowed as modulator sources: bank select,
+/// data entry, the parameter number selectors and the channel mode messages.
+fn is_valid_midi_source(index: u8) -> bool {
+    !matches!(index, 0 | 6 | 32 | 38 | 98..=101 | 120..=127)
+}
+
+fn is_valid_source(source: &Sf2ModulatorSource) -> bool {
+    match source.controller_palette {
+        Sf2ControllerPalette::General => !matches!(
+            source.general_controller(),
+            None | Some(Sf2GeneralController::Link)
+        ),
+        Sf2ControllerPalette::Midi => is_valid_midi_source(source.index),
+    }
+}
+
+/// The concave curve, spanning 96 dB of attenuation over the unit range.
+fn concave(x: f64) -> f64 {
+    if x >= 1.0 {
+        1.0
+    } else {
+        (-5.0 / 12.0 * f64::log10(1.0 - x)).clamp(0.0, 1.0)
+    }
+}
+
+fn convex(x: f64) -> f64 {
+    1.0 - concave(1.0 - x)
+}
+
+/// Maps a controller value through the direction, polarity and curve type of
+/// a modulator source. The linear and switch curves span the whole range of
+/// the controller, the concave and convex curves reach their ends at its last
+/// value, so a velocity of 127 is free of attenuation.
+fn map_source(source: &Sf2ModulatorSource, value: u16, range: u16) -> f64 {
+    let x = match source.curve_type {
+        Sf2SourceCurveType::Linear | Sf2SourceCurveType::Switch => value as f64 / range as f64,
+        Sf2SourceCurveType::Concave | Sf2SourceCurveType::Convex => {
+            (value as f64 / (range - 1) as f64).min(1.0)
+        }
+    };
+
+    // The switch curve turns on at the center of the range; its negative
+    // direction is mirrored on the raw value, keeping the center in the
+    // upper half.
+    if source.curve_type == Sf2SourceCurveType::Switch {
+        let is_on = (x >= 0.5) == (source.direction == Sf2SourceDirection::Positive);
+        return match (source.polarity, is_on) {
+            (_, true) => 1.0,
+            (Sf2SourcePolarity::Unipolar, false) => 0.0,
+            (Sf2SourcePolarity::Bipolar, false) => -1.0,
+        };
+    }
+
+    let x = match source.direction {
+        Sf2SourceDirection::Positive => x,
+        Sf2SourceDirection::Negative => 1.0 - x,
+    };
+
+    let curve = |x: f64| match source.curve_type {
+        Sf2SourceCurveType::Linear | Sf2SourceCurveType::Switch => x,
+        Sf2SourceCurveType::Concave => concave(x),
+        Sf2SourceCurveType::Convex => convex(x),
+    };
+
+    match source.polarity {
+        Sf2SourcePolarity::Unipolar => curve(x),
+        // Bipolar curves are mirrored around the center of the range.
+        Sf2SourcePolarity::Bipolar => {
+            if x >= 0.5 {
+                curve(2.0 * x - 1.0)
+            } else {
+                -curve(1.0 - 2.0 * x)
+            }
+        }
+    }
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+/// A modulator routing a controller into a generator.
+///
+/// Linked modulators, having another modulator as their destination or
+/// source, are not supported.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SynthModulator {
+    pub source: Sf2ModulatorSource,
+    pub destination: Sf2GeneratorKind,
+    pub amount: i16,
+    pub amount_source: Sf2ModulatorSource,
+    pub transform: Sf2ModulatorTransform,
+}
+
+impl SynthModulator {
+    /// Decodes a modulator from its `sfModList` fields. Unknown or invalid
+    /// fields make the whole modulator ignored, as the specification requires.
+    pub fn from_raw(
+        raw_source: u16,
+        raw_destination: u16,
+        amount: i16,
+        raw_amount_source: u16,
+        raw_transform: u16,
+    ) -> Option<SynthModulator> {
+        let source = Sf2ModulatorSource::from_raw(raw_source).ok()?;
+        let amount_source = Sf2ModulatorSource::from_raw(raw_amount_source).ok()?;
+
+        let Ok(Sf2ModulatorDestination::Generator(destination)) =
+            Sf2ModulatorDestination::from_raw(raw_destination)
+        else {
+            return None;
+        };
+
+        if !is_valid_source(&source) || !is_valid_source(&amount_source) {
+            return None;
+        }
+
+        Some(SynthModulator {
+            source,
+            destination,
+            amount,
+            amount_source,
+            transform: Sf2ModulatorTransform::from_raw(raw_transform).ok()?,
+        })
+    }
+
+    pub fn from_sf2<M: Sf2Modulator>(modulator: &M) -> Option<SynthModulator> {
+        SynthModulator::from_raw(
+            modulator.raw_source(),
+            modulator.raw_destination(),
+            modulator.amount(),
+            modulator.raw_amount_source(),
+            modulator.raw_transform(),
+        )
+    }
+
+    /// Modulators are identical when they only differ in their amount, in
+    /// which case one overrides the other.
+    pub fn is_identical(&self, other: &SynthModulator) -> bool {
+        (self.source == other.source)
+            && (self.destination == other.destination)
+            && (self.amount_source == other.amount_source)
+    }
+
+    fn source_value(
+        source: &Sf2ModulatorSource,
+        controllers: &SynthControllers,
+        key: u8,
+        velocity: u8,
+    ) -> f64 {
+        let (value, range) = match source.controller_palette {
+            Sf2ControllerPalette::Midi => {
+                (controllers.controllers[source.index as usize] as u16, 128)
+            }
+            Sf2ControllerPalette::General => match source.general_controller() {
+                Some(Sf2GeneralController::NoteOnVelocity) => (velocity as u16, 128),
+                Some(Sf2GeneralController::NoteOnKeyNumber) => (key as u16, 128),
+                Some(Sf2GeneralController::PolyPressure) => {
+                    (controllers.key_pressures[key as usize & 0x7F] as u16, 128)
+                }
+                Some(Sf2GeneralController::ChannelPressure) => {
+                    (controllers.channel_pressure as u16, 128)
+                }
+                Some(Sf2GeneralController::PitchWheel) => (controllers.pitch_wheel, 16384),
+                // Normalized by 127 rather than 128, so the default pitch
+                // wheel modulator amount of 12700 cents bends by exactly
+                // 100 cents per semitone of sensitivity.
+                Some(Sf2GeneralController::PitchWheelSensitivity) => {
+                    (controllers.pitch_wheel_sensitivity as u16, 127)
+                }
+                // Without a controller the source is taken as a constant 1.
+                _ => return 1.0,
+            },
+        };
+
+        map_source(source, value, range)
+    }
+
+    /// The output of the modulator, in the units of its destination.
+    pub fn value(&self, controllers: &SynthControllers, key: u8, velocity: u8) -> f64 {
+        let value = self.amount as f64
+            * Self::source_value(&self.source, controllers, key, velocity)
+            * Self::source_value(&self.amount_source, controllers, key, velocity);
+
+        match self.transform {
+            Sf2ModulatorTransform::Linear => value,
+            Sf2ModulatorTransform::AbsoluteValue => value.abs(),
+        }
+    }
+}
+
+/// The default modulators of section 8.4 of the SoundFont 2.04 specification,
+/// present on every voice unless overridden.
+pub fn default_modulators() -> Vec<SynthModulator> {
+    #[rustfmt::skip]
+    let default_modulators = [
+        (0x0502, Sf2GeneratorKind::InitialAttenuation,    960, 0x0000), // Velocity to attenuation
+        (0x0102, Sf2GeneratorKind::InitialFilterFc,     -2400, 0x0000), // Velocity to filter cutoff
+        (0x000D, Sf2GeneratorKind::VibLfoToPitch,          50, 0x0000), // Channel pressure to vibrato
+        (0x0081, Sf2GeneratorKind::VibLfoToPitch,          50, 0x0000), // Modulation wheel to vibrato
+        (0x0587, Sf2GeneratorKind::InitialAttenuation,    960, 0x0000), // Volume to attenuation
+        (0x028A, Sf2GeneratorKind::Pan,                  1000, 0x0000), // Pan to pan
+        (0x058B, Sf2GeneratorKind::InitialAttenuation,    960, 0x0000), // Expression to attenuation
+        (0x00DB, Sf2GeneratorKind::ReverbEffectsSend,     200, 0x0000), // Reverb send level
+        (0x00DD, Sf2GeneratorKind::ChorusEffectsSend,     200, 0x0000), // Chorus send level
+        (0x020E, Sf2GeneratorKind::FineTune,            12700, 0x0010), // Pitch wheel to pitch
+    ];
+
+    default_modulators
+        .into_iter()
+        .map(|(source, destination, amount, amount_source)| {
+            SynthModulator::from_raw(source, destination.id(), amount, amount_source, 0).unwrap()
+        })
+        .collect()
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+/// Collects the modulators of a zone pair, the local zone overriding the
+/// identical modulators of the global zone. Within a single zone the last one
+/// of identical modulators is used.
+fn zone_modulators<M: Sf2Modulator>(zone_modulators: &[&[M]]) -> Vec<SynthModulator> {
+    let mut modulators: Vec<SynthModulator> = Vec::new();
+
+    for modulator in zone_modulators
+        .iter()
+        .flat_map(|zone| zone.iter())
+        .filter_map(SynthModulator::from_sf2)
+    {
+        match modulators
+            .iter()
+            .position(|other| other.is_identical(&modulator))
+        {
+            Some(index) => modulators[index] = modulator,
+            None => modulators.push(modulator),
+        }
+    }
+
+    modulators
+}
+
+/// The modulators of a voice.
+///
+/// Built following section 9.5 of the SoundFont 2.04 specification: the
+/// instrument-level modulators override the identical default modulators
+/// (local zones overriding the global zone), then the preset-level
+/// modulators get added on top of them, their outputs summing up with the
+/// identical modulators of the instrument level.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SynthModulators {
+    modulators: Vec<SynthModulator>,
+}
+
+impl Default for SynthModulators {
+    fn default() -> Self {
+        SynthModulators {
+            modulators: default_modulators(),
+        }
+    }
+}
+
+impl SynthModulators {
+    pub fn new<PM: Sf2Modulator, IM: Sf2Modulator>(
+        preset_modulators: &[&[PM]],
+        instrument_modulators: &[&[IM]],
+    ) -> SynthModulators {
+        let mut modulators = default_modulators();
+
+        for modulator in zone_modulators(instrument_modulators) {
+            match modulators
+                .iter()
+                .position(|other| other.is_identical(&modulator))
+            {
+                Some(index) => modulators[index] = modulator,
+                None => modulators.push(modulator),
+            }
+        }
+
+        modulators.extend(zone_modulators(preset_modulators));
+
+        SynthModulators { modulators }
+    }
+
+    pub fn modulators(&self) -> &[SynthModulator] {
+        &self.modulators
+    }
+
+    /// The generators of a voice with the outputs of the modulators added,
+    /// for the given controller state, key and velocity.
+    pub fn apply(
+        &self,
+        generators: &SynthGenerators,
+        controllers: &SynthControllers,
+        key: u8,
+        velocity: u8,
+    ) -> SynthGenerators {
+        let mut offsets = [0.0; GENERATOR_COUNT];
+
+        for modulator in &self.modulators {
+            offsets[modulator.destination as usize] += modulator.value(controllers, key, velocity);
+        }
+
+        let mut modulated_generators = generators.clone();
+
+        for generator_kind in Sf2GeneratorKind::iter() {
+            let offset = offsets[generator_kind as usize];
+            if offset != 0.0 {
+                modulated_generators.set(
+                    generator_kind,
+                    generators.get(generator_kind) + offset.round() as i32,
+                );
+            }
+        }
+
+        modulated_generators
+    }
+}
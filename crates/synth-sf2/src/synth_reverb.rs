@@ -0,0 +1,211 @@
+/// Delay lengths of the comb and all-pass filters at 44.1 kHz, the right
+/// channel using `STEREO_SPREAD` longer ones.
+const COMB_LENGTHS: [usize; 8] = [1116, 1188, 1277, 1356, 1422, 1491, 1557, 1617];
+const ALLPASS_LENGTHS: [usize; 4] = [556, 441, 341, 225];
+const STEREO_SPREAD: usize = 23;
+
+const INPUT_GAIN: f32 = 0.015;
+const WET_SCALE: f32 = 3.0;
+const ROOM_SCALE: f32 = 0.28;
+const ROOM_OFFSET: f32 = 0.7;
+const DAMPING_SCALE: f32 = 0.4;
+const ALLPASS_FEEDBACK: f32 = 0.5;
+
+/// Level the reverb tail has to decay to after the last input before the
+/// reverb stops processing, about -100 dB.
+const TAIL_LEVEL: f64 = 1e-5;
+
+/// Parameters of the reverb, all in the range of 0.0 to 1.0.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SynthReverbParameters {
+    pub room_size: f32,
+    pub damping: f32,
+    /// Stereo width of the reverb output.
+    pub width: f32,
+    /// Output level of the reverb.
+    pub level: f32,
+}
+
+impl Default for SynthReverbParameters {
+    fn default() -> Self {
+        SynthReverbParameters {
+            room_size: 0.2,
+            damping: 0.0,
+            width: 0.5,
+            level: 0.9,
+        }
+    }
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+#[derive(Debug, Clone)]
+struct Comb {
+    buffer: Vec<f32>,
+    index: usize,
+    filter_store: f32,
+}
+
+impl Comb {
+    fn new(length: usize) -> Comb {
+        Comb {
+            buffer: vec![0.0; length],
+            index: 0,
+            filter_store: 0.0,
+        }
+    }
+
+    fn process(&mut self, input: f32, feedback: f32, damping: f32) -> f32 {
+        let output = self.buffer[self.index];
+
+        self.filter_store = output * (1.0 - damping) + self.filter_store * damping;
+        self.buffer[self.index] = input + self.filter_store * feedback;
+        self.index = (self.index + 1) % self.buffer.len();
+
+        output
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Allpass {
+    buffer: Vec<f32>,
+    index: usize,
+}
+
+impl Allpass {
+    fn new(length: usize) -> Allpass {
+        Allpass {
+            buffer: vec![0.0; length],
+            index: 0,
+        }
+    }
+
+    fn process(&mut self, input: f32) -> f32 {
+        let buffered = self.buffer[self.index];
+
+        self.buffer[self.index] = input + buffered * ALLPASS_FEEDBACK;
+        self.index = (self.index + 1) % self.buffer.len();
+
+        buffered - input
+    }
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+/// A Freeverb-style stereo reverb, eight parallel damped comb filters
+/// followed by four series all-pass filters per channel.
+#[derive(Debug, Clone)]
+pub struct SynthReverb {
+    parameters: SynthReverbParameters,
+
+    combs: [Vec<Comb>; 2],
+    allpasses: [Vec<Allpass>; 2],
+
+    silent_frames: usize,
+}
+
+impl SynthReverb {
+    pub fn new(sample_rate: u32, parameters: SynthReverbParameters) -> SynthReverb {
+        let scale_length =
+            |length: usize| ((length * sample_rate as usize) as f64 / 44100.0).round() as usize;
+
+        let channel_combs = |spread: usize| {
+            COMB_LENGTHS
+                .iter()
+                .map(|&length| Comb::new(scale_length(length + spread).max(1)))
+                .collect()
+        };
+
+        let channel_allpasses = |spread: usize| {
+            ALLPASS_LENGTHS
+                .iter()
+                .map(|&length| Allpass::new(scale_length(length + spread).max(1)))
+                .collect()
+        };
+
+        SynthReverb {
+            parameters,
+
+            combs: [channel_combs(0), channel_combs(STEREO_SPREAD)],
+            allpasses: [channel_allpasses(0), channel_allpasses(STEREO_SPREAD)],
+
+            silent_frames: usize::MAX,
+        }
+    }
+
+    fn feedback(&self) -> f32 {
+        self.parameters.room_size.clamp(0.0, 1.0) * ROOM_SCALE + ROOM_OFFSET
+    }
+
+    /// Number of frames for the longest comb filter to decay to `TAIL_LEVEL`
+    /// and to pass through the all-pass filters.
+    fn tail_length(&self) -> usize {
+        let longest_comb = self.combs[1].iter().map(|comb| comb.buffer.len()).max();
+        let allpass_length = self.allpasses[1]
+            .iter()
+            .map(|allpass| allpass.buffer.len())
+            .sum::<usize>();
+
+        let passes = (TAIL_LEVEL.ln() / (self.feedback() as f64).ln()).ceil() as usize;
+        passes * longest_comb.unwrap_or(0) + allpass_length
+    }
+
+    pub fn parameters(&self) -> SynthReverbParameters {
+        self.parameters
+    }
+
+    /// Changes the parameters, keeping the sound already in the reverb.
+    pub fn set_parameters(&mut self, parameters: SynthReverbParameters) {
+        self.parameters = parameters;
+    }
+
+    /// Whether the reverb is still sounding after its last input.
+    pub fn is_ringing(&self) -> bool {
+        self.silent_frames < self.tail_length()
+    }
+
+    /// Mixes the reverberated mono input into an interleaved stereo buffer.
+    pub fn process(&mut self, input: &[f32], output: &mut [f32]) {
+        let SynthReverbParameters {
+            damping,
+            width,
+            level,
+            ..
+        } = self.parameters;
+
+        let feedback = self.feedback();
+        let tail_length = self.tail_length();
+        let damping = damping.clamp(0.0, 1.0) * DAMPING_SCALE;
+        let width = width.clamp(0.0, 1.0);
+        let wet = level.max(0.0) * WET_SCALE;
+
+        let wet_direct = wet * (width / 2.0 + 0.5);
+        let wet_cross = wet * ((1.0 - width) / 2.0);
+
+        for (&input, frame) in input.iter().zip(output.chunks_exact_mut(2)) {
+            if input != 0.0 {
+                self.silent_frames = 0;
+            } else if self.silent_frames < tail_length {
+                self.silent_frames += 1;
+            } else {
+                continue;
+            }
+
+            let input = input * INPUT_GAIN;
+
+            let [left, right] = [0, 1].map(|channel| {
+                let comb_sum = self.combs[channel]
+                    .iter_mut()
+                    .map(|comb| comb.process(input, feedback, damping))
+                    .sum::<f32>();
+
+                self.allpasses[channel]
+                    .iter_mut()
+                    .fold(comb_sum, |sample, allpass| allpass.process(sample))
+            });
+
+            frame[0] += left * wet_direct + right * wet_cross;
+            frame[1] += right * wet_direct + left * wet_cross;
+        }
+    }
+}
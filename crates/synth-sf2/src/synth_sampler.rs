@@ -0,0 +1,346 @@
+use std::f64::consts::PI;
+use std::sync::OnceLock;
+
+use parser_sf2::{Sf2GeneratorKind, Sf2SampleData, Sf2SampleHeader};
+use strum::{Display, EnumIter, EnumString};
+
+use crate::SynthGenerators;
+
+/// Loop playback modes of the `sampleModes` generator.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SynthLoopMode {
+    NoLoop,
+    Continuous,
+    UntilRelease,
+}
+
+impl SynthLoopMode {
+    pub fn from_sample_modes(sample_modes: i32) -> SynthLoopMode {
+        match sample_modes & 0b11 {
+            1 => SynthLoopMode::Continuous,
+            3 => SynthLoopMode::UntilRelease,
+            // Value 2 is unused and must be treated as no loop.
+            _ => SynthLoopMode::NoLoop,
+        }
+    }
+}
+
+/// Interpolation methods for reading sample data at fractional positions,
+/// from the cheapest to the cleanest.
+#[rustfmt::skip]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default, Display, EnumIter, EnumString)]
+pub enum SynthInterpolation {
+    #[strum(serialize = "nearest")] Nearest,
+    #[strum(serialize = "linear")]  #[default] Linear,
+    #[strum(serialize = "cubic")]   Cubic,
+    #[strum(serialize = "sinc")]    Sinc,
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+/// Number of sample points contributing to a windowed-sinc interpolated value.
+const SINC_TAPS: usize = 16;
+
+/// Number of precomputed kernels between two sample points, the kernels of
+/// the positions in between are interpolated.
+const SINC_PHASES: usize = 256;
+
+/// Blackman-windowed sinc kernels for the fractional positions of
+/// `0..=SINC_PHASES`, normalized to unity gain at DC.
+fn sinc_table() -> &'static [[f32; SINC_TAPS]] {
+    static SINC_TABLE: OnceLock<Vec<[f32; SINC_TAPS]>> = OnceLock::new();
+
+    SINC_TABLE.get_or_init(|| {
+        (0..=SINC_PHASES)
+            .map(|phase| {
+                let fraction = phase as f64 / SINC_PHASES as f64;
+                let half_width = (SINC_TAPS / 2) as f64;
+
+                let weights: [f64; SINC_TAPS] = std::array::from_fn(|tap| {
+                    let x = tap as f64 - (half_width - 1.0) - fraction;
+                    let t = x / half_width;
+
+                    let sinc = if x == 0.0 {
+                        1.0
+                    } else if x.fract() == 0.0 {
+                        0.0
+                    } else {
+                        f64::sin(PI * x) / (PI * x)
+                    };
+
+                    let window = 0.42 + 0.5 * f64::cos(PI * t) + 0.08 * f64::cos(2.0 * PI * t);
+                    sinc * window.max(0.0)
+                });
+
+                let weight_sum = weights.iter().sum::<f64>();
+                weights.map(|weight| (weight / weight_sum) as f32)
+            })
+            .collect()
+    })
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+/// Number of fractional bits of the fixed-point playback position.
+const FRACTION_BITS: u32 = 32;
+const FRACTION_MASK: u64 = (1 << FRACTION_BITS) - 1;
+
+/// Sample point offset of an address generator pair, the coarse generator
+/// counting in units of 32768 sample points.
+fn address_offset(
+    generators: &SynthGenerators,
+    fine: Sf2GeneratorKind,
+    coarse: Sf2GeneratorKind,
+) -> i64 {
+    generators.get(fine) as i64 + generators.get(coarse) as i64 * 32768
+}
+
+/// Plays the sample data of a voice at arbitrary pitch ratios.
+///
+/// The playback position is kept in 32.32 fixed point, so loop points are
+/// crossed sample-accurately and the output is bit-for-bit reproducible.
+/// Sample points outside of the played range are taken as silence, except
+/// around the loop points while looping, where the loop wraps around.
+#[derive(Debug, Clone)]
+pub struct SynthSampler<'a> {
+    sample_data: Sf2SampleData<'a>,
+    interpolation: SynthInterpolation,
+
+    start: usize,
+    end: usize,
+    loop_mode: SynthLoopMode,
+    loop_start: usize,
+    loop_end: usize,
+
+    position: u64,
+
+    has_looped: bool,
+    is_released: bool,
+    is_finished: bool,
+}
+
+impl<'a> SynthSampler<'a> {
+    /// Sets up the playback of a sample, applying the address offset
+    /// generators 0–4, 12, 45 and 50 and the `sampleModes` generator.
+    ///
+    /// Offsets reaching outside of the sample get clamped to it. Samples with
+    /// unusable loop points are played without looping.
+    pub fn new(
+        sample_header: &Sf2SampleHeader,
+        sample_data: Sf2SampleData<'a>,
+        generators: &SynthGenerators,
+        interpolation: SynthInterpolation,
+    ) -> SynthSampler<'a> {
+        let sample_start = sample_header.start.get() as i64;
+        let sample_length = sample_data.len() as i64;
+
+        let start = address_offset(
+            generators,
+            Sf2GeneratorKind::StartAddrsOffset,
+            Sf2GeneratorKind::StartAddrsCoarseOffset,
+        )
+        .clamp(0, sample_length);
+
+        let end = (sample_length
+            + address_offset(
+                generators,
+                Sf2GeneratorKind::EndAddrsOffset,
+                Sf2GeneratorKind::EndAddrsCoarseOffset,
+            ))
+        .clamp(start, sample_length);
+
+        let loop_start = sample_header.start_loop.get() as i64 - sample_start
+            + address_offset(
+                generators,
+                Sf2GeneratorKind::StartloopAddrsOffset,
+                Sf2GeneratorKind::StartloopAddrsCoarseOffset,
+            );
+
+        let loop_end = sample_header.end_loop.get() as i64 - sample_start
+            + address_offset(
+                generators,
+                Sf2GeneratorKind::EndloopAddrsOffset,
+                Sf2GeneratorKind::EndloopAddrsCoarseOffset,
+            );
+
+        let loop_mode = if (start <= loop_start) && (loop_start < loop_end) && (loop_end <= end) {
+            SynthLoopMode::from_sample_modes(generators.get(Sf2GeneratorKind::SampleModes))
+        } else {
+            SynthLoopMode::NoLoop
+        };
+
+        SynthSampler {
+            sample_data,
+            interpolation,
+
+            start: start as usize,
+            end: end as usize,
+            loop_mode,
+            loop_start: loop_start.max(0) as usize,
+            loop_end: loop_end.max(0) as usize,
+
+            position: (start as u64) << FRACTION_BITS,
+
+            has_looped: false,
+            is_released: false,
+            is_finished: start == end,
+        }
+    }
+
+    pub fn interpolation(&self) -> SynthInterpolation {
+        self.interpolation
+    }
+
+    pub fn loop_mode(&self) -> SynthLoopMode {
+        self.loop_mode
+    }
+
+    /// The current playback position, in sample points from the start of
+    /// the sample.
+    pub fn position(&self) -> f64 {
+        self.position as f64 / (1u64 << FRACTION_BITS) as f64
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.is_finished
+    }
+
+    /// Leaves the loop of the loop until release mode, playing the remainder
+    /// of the sample after the loop.
+    pub fn release(&mut self) {
+        self.is_released = true;
+    }
+
+    fn is_looping(&self) -> bool {
+        match self.loop_mode {
+            SynthLoopMode::NoLoop => false,
+            SynthLoopMode::Continuous => true,
+            SynthLoopMode::UntilRelease => !self.is_released,
+        }
+    }
+
+    fn sample_point(&self, index: i64) -> f32 {
+        let loop_start = self.loop_start as i64;
+        let loop_end = self.loop_end as i64;
+        let loop_length = loop_end - loop_start;
+
+        // Points past the loop end are read from the start of the loop, and
+        // once looped, points before the loop start from its end.
+        let index = if self.is_looping() && (index >= loop_end) {
+            loop_start + (index - loop_start) % loop_length
+        } else if self.has_looped && (index < loop_start) && (index >= loop_start - loop_length) {
+            index + loop_length
+        } else {
+            index
+        };
+
+        if (index < self.start as i64) || (index >= self.end as i64) {
+            return 0.0;
+        }
+
+        let index = index as usize;
+
+        match self.sample_data.samples_24_lsb() {
+            Some(samples_24_lsb) => {
+                let msb = self.sample_data.samples_16()[index].get() as i32;
+                let lsb = samples_24_lsb[index] as i32;
+                ((msb << 8) | lsb) as f32 / 8388608.0
+            }
+            None => self.sample_data.samples_16()[index].get() as f32 / 32768.0,
+        }
+    }
+
+    /// The sample value at the current playback position.
+    pub fn value(&self) -> f32 {
+        if self.is_finished {
+            return 0.0;
+        }
+
+        let index = (self.position >> FRACTION_BITS) as i64;
+        let fraction = self.position & FRACTION_MASK;
+        let t = fraction as f32 / (1u64 << FRACTION_BITS) as f32;
+
+        match self.interpolation {
+            SynthInterpolation::Nearest => {
+                let rounding = (fraction >> (FRACTION_BITS - 1)) as i64;
+                self.sample_point(index + rounding)
+            }
+            SynthInterpolation::Linear => {
+                let p0 = self.sample_point(index);
+                let p1 = self.sample_point(index + 1);
+                p0 + (p1 - p0) * t
+            }
+            SynthInterpolation::Cubic => {
+                // Four-point Catmull-Rom spline.
+                let pm1 = self.sample_point(index - 1);
+                let p0 = self.sample_point(index);
+                let p1 = self.sample_point(index + 1);
+                let p2 = self.sample_point(index + 2);
+
+                let c1 = 0.5 * (p1 - pm1);
+                let c2 = pm1 - 2.5 * p0 + 2.0 * p1 - 0.5 * p2;
+                let c3 = 0.5 * (p2 - pm1) + 1.5 * (p0 - p1);
+                ((c3 * t + c2) * t + c1) * t + p0
+            }
+            SynthInterpolation::Sinc => {
+                let sinc_table = sinc_table();
+
+                let phase_position = fraction * SINC_PHASES as u64;
+                let phase = (phase_position >> FRACTION_BITS) as usize;
+                let phase_fraction =
+                    (phase_position & FRACTION_MASK) as f32 / (1u64 << FRACTION_BITS) as f32;
+
+                let first_index = index - (SINC_TAPS as i64 / 2 - 1);
+
+                (0..SINC_TAPS)
+                    .map(|tap| {
+                        let weight = sinc_table[phase][tap]
+                            + (sinc_table[phase + 1][tap] - sinc_table[phase][tap])
+                                * phase_fraction;
+                        self.sample_point(first_index + tap as i64) * weight
+                    })
+                    .sum()
+            }
+        }
+    }
+
+    /// Advances the playback position by the given number of sample points,
+    /// wrapping around the loop while looping.
+    ///
+    /// The sampler finishes when the position would no longer be
+    /// representable, even while looping.
+    pub fn advance(&mut self, increment: f64) {
+        if self.is_finished {
+            return;
+        }
+
+        // Increments moving the position past the fixed-point range, or not
+        // being numbers at all, end the playback instead of overflowing.
+        let increment = (increment * (1u64 << FRACTION_BITS) as f64).round();
+        let position = if increment < u64::MAX as f64 {
+            self.position.checked_add(increment as u64)
+        } else {
+            None
+        };
+
+        match position {
+            Some(position) => self.position = position,
+            None => {
+                self.is_finished = true;
+                return;
+            }
+        }
+
+        if self.is_looping() {
+            let loop_start = (self.loop_start as u64) << FRACTION_BITS;
+            let loop_end = (self.loop_end as u64) << FRACTION_BITS;
+
+            if self.position >= loop_end {
+                self.position = loop_start + (self.position - loop_start) % (loop_end - loop_start);
+                self.has_looped = true;
+            }
+        } else if self.position >= (self.end as u64) << FRACTION_BITS {
+            self.is_finished = true;
+        }
+    }
+}
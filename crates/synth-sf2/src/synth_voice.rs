@@ -0,0 +1,319 @@
+use std::f32::consts::FRAC_PI_2;
+
+use parser_sf2::{Sf2GeneratorKind, Sf2SampleData, Sf2SampleHeader};
+
+use crate::{
+    SynthControllers, SynthEnvelope, SynthFilter, SynthGenerators, SynthInterpolation, SynthLfo,
+    SynthModulators, SynthSampler, FILTER_CONTROL_PERIOD,
+};
+
+pub(crate) struct SynthVoice<'a> {
+    channel: usize,
+    key: u8,
+    velocity: u8,
+    exclusive_class: i32,
+
+    generators: SynthGenerators,
+    modulators: SynthModulators,
+
+    sampler: SynthSampler<'a>,
+
+    output_sample_rate: u32,
+    root_key: i32,
+    pitch_correction: i32,
+    sample_rate_ratio: f64,
+
+    base_increment: f64,
+
+    gain_left: f32,
+    gain_right: f32,
+    reverb_send: f32,
+    chorus_send: f32,
+
+    volume_envelope: SynthEnvelope,
+    modulation_envelope: SynthEnvelope,
+    modulation_envelope_to_pitch: f64,
+
+    modulation_lfo: SynthLfo,
+    modulation_lfo_to_pitch: f64,
+    modulation_lfo_to_volume: f32,
+
+    vibrato_lfo: SynthLfo,
+    vibrato_lfo_to_pitch: f64,
+
+    filter: Option<SynthFilter>,
+    filter_cutoff: f64,
+    filter_resonance: f64,
+    modulation_envelope_to_filter: f64,
+    modulation_lfo_to_filter: f64,
+
+    frame_position: usize,
+
+    is_sustained: bool,
+    is_released: bool,
+    is_finished: bool,
+}
+
+impl<'a> SynthVoice<'a> {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        channel: usize,
+        key: u8,
+        velocity: u8,
+        generators: SynthGenerators,
+        modulators: SynthModulators,
+        controllers: &SynthControllers,
+        sample_header: &Sf2SampleHeader,
+        sample_data: Sf2SampleData<'a>,
+        interpolation: SynthInterpolation,
+        output_sample_rate: u32,
+    ) -> SynthVoice<'a> {
+        // Parameters which are not updated over the lifetime of the voice
+        // take the modulator outputs at the start of the note.
+        let modulated_generators = modulators.apply(&generators, controllers, key, velocity);
+
+        let root_key = match modulated_generators.get(Sf2GeneratorKind::OverridingRootKey) {
+            root_key @ 0..=127 => root_key,
+            _ => match sample_header.original_pitch {
+                original_pitch @ 0..=127 => original_pitch as i32,
+                // Unpitched samples and invalid values default to middle C.
+                _ => 60,
+            },
+        };
+
+        let sampler = SynthSampler::new(
+            sample_header,
+            sample_data,
+            &modulated_generators,
+            interpolation,
+        );
+        let is_finished = sampler.is_finished();
+
+        let mut voice = SynthVoice {
+            channel,
+            key,
+            velocity,
+            exclusive_class: modulated_generators.get(Sf2GeneratorKind::ExclusiveClass),
+
+            generators,
+            modulators,
+
+            sampler,
+
+            output_sample_rate,
+            root_key,
+            pitch_correction: sample_header.pitch_correction as i32,
+            sample_rate_ratio: sample_header.sample_rate.get() as f64 / output_sample_rate as f64,
+
+            base_increment: 0.0,
+
+            gain_left: 0.0,
+            gain_right: 0.0,
+            reverb_send: 0.0,
+            chorus_send: 0.0,
+
+            volume_envelope: SynthEnvelope::volume(&modulated_generators, key, output_sample_rate),
+            modulation_envelope: SynthEnvelope::modulation(
+                &modulated_generators,
+                key,
+                output_sample_rate,
+            ),
+            modulation_envelope_to_pitch: 0.0,
+
+            modulation_lfo: SynthLfo::modulation(&modulated_generators, output_sample_rate),
+            modulation_lfo_to_pitch: 0.0,
+            modulation_lfo_to_volume: 0.0,
+
+            vibrato_lfo: SynthLfo::vibrato(&modulated_generators, output_sample_rate),
+            vibrato_lfo_to_pitch: 0.0,
+
+            filter: None,
+            filter_cutoff: 0.0,
+            filter_resonance: 0.0,
+            modulation_envelope_to_filter: 0.0,
+            modulation_lfo_to_filter: 0.0,
+
+            frame_position: 0,
+
+            is_sustained: false,
+            is_released: false,
+            is_finished,
+        };
+
+        voice.update_parameters(&modulated_generators);
+        voice
+    }
+
+    /// Updates the parameters controlled in real time from the generators
+    /// with the modulator outputs added.
+    fn update_parameters(&mut self, generators: &SynthGenerators) {
+        // Pitch, in cents relative to the root key of the sample.
+        let pitch = {
+            let key = match generators.get(Sf2GeneratorKind::Keynum) {
+                keynum @ 0..=127 => keynum,
+                _ => self.key as i32,
+            };
+
+            generators.get(Sf2GeneratorKind::ScaleTuning) * (key - self.root_key)
+                + generators.get(Sf2GeneratorKind::CoarseTune) * 100
+                + generators.get(Sf2GeneratorKind::FineTune)
+                + self.pitch_correction
+        };
+
+        self.base_increment = f64::powf(2.0, pitch as f64 / 1200.0) * self.sample_rate_ratio;
+
+        // Attenuation in centibels, pan in 0.1% units from left to right.
+        let gain = {
+            let attenuation = generators
+                .get(Sf2GeneratorKind::InitialAttenuation)
+                .clamp(0, 1440);
+            f32::powf(10.0, -attenuation as f32 / 200.0)
+        };
+
+        (self.gain_left, self.gain_right) = {
+            let pan = generators.get(Sf2GeneratorKind::Pan).clamp(-500, 500);
+            let pan_angle = (pan + 500) as f32 / 1000.0 * FRAC_PI_2;
+            (gain * pan_angle.cos(), gain * pan_angle.sin())
+        };
+
+        // Effect send levels in 0.1% units, the sends are taken before panning.
+        let send_level =
+            |generator_kind| generators.get(generator_kind).clamp(0, 1000) as f32 / 1000.0;
+        self.reverb_send = gain * send_level(Sf2GeneratorKind::ReverbEffectsSend);
+        self.chorus_send = gain * send_level(Sf2GeneratorKind::ChorusEffectsSend);
+
+        self.modulation_envelope_to_pitch = generators.get(Sf2GeneratorKind::ModEnvToPitch) as f64;
+        self.modulation_lfo_to_pitch = generators.get(Sf2GeneratorKind::ModLfoToPitch) as f64;
+        self.modulation_lfo_to_volume = generators
+            .get(Sf2GeneratorKind::ModLfoToVolume)
+            .clamp(-960, 960) as f32;
+        self.vibrato_lfo_to_pitch = generators.get(Sf2GeneratorKind::VibLfoToPitch) as f64;
+
+        self.filter_cutoff = generators.get(Sf2GeneratorKind::InitialFilterFc) as f64;
+        self.filter_resonance = generators.get(Sf2GeneratorKind::InitialFilterQ) as f64;
+        self.modulation_envelope_to_filter =
+            generators.get(Sf2GeneratorKind::ModEnvToFilterFc) as f64;
+        self.modulation_lfo_to_filter = generators.get(Sf2GeneratorKind::ModLfoToFilterFc) as f64;
+
+        // A fully open filter without resonance or modulation is bypassed,
+        // until the modulators close it.
+        if self.filter.is_none()
+            && ((self.filter_cutoff < 13500.0)
+                || (self.filter_resonance > 0.0)
+                || (self.modulation_envelope_to_filter != 0.0)
+                || (self.modulation_lfo_to_filter != 0.0))
+        {
+            self.filter = Some(SynthFilter::new(self.output_sample_rate));
+        }
+    }
+
+    /// Reevaluates the modulators after a change of the channel controllers.
+    pub(crate) fn update_controllers(&mut self, controllers: &SynthControllers) {
+        let modulated_generators =
+            self.modulators
+                .apply(&self.generators, controllers, self.key, self.velocity);
+        self.update_parameters(&modulated_generators);
+    }
+
+    pub(crate) fn channel(&self) -> usize {
+        self.channel
+    }
+
+    pub(crate) fn key(&self) -> u8 {
+        self.key
+    }
+
+    pub(crate) fn exclusive_class(&self) -> i32 {
+        self.exclusive_class
+    }
+
+    pub(crate) fn is_sustained(&self) -> bool {
+        self.is_sustained
+    }
+
+    pub(crate) fn is_released(&self) -> bool {
+        self.is_released
+    }
+
+    pub(crate) fn is_finished(&self) -> bool {
+        self.is_finished
+    }
+
+    /// Moves the envelopes into their release stage, the voice keeps sounding
+    /// until the volume envelope is finished.
+    pub(crate) fn release(&mut self) {
+        self.is_sustained = false;
+        self.is_released = true;
+
+        self.volume_envelope.release();
+        self.modulation_envelope.release();
+        self.sampler.release();
+    }
+
+    /// Defers the release of the voice while the sustain pedal is held.
+    pub(crate) fn sustain(&mut self) {
+        self.is_sustained = true;
+    }
+
+    pub(crate) fn kill(&mut self) {
+        self.is_finished = true;
+    }
+
+    /// Mixes the voice into an interleaved stereo buffer, and into the mono
+    /// buffers of the reverb and chorus sends.
+    pub(crate) fn render(
+        &mut self,
+        output: &mut [f32],
+        reverb_input: &mut [f32],
+        chorus_input: &mut [f32],
+    ) {
+        for (frame_index, frame) in output.chunks_exact_mut(2).enumerate() {
+            if self.is_finished || self.volume_envelope.is_finished() {
+                self.is_finished = true;
+                break;
+            }
+
+            let envelope_gain = self.volume_envelope.next_value();
+            let modulation_envelope = self.modulation_envelope.next_value() as f64;
+            let modulation_lfo = self.modulation_lfo.next_value();
+            let vibrato_lfo = self.vibrato_lfo.next_value() as f64;
+
+            // A positive swing of the LFO raises the volume by the given
+            // amount of centibels.
+            let gain = envelope_gain
+                * f32::powf(10.0, modulation_lfo * self.modulation_lfo_to_volume / 200.0);
+
+            let sample_point = self.sampler.value();
+
+            let sample_point = match &mut self.filter {
+                Some(filter) => {
+                    if self.frame_position % FILTER_CONTROL_PERIOD == 0 {
+                        let cutoff = self.filter_cutoff
+                            + modulation_envelope * self.modulation_envelope_to_filter
+                            + modulation_lfo as f64 * self.modulation_lfo_to_filter;
+                        filter.set_parameters(cutoff, self.filter_resonance);
+                    }
+                    filter.process(sample_point)
+                }
+                None => sample_point,
+            };
+
+            self.frame_position += 1;
+
+            frame[0] += sample_point * gain * self.gain_left;
+            frame[1] += sample_point * gain * self.gain_right;
+            reverb_input[frame_index] += sample_point * gain * self.reverb_send;
+            chorus_input[frame_index] += sample_point * gain * self.chorus_send;
+
+            let pitch_offset = modulation_envelope * self.modulation_envelope_to_pitch
+                + modulation_lfo as f64 * self.modulation_lfo_to_pitch
+                + vibrato_lfo * self.vibrato_lfo_to_pitch;
+            self.sampler
+                .advance(self.base_increment * f64::powf(2.0, pitch_offset / 1200.0));
+
+            if self.sampler.is_finished() {
+                self.is_finished = true;
+            }
+        }
+    }
+}
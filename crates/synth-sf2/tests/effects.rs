@@ -0,0 +1,151 @@
+use synth_sf2::{SynthChorus, SynthChorusParameters, SynthReverb, SynthReverbParameters};
+
+const SAMPLE_RATE: u32 = 44100;
+
+fn impulse(frame_count: usize) -> Vec<f32> {
+    let mut input = vec![0.0; frame_count];
+    input[0] = 1.0;
+    input
+}
+
+fn first_sounding_frame(output: &[f32]) -> Option<usize> {
+    output
+        .chunks_exact(2)
+        .position(|frame| frame[0] != 0.0 || frame[1] != 0.0)
+}
+
+fn peak(output: &[f32]) -> f32 {
+    output
+        .iter()
+        .fold(0.0, |peak, sample| sample.abs().max(peak))
+}
+
+#[test]
+fn reverb_impulse_response() {
+    let mut reverb = SynthReverb::new(SAMPLE_RATE, SynthReverbParameters::default());
+    assert!(!reverb.is_ringing());
+
+    let mut output = vec![0.0; SAMPLE_RATE as usize * 2];
+    reverb.process(&impulse(SAMPLE_RATE as usize), &mut output);
+
+    // The shortest comb filter delays the first reflection.
+    assert_eq!(first_sounding_frame(&output), Some(1116));
+    assert!(output[..1116 * 2].iter().all(|&sample| sample == 0.0));
+
+    // Both channels sound, differently, and the tail decays.
+    let (left, right): (Vec<f32>, Vec<f32>) = output
+        .chunks_exact(2)
+        .map(|frame| (frame[0], frame[1]))
+        .unzip();
+    assert_ne!(left, right);
+    assert!(peak(&output[..SAMPLE_RATE as usize / 2]) > peak(&output[SAMPLE_RATE as usize..]));
+    assert!(reverb.is_ringing());
+
+    // The tail ends after a while of silent input.
+    let mut tail = vec![0.0; SAMPLE_RATE as usize * 20];
+    reverb.process(&vec![0.0; SAMPLE_RATE as usize * 10], &mut tail);
+    assert!(!reverb.is_ringing());
+    assert!(peak(&tail[SAMPLE_RATE as usize * 18..]) == 0.0);
+}
+
+#[test]
+fn reverb_parameters() {
+    let render = |parameters: SynthReverbParameters| {
+        let mut reverb = SynthReverb::new(SAMPLE_RATE, parameters);
+        let mut output = vec![0.0; SAMPLE_RATE as usize * 2];
+        reverb.process(&impulse(SAMPLE_RATE as usize), &mut output);
+        output
+    };
+
+    let default_parameters = SynthReverbParameters::default();
+
+    let silent = render(SynthReverbParameters {
+        level: 0.0,
+        ..default_parameters
+    });
+    assert!(silent.iter().all(|&sample| sample == 0.0));
+
+    // Without width both channels carry the same signal.
+    let mono = render(SynthReverbParameters {
+        width: 0.0,
+        ..default_parameters
+    });
+    assert!(mono.chunks_exact(2).all(|frame| frame[0] == frame[1]));
+
+    // A larger room rings longer.
+    let small_room = render(default_parameters);
+    let large_room = render(SynthReverbParameters {
+        room_size: 1.0,
+        ..default_parameters
+    });
+    let late = SAMPLE_RATE as usize * 3 / 2..SAMPLE_RATE as usize * 2;
+    assert!(peak(&large_room[late.clone()]) > peak(&small_room[late]) * 10.0);
+}
+
+#[test]
+fn reverb_adds_to_output() {
+    let mut reverb = SynthReverb::new(SAMPLE_RATE, SynthReverbParameters::default());
+
+    let mut output = vec![0.25; 200];
+    reverb.process(&[0.0; 100], &mut output);
+    assert!(output.iter().all(|&sample| sample == 0.25));
+}
+
+#[test]
+fn chorus_delays_input() {
+    let parameters = SynthChorusParameters {
+        voice_count: 1,
+        depth: 0.0,
+        ..Default::default()
+    };
+
+    // At 48 kHz the delay is a whole number of frames.
+    let mut chorus = SynthChorus::new(48000, parameters);
+    assert!(!chorus.is_ringing());
+
+    let mut output = vec![0.0; 1000 * 2];
+    chorus.process(&impulse(1000), &mut output);
+
+    // A single unmodulated voice is a centered 5 ms delay.
+    let delay = 240;
+    assert_eq!(first_sounding_frame(&output), Some(delay));
+    assert_eq!(output[delay * 2], 0.5);
+    assert_eq!(output[delay * 2 + 1], 0.5);
+    assert_eq!(output.iter().filter(|&&sample| sample != 0.0).count(), 2);
+
+    assert!(chorus.is_ringing());
+    chorus.process(&vec![0.0; 2000], &mut vec![0.0; 4000]);
+    assert!(!chorus.is_ringing());
+}
+
+#[test]
+fn chorus_voices() {
+    let mut chorus = SynthChorus::new(SAMPLE_RATE, SynthChorusParameters::default());
+
+    // A constant input passes at the chorus level once the delay line is
+    // filled, the voices spread across both channels.
+    let mut output = vec![0.0; SAMPLE_RATE as usize * 2];
+    chorus.process(&vec![1.0; SAMPLE_RATE as usize], &mut output);
+
+    for frame in output.chunks_exact(2).skip(SAMPLE_RATE as usize / 10) {
+        assert!((frame[0] + frame[1] - 1.0).abs() < 1e-5);
+        assert!(frame[0] > 0.0 && frame[1] > 0.0);
+    }
+
+    // The modulation moves the delayed copies of a sine input.
+    let mut chorus = SynthChorus::new(SAMPLE_RATE, SynthChorusParameters::default());
+    let input = (0..SAMPLE_RATE as usize)
+        .map(|frame| f32::sin(frame as f32 * 0.05))
+        .collect::<Vec<_>>();
+    let mut output = vec![0.0; SAMPLE_RATE as usize * 2];
+    chorus.process(&input, &mut output);
+
+    let left = output.iter().step_by(2).copied().collect::<Vec<_>>();
+    let right = output
+        .iter()
+        .skip(1)
+        .step_by(2)
+        .copied()
+        .collect::<Vec<_>>();
+    assert_ne!(left, right);
+}
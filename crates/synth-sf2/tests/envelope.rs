@@ -0,0 +1,170 @@
+use parser_sf2::Sf2GeneratorKind;
+use synth_sf2::{timecents_to_seconds, SynthEnvelope, SynthEnvelopeStage, SynthGenerators};
+
+// Timecents of -1200 * n are 2^-n seconds, exact sample counts at this rate.
+const SAMPLE_RATE: u32 = 1024;
+
+fn generators(amounts: &[(Sf2GeneratorKind, i32)]) -> SynthGenerators {
+    let mut generators = SynthGenerators::default();
+    for &(generator_kind, amount) in amounts {
+        generators.set(generator_kind, amount);
+    }
+    generators
+}
+
+fn render(envelope: &mut SynthEnvelope, frame_count: usize) -> Vec<f32> {
+    (0..frame_count).map(|_| envelope.next_value()).collect()
+}
+
+fn assert_curve(actual: &[f32], expected: impl Fn(usize) -> f64) {
+    for (frame, &actual) in actual.iter().enumerate() {
+        let expected = expected(frame);
+        assert!(
+            (actual as f64 - expected).abs() <= expected.abs() * 1e-5 + 1e-7,
+            "frame {frame}: expected {expected}, got {actual}"
+        );
+    }
+}
+
+/// Gain of the volume envelope at a normalized level, 0.0 being 96 dB below
+/// the peak.
+fn volume_gain(level: f64) -> f64 {
+    f64::powf(10.0, -(1.0 - level) * 960.0 / 200.0)
+}
+
+#[test]
+fn timecents() {
+    assert_eq!(timecents_to_seconds(0), 1.0);
+    assert_eq!(timecents_to_seconds(1200), 2.0);
+    assert_eq!(timecents_to_seconds(-1200), 0.5);
+    assert_eq!(timecents_to_seconds(-32768), 0.0);
+    assert!((timecents_to_seconds(-12000) - 0.000977).abs() < 1e-6);
+}
+
+#[test]
+fn volume_envelope_stages() {
+    let generators = generators(&[
+        (Sf2GeneratorKind::DelayVolEnv, -3600),   // 128 frames
+        (Sf2GeneratorKind::AttackVolEnv, -4800),  // 64 frames
+        (Sf2GeneratorKind::HoldVolEnv, -6000),    // 32 frames
+        (Sf2GeneratorKind::DecayVolEnv, -2400),   // 256 frames for 96 dB
+        (Sf2GeneratorKind::SustainVolEnv, 480),   // -48 dB, half way
+        (Sf2GeneratorKind::ReleaseVolEnv, -2400), // 256 frames for 96 dB
+    ]);
+    let mut envelope = SynthEnvelope::volume(&generators, 60, SAMPLE_RATE);
+
+    assert_eq!(envelope.stage(), SynthEnvelopeStage::Delay);
+    assert_curve(&render(&mut envelope, 128), |_| 0.0);
+
+    assert_eq!(envelope.stage(), SynthEnvelopeStage::Attack);
+    assert_curve(&render(&mut envelope, 64), |frame| frame as f64 / 64.0);
+
+    assert_eq!(envelope.stage(), SynthEnvelopeStage::Hold);
+    assert_curve(&render(&mut envelope, 32), |_| 1.0);
+
+    assert_eq!(envelope.stage(), SynthEnvelopeStage::Decay);
+    assert_curve(&render(&mut envelope, 128), |frame| {
+        volume_gain(1.0 - frame as f64 / 256.0)
+    });
+
+    assert_eq!(envelope.stage(), SynthEnvelopeStage::Sustain);
+    assert_curve(&render(&mut envelope, 1000), |_| f64::powf(10.0, -2.4));
+
+    envelope.release();
+    assert_eq!(envelope.stage(), SynthEnvelopeStage::Release);
+    assert_curve(&render(&mut envelope, 128), |frame| {
+        volume_gain(0.5 - frame as f64 / 256.0)
+    });
+
+    assert!(envelope.is_finished());
+    assert_curve(&render(&mut envelope, 10), |_| 0.0);
+}
+
+#[test]
+fn volume_envelope_release_during_attack() {
+    let generators = generators(&[
+        (Sf2GeneratorKind::DelayVolEnv, -32768),
+        (Sf2GeneratorKind::AttackVolEnv, -4800),  // 64 frames
+        (Sf2GeneratorKind::ReleaseVolEnv, -2400), // 256 frames for 96 dB
+    ]);
+    let mut envelope = SynthEnvelope::volume(&generators, 60, SAMPLE_RATE);
+
+    render(&mut envelope, 32);
+    envelope.release();
+
+    // The release continues from the amplitude reached, -6 dB.
+    let start_level = 1.0 + f64::log10(0.5) * 200.0 / 960.0;
+    let release_length = (start_level * 256.0).ceil() as usize;
+
+    assert_curve(&render(&mut envelope, release_length), |frame| {
+        volume_gain(start_level - frame as f64 / 256.0)
+    });
+    assert!(envelope.is_finished());
+}
+
+#[test]
+fn volume_envelope_silent_sustain() {
+    let generators = generators(&[
+        (Sf2GeneratorKind::DelayVolEnv, -32768),
+        (Sf2GeneratorKind::AttackVolEnv, -32768),
+        (Sf2GeneratorKind::DecayVolEnv, -2400), // 256 frames for 96 dB
+        (Sf2GeneratorKind::SustainVolEnv, 1440),
+    ]);
+    let mut envelope = SynthEnvelope::volume(&generators, 60, SAMPLE_RATE);
+
+    // No hold generator, the default of -12000 timecents is a single frame.
+    render(&mut envelope, 1);
+    assert_curve(&render(&mut envelope, 256), |frame| {
+        volume_gain(1.0 - frame as f64 / 256.0)
+    });
+    assert!(envelope.is_finished());
+}
+
+#[test]
+fn keynum_scales_hold_and_decay() {
+    for (key, hold_frames, decay_frames) in [(60, 64, 256), (48, 128, 128), (84, 16, 1024)] {
+        let generators = generators(&[
+            (Sf2GeneratorKind::DelayModEnv, -32768),
+            (Sf2GeneratorKind::AttackModEnv, -32768),
+            (Sf2GeneratorKind::HoldModEnv, -4800),  // 64 frames
+            (Sf2GeneratorKind::DecayModEnv, -2400), // 256 frames
+            (Sf2GeneratorKind::SustainModEnv, 1000),
+            (Sf2GeneratorKind::KeynumToModEnvHold, 100),
+            (Sf2GeneratorKind::KeynumToModEnvDecay, -100),
+        ]);
+        let mut envelope = SynthEnvelope::modulation(&generators, key, SAMPLE_RATE);
+
+        assert_curve(&render(&mut envelope, hold_frames), |_| 1.0);
+        assert_eq!(envelope.stage(), SynthEnvelopeStage::Decay, "key {key}");
+
+        assert_curve(&render(&mut envelope, decay_frames), |frame| {
+            1.0 - frame as f64 / decay_frames as f64
+        });
+        assert_eq!(envelope.stage(), SynthEnvelopeStage::Sustain, "key {key}");
+    }
+}
+
+#[test]
+fn modulation_envelope_is_linear() {
+    let generators = generators(&[
+        (Sf2GeneratorKind::DelayModEnv, -32768),
+        (Sf2GeneratorKind::AttackModEnv, -3600), // 128 frames
+        (Sf2GeneratorKind::HoldModEnv, -32768),
+        (Sf2GeneratorKind::DecayModEnv, -1200), // 512 frames
+        (Sf2GeneratorKind::SustainModEnv, 250), // 75%
+        (Sf2GeneratorKind::ReleaseModEnv, 0),   // 1024 frames
+    ]);
+    let mut envelope = SynthEnvelope::modulation(&generators, 60, SAMPLE_RATE);
+
+    assert_curve(&render(&mut envelope, 128), |frame| frame as f64 / 128.0);
+    assert_curve(&render(&mut envelope, 128), |frame| {
+        1.0 - frame as f64 / 512.0
+    });
+    assert_curve(&render(&mut envelope, 100), |_| 0.75);
+
+    envelope.release();
+    assert_curve(&render(&mut envelope, 768), |frame| {
+        0.75 - frame as f64 / 1024.0
+    });
+    assert!(envelope.is_finished());
+}
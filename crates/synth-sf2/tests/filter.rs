@@ -0,0 +1,92 @@
+use std::f64::consts::PI;
+
+use synth_sf2::{absolute_cents_to_hertz, SynthFilter, FILTER_CONTROL_PERIOD};
+
+const SAMPLE_RATE: u32 = 44100;
+
+/// Peak amplitude of the filter output for a sine input, once the filter
+/// has settled.
+fn sine_response(cutoff: f64, resonance: f64, frequency: f64) -> f64 {
+    let mut filter = SynthFilter::new(SAMPLE_RATE);
+    filter.set_parameters(cutoff, resonance);
+
+    (0..SAMPLE_RATE as usize)
+        .map(|frame| {
+            let input = f64::sin(2.0 * PI * frequency * frame as f64 / SAMPLE_RATE as f64);
+            filter.process(input as f32).abs() as f64
+        })
+        .skip(SAMPLE_RATE as usize / 2)
+        .fold(0.0, f64::max)
+}
+
+fn impulse_response(filter: &mut SynthFilter, frame_count: usize) -> Vec<f32> {
+    (0..frame_count)
+        .map(|frame| filter.process(if frame == 0 { 1.0 } else { 0.0 }))
+        .collect()
+}
+
+#[test]
+fn unity_dc_gain() {
+    for resonance in [0.0, 120.0, 240.0] {
+        let mut filter = SynthFilter::new(SAMPLE_RATE);
+        filter.set_parameters(6900.0, resonance);
+
+        let output = (0..SAMPLE_RATE).map(|_| filter.process(1.0)).last();
+        assert!(
+            (output.unwrap() - 1.0).abs() < 1e-4,
+            "resonance {resonance}"
+        );
+    }
+}
+
+#[test]
+fn resonance_peak_at_cutoff() {
+    for (cutoff, resonance) in [(6900, 0), (6900, 120), (8400, 60), (4800, 240)] {
+        let frequency = absolute_cents_to_hertz(cutoff);
+        let expected = f64::powf(10.0, resonance as f64 / 200.0);
+        let actual = sine_response(cutoff as f64, resonance as f64, frequency);
+
+        assert!(
+            (actual / expected - 1.0).abs() < 0.01,
+            "cutoff {cutoff}, resonance {resonance}: expected {expected}, got {actual}"
+        );
+    }
+}
+
+#[test]
+fn two_pole_roll_off() {
+    // Two octaves above the cutoff, a second-order low-pass is down about
+    // 24 dB.
+    let frequency = absolute_cents_to_hertz(6900) * 4.0;
+    let actual = sine_response(6900.0, 0.0, frequency);
+
+    assert!((20.0 * actual.log10() + 24.0).abs() < 1.0, "got {actual}");
+}
+
+#[test]
+fn parameters_change_gradually() {
+    let mut target_filter = SynthFilter::new(SAMPLE_RATE);
+    target_filter.set_parameters(9000.0, 120.0);
+    let target_response = impulse_response(&mut target_filter, 256);
+
+    // Right after the change the filter is still in between the two settings.
+    let mut filter = SynthFilter::new(SAMPLE_RATE);
+    filter.set_parameters(3000.0, 0.0);
+    filter.set_parameters(9000.0, 120.0);
+    let response = impulse_response(&mut filter, 256);
+
+    assert!((response[0] - target_response[0]).abs() > 1e-3);
+
+    // After a control period the filter matches the new setting.
+    let mut filter = SynthFilter::new(SAMPLE_RATE);
+    filter.set_parameters(3000.0, 0.0);
+    filter.set_parameters(9000.0, 120.0);
+    for _ in 0..FILTER_CONTROL_PERIOD {
+        filter.process(0.0);
+    }
+    let response = impulse_response(&mut filter, 256);
+
+    for (actual, expected) in response.iter().zip(target_response) {
+        assert!((actual - expected).abs() < 1e-6);
+    }
+}
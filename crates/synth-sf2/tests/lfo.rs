@@ -0,0 +1,84 @@
+use parser_sf2::Sf2GeneratorKind;
+use synth_sf2::{absolute_cents_to_hertz, SynthGenerators, SynthLfo};
+
+const SAMPLE_RATE: u32 = 44100;
+
+fn generators(amounts: &[(Sf2GeneratorKind, i32)]) -> SynthGenerators {
+    let mut generators = SynthGenerators::default();
+    for &(generator_kind, amount) in amounts {
+        generators.set(generator_kind, amount);
+    }
+    generators
+}
+
+/// Triangle wave starting from zero and rising first, at the given time.
+fn triangle(frequency: f64, time: f64) -> f64 {
+    let phase = (frequency * time).fract();
+    if phase < 0.25 {
+        4.0 * phase
+    } else if phase < 0.75 {
+        2.0 - 4.0 * phase
+    } else {
+        4.0 * phase - 4.0
+    }
+}
+
+#[test]
+fn absolute_cents() {
+    assert_eq!(absolute_cents_to_hertz(0), 8.176);
+    assert!((absolute_cents_to_hertz(6900) - 440.0).abs() < 0.02);
+    assert!((absolute_cents_to_hertz(-1200) - 4.088).abs() < 1e-9);
+}
+
+#[test]
+fn triangle_shape() {
+    // 8.176 Hz * 2^(-3600/1200), about a second per cycle.
+    let generators = generators(&[
+        (Sf2GeneratorKind::DelayModLfo, -32768),
+        (Sf2GeneratorKind::FreqModLfo, -3600),
+    ]);
+    let mut lfo = SynthLfo::modulation(&generators, SAMPLE_RATE);
+
+    let frequency = 8.176 / 8.0;
+    for frame in 0..SAMPLE_RATE as usize * 3 {
+        let expected = triangle(frequency, frame as f64 / SAMPLE_RATE as f64);
+        let actual = lfo.next_value() as f64;
+        assert!(
+            (actual - expected).abs() < 1e-5,
+            "frame {frame}: expected {expected}, got {actual}"
+        );
+    }
+}
+
+#[test]
+fn delay_and_default_frequency() {
+    // Half a second of delay, default frequency of 8.176 Hz.
+    let generators = generators(&[(Sf2GeneratorKind::DelayVibLfo, -1200)]);
+    let mut lfo = SynthLfo::vibrato(&generators, SAMPLE_RATE);
+
+    let delay_length = SAMPLE_RATE as usize / 2;
+    for _ in 0..delay_length {
+        assert_eq!(lfo.next_value(), 0.0);
+    }
+
+    for frame in 0..SAMPLE_RATE as usize {
+        let expected = triangle(8.176, frame as f64 / SAMPLE_RATE as f64);
+        assert!((lfo.next_value() as f64 - expected).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn frequency_is_clamped() {
+    // Above the maximum of 4500 absolute cents, about 100 Hz.
+    let generators = generators(&[
+        (Sf2GeneratorKind::DelayModLfo, -32768),
+        (Sf2GeneratorKind::FreqModLfo, 9000),
+    ]);
+    let mut lfo = SynthLfo::modulation(&generators, SAMPLE_RATE);
+
+    let frequency = absolute_cents_to_hertz(4500);
+    for frame in 0..SAMPLE_RATE as usize {
+        let expected = triangle(frequency, frame as f64 / SAMPLE_RATE as f64);
+        assert!((lfo.next_value() as f64 - expected).abs() < 1e-5);
+    }
+}
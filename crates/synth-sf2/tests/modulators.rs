@@ -0,0 +1,377 @@
+use parser_sf2::{Sf2GeneratorKind, Sf2ModelModulator};
+use synth_sf2::{
+    default_modulators, SynthControllers, SynthGenerators, SynthModulator, SynthModulators,
+};
+
+const NO_MODULATORS: &[&[Sf2ModelModulator]] = &[];
+
+fn modulator(
+    source: u16,
+    destination: Sf2GeneratorKind,
+    amount: i16,
+    amount_source: u16,
+) -> Sf2ModelModulator {
+    Sf2ModelModulator {
+        source,
+        destination: destination as u16,
+        amount,
+        amount_source,
+        transform: 0,
+    }
+}
+
+fn synth_modulator(source: u16, amount_source: u16, transform: u16) -> SynthModulator {
+    SynthModulator::from_raw(
+        source,
+        Sf2GeneratorKind::FineTune as u16,
+        1000,
+        amount_source,
+        transform,
+    )
+    .unwrap()
+}
+
+fn controllers_with(controller: u8, value: u8) -> SynthControllers {
+    let mut controllers = SynthControllers::default();
+    controllers.set_controller(controller, value);
+    controllers
+}
+
+fn concave(x: f64) -> f64 {
+    -5.0 / 12.0 * f64::log10(1.0 - x)
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-9,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn default_modulator_set() {
+    let modulators = default_modulators();
+    assert_eq!(modulators.len(), 10);
+
+    let destinations = modulators
+        .iter()
+        .map(|modulator| (modulator.destination, modulator.amount))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        destinations,
+        [
+            (Sf2GeneratorKind::InitialAttenuation, 960),
+            (Sf2GeneratorKind::InitialFilterFc, -2400),
+            (Sf2GeneratorKind::VibLfoToPitch, 50),
+            (Sf2GeneratorKind::VibLfoToPitch, 50),
+            (Sf2GeneratorKind::InitialAttenuation, 960),
+            (Sf2GeneratorKind::Pan, 1000),
+            (Sf2GeneratorKind::InitialAttenuation, 960),
+            (Sf2GeneratorKind::ReverbEffectsSend, 200),
+            (Sf2GeneratorKind::ChorusEffectsSend, 200),
+            (Sf2GeneratorKind::FineTune, 12700),
+        ]
+    );
+
+    assert_eq!(
+        SynthModulators::new(NO_MODULATORS, NO_MODULATORS).modulators(),
+        modulators
+    );
+}
+
+#[test]
+fn linear_sources() {
+    let controllers = controllers_with(1, 32);
+
+    // Modulation wheel, unipolar, positive and negative.
+    assert_close(
+        synth_modulator(0x0081, 0, 0).value(&controllers, 60, 100),
+        1000.0 * 32.0 / 128.0,
+    );
+    assert_close(
+        synth_modulator(0x0181, 0, 0).value(&controllers, 60, 100),
+        1000.0 * 96.0 / 128.0,
+    );
+
+    // Key number and velocity, bipolar.
+    assert_close(
+        synth_modulator(0x0203, 0, 0).value(&controllers, 96, 100),
+        1000.0 * (2.0 * 96.0 / 128.0 - 1.0),
+    );
+    assert_close(
+        synth_modulator(0x0202, 0, 0).value(&controllers, 60, 64),
+        0.0,
+    );
+}
+
+#[test]
+fn concave_and_convex_sources() {
+    let controllers = SynthControllers::default();
+    let velocity_to_attenuation = synth_modulator(0x0502, 0, 0);
+
+    // Negative concave velocity: silent at zero, unattenuated at 127.
+    assert_close(velocity_to_attenuation.value(&controllers, 60, 0), 1000.0);
+    assert_close(velocity_to_attenuation.value(&controllers, 60, 127), 0.0);
+    assert_close(
+        velocity_to_attenuation.value(&controllers, 60, 64),
+        1000.0 * concave(1.0 - 64.0 / 127.0),
+    );
+
+    // Positive convex velocity mirrors the concave curve.
+    let convex = synth_modulator(0x0802, 0, 0);
+    assert_close(convex.value(&controllers, 60, 0), 0.0);
+    assert_close(convex.value(&controllers, 60, 127), 1000.0);
+    assert_close(
+        convex.value(&controllers, 60, 100),
+        1000.0 * (1.0 - concave(1.0 - 100.0 / 127.0)),
+    );
+
+    // Bipolar concave curves are mirrored around the center of the range.
+    let bipolar_concave = synth_modulator(0x0602, 0, 0);
+    for velocity in 0..=127 {
+        let value = bipolar_concave.value(&controllers, 60, velocity);
+        let mirrored = bipolar_concave.value(&controllers, 60, 127 - velocity);
+        assert_close(value, -mirrored);
+    }
+    assert_close(bipolar_concave.value(&controllers, 60, 127), 1000.0);
+    assert_close(bipolar_concave.value(&controllers, 60, 0), -1000.0);
+}
+
+#[test]
+fn switch_sources() {
+    let controllers = SynthControllers::default();
+
+    let unipolar = synth_modulator(0x0C02, 0, 0);
+    assert_close(unipolar.value(&controllers, 60, 63), 0.0);
+    assert_close(unipolar.value(&controllers, 60, 64), 1000.0);
+
+    let bipolar_negative = synth_modulator(0x0F02, 0, 0);
+    assert_close(bipolar_negative.value(&controllers, 60, 63), 1000.0);
+    assert_close(bipolar_negative.value(&controllers, 60, 64), -1000.0);
+}
+
+#[test]
+fn pitch_wheel_and_sensitivity() {
+    let mut controllers = SynthControllers::default();
+    let pitch_wheel = default_modulators()[9];
+
+    assert_close(pitch_wheel.value(&controllers, 60, 100), 0.0);
+
+    controllers.pitch_wheel = 0;
+    assert_close(pitch_wheel.value(&controllers, 60, 100), -200.0);
+
+    // Sensitivity is set through the data entry of RPN 0.
+    controllers.set_controller(101, 0);
+    controllers.set_controller(100, 0);
+    controllers.set_controller(6, 12);
+    assert_eq!(controllers.pitch_wheel_sensitivity, 12);
+
+    controllers.pitch_wheel = 0x3FFF;
+    assert_close(
+        pitch_wheel.value(&controllers, 60, 100),
+        1200.0 * (8191.0 / 8192.0),
+    );
+
+    // Data entry for a non-registered parameter leaves it alone.
+    controllers.set_controller(99, 0);
+    controllers.set_controller(98, 0);
+    controllers.set_controller(6, 24);
+    assert_eq!(controllers.pitch_wheel_sensitivity, 12);
+}
+
+#[test]
+fn full_bend_is_sensitivity_in_cents() {
+    let modulators = SynthModulators::default();
+    let generators = SynthGenerators::default();
+    // The default sensitivity of two semitones bends by 200 cents either way,
+    // the upwards bend falling short by a step of the pitch wheel.
+    let mut controllers = SynthControllers {
+        pitch_wheel: 0,
+        ..Default::default()
+    };
+    let modulated = modulators.apply(&generators, &controllers, 60, 127);
+    assert_eq!(modulated.get(Sf2GeneratorKind::FineTune), -200);
+
+    controllers.pitch_wheel = 0x3FFF;
+    let modulated = modulators.apply(&generators, &controllers, 60, 127);
+    assert_eq!(modulated.get(Sf2GeneratorKind::FineTune), 200);
+
+    let pitch_wheel = default_modulators()[9];
+    controllers.pitch_wheel_sensitivity = 24;
+    controllers.pitch_wheel = 0;
+    assert_close(pitch_wheel.value(&controllers, 60, 127), -2400.0);
+}
+
+#[test]
+fn amount_source_and_transform() {
+    let controllers = controllers_with(1, 64);
+
+    // Velocity scaled by the modulation wheel.
+    assert_close(
+        synth_modulator(0x0002, 0x0081, 0).value(&controllers, 60, 32),
+        1000.0 * 32.0 / 128.0 * 0.5,
+    );
+
+    // Absolute value of a bipolar key number.
+    let absolute = synth_modulator(0x0203, 0, 2);
+    assert_close(
+        absolute.value(&controllers, 32, 100),
+        1000.0 * (1.0 - 2.0 * 32.0 / 128.0),
+    );
+    assert_close(
+        absolute.value(&controllers, 96, 100),
+        1000.0 * (2.0 * 96.0 / 128.0 - 1.0),
+    );
+}
+
+#[test]
+fn invalid_modulators_are_ignored() {
+    let destination = Sf2GeneratorKind::FineTune as u16;
+
+    // Data entry and the channel mode messages are not valid sources.
+    assert!(SynthModulator::from_raw(0x0086, destination, 100, 0, 0).is_none());
+    assert!(SynthModulator::from_raw(0x00FB, destination, 100, 0, 0).is_none());
+    // Undefined general controller.
+    assert!(SynthModulator::from_raw(0x0005, destination, 100, 0, 0).is_none());
+    // Undefined curve type.
+    assert!(SynthModulator::from_raw(0x1002, destination, 100, 0, 0).is_none());
+    // Undefined transform.
+    assert!(SynthModulator::from_raw(0x0002, destination, 100, 0, 1).is_none());
+    // Linked modulators.
+    assert!(SynthModulator::from_raw(0x0002, 0x8001, 100, 0, 0).is_none());
+    assert!(SynthModulator::from_raw(0x007F, destination, 100, 0, 0).is_none());
+}
+
+#[test]
+fn instrument_modulators_override_defaults() {
+    let instrument_modulators = [
+        // Identical to the velocity to attenuation default, a new amount.
+        modulator(0x0502, Sf2GeneratorKind::InitialAttenuation, 480, 0),
+        // Differs from the defaults in its amount source.
+        modulator(0x0502, Sf2GeneratorKind::InitialAttenuation, 100, 0x0081),
+    ];
+
+    let modulators = SynthModulators::new(NO_MODULATORS, &[&[], &instrument_modulators]);
+
+    assert_eq!(modulators.modulators().len(), 11);
+    assert_eq!(modulators.modulators()[0].amount, 480);
+    assert_eq!(modulators.modulators()[10].amount, 100);
+}
+
+#[test]
+fn local_zone_overrides_global_zone() {
+    let global_modulators = [
+        modulator(0x0081, Sf2GeneratorKind::Pan, 300, 0),
+        modulator(0x0082, Sf2GeneratorKind::Pan, 200, 0),
+    ];
+    let local_modulators = [
+        modulator(0x0081, Sf2GeneratorKind::Pan, -300, 0),
+        // A second identical modulator in the same zone overrides the first.
+        modulator(0x0081, Sf2GeneratorKind::Pan, 500, 0),
+    ];
+
+    let modulators = SynthModulators::new(NO_MODULATORS, &[&global_modulators, &local_modulators]);
+    let added_modulators = &modulators.modulators()[10..];
+
+    assert_eq!(added_modulators.len(), 2);
+    assert_eq!(added_modulators[0].amount, 500);
+    assert_eq!(added_modulators[1].amount, 200);
+}
+
+#[test]
+fn last_identical_modulator_in_zone_wins() {
+    let zone_modulators = [
+        modulator(0x0081, Sf2GeneratorKind::FineTune, 100, 0),
+        modulator(0x0082, Sf2GeneratorKind::FineTune, 50, 0),
+        modulator(0x0081, Sf2GeneratorKind::FineTune, -200, 0),
+        modulator(0x0081, Sf2GeneratorKind::FineTune, 300, 0),
+    ];
+
+    let modulators = SynthModulators::new(NO_MODULATORS, &[&zone_modulators]);
+    let added_modulators = &modulators.modulators()[10..];
+
+    // The overriding modulator keeps the position of the first one.
+    assert_eq!(added_modulators.len(), 2);
+    assert_eq!(added_modulators[0].amount, 300);
+    assert_eq!(added_modulators[1].amount, 50);
+
+    // The same applies to the preset level.
+    let modulators = SynthModulators::new(&[&zone_modulators], NO_MODULATORS);
+    let added_modulators = &modulators.modulators()[10..];
+
+    assert_eq!(added_modulators.len(), 2);
+    assert_eq!(added_modulators[0].amount, 300);
+}
+
+#[test]
+fn preset_modulators_add_to_instrument_modulators() {
+    let instrument_modulators = [modulator(0x0081, Sf2GeneratorKind::FineTune, 100, 0)];
+    let preset_modulators = [
+        modulator(0x0081, Sf2GeneratorKind::FineTune, 50, 0),
+        modulator(0x0502, Sf2GeneratorKind::InitialAttenuation, -960, 0),
+    ];
+
+    let modulators = SynthModulators::new(&[&preset_modulators], &[&instrument_modulators]);
+    assert_eq!(modulators.modulators().len(), 13);
+
+    let mut generators = SynthGenerators::default();
+    generators.set(Sf2GeneratorKind::FineTune, 10);
+
+    let controllers = controllers_with(1, 127);
+    let modulated = modulators.apply(&generators, &controllers, 60, 0);
+
+    // Both modulation wheel modulators sum up on top of the generator.
+    assert_eq!(
+        modulated.get(Sf2GeneratorKind::FineTune),
+        10 + (150.0 * 127.0 / 128.0f64).round() as i32
+    );
+
+    // The preset modulator cancels the default one at zero velocity, while
+    // the channel volume of 100 still attenuates.
+    assert_eq!(
+        modulated.get(Sf2GeneratorKind::InitialAttenuation),
+        (960.0 * concave(1.0 - 100.0 / 127.0)).round() as i32
+    );
+}
+
+#[test]
+fn apply_with_default_controllers() {
+    let modulators = SynthModulators::default();
+    let generators = SynthGenerators::default();
+    let controllers = SynthControllers::default();
+
+    let modulated = modulators.apply(&generators, &controllers, 60, 127);
+
+    // Only the channel volume of 100 and the velocity to filter cutoff
+    // modulator have an effect, pan stays centered and the pitch unbent.
+    assert_eq!(
+        modulated.get(Sf2GeneratorKind::InitialAttenuation),
+        (960.0 * concave(1.0 - 100.0 / 127.0)).round() as i32
+    );
+    assert_eq!(
+        modulated.get(Sf2GeneratorKind::InitialFilterFc),
+        13500 + (-2400.0 / 128.0f64).round() as i32
+    );
+    assert_eq!(modulated.get(Sf2GeneratorKind::Pan), 0);
+    assert_eq!(modulated.get(Sf2GeneratorKind::FineTune), 0);
+    assert_eq!(modulated.get(Sf2GeneratorKind::VibLfoToPitch), 0);
+}
+
+#[test]
+fn reset_all_controllers() {
+    let mut controllers = SynthControllers::default();
+    controllers.set_controller(7, 80);
+    controllers.set_controller(11, 40);
+    controllers.set_controller(1, 90);
+    controllers.channel_pressure = 100;
+    controllers.pitch_wheel = 0;
+
+    controllers.reset();
+
+    assert_eq!(controllers.controllers[7], 80);
+    assert_eq!(controllers.controllers[11], 127);
+    assert_eq!(controllers.controllers[1], 0);
+    assert_eq!(controllers.channel_pressure, 0);
+    assert_eq!(controllers.pitch_wheel, 0x2000);
+}
@@ -0,0 +1,315 @@
+use parser_sf2::{Sf2GeneratorKind, Sf2Model, Sf2ModelSample, Sf2SampleType, Sf2SoundFont};
+use strum::IntoEnumIterator;
+use synth_sf2::{SynthGenerators, SynthInterpolation, SynthLoopMode, SynthSampler};
+
+const LOOP_START: usize = 500;
+const LOOP_END: usize = 900;
+
+/// A file with a single sample of the given length, a sawtooth rising by 16
+/// on every point and wrapping around every 1000 points, with a loop on
+/// `LOOP_START..LOOP_END`.
+fn test_soundfont(sample_length: usize) -> Vec<u8> {
+    let model = Sf2Model {
+        samples: vec![Sf2ModelSample {
+            sample_name: "Sawtooth".to_owned(),
+            samples_16: (0..sample_length)
+                .map(|index| (index % 1000) as i16 * 16)
+                .collect(),
+            start_loop: LOOP_START as u32,
+            end_loop: LOOP_END as u32,
+            sample_rate: 44100,
+            original_pitch: 60,
+            sample_type: Sf2SampleType::Mono as u16,
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    model.to_bytes().unwrap()
+}
+
+fn sampler<'a>(
+    soundfont: &'a Sf2SoundFont<'a>,
+    generators: &[(Sf2GeneratorKind, i32)],
+    interpolation: SynthInterpolation,
+) -> SynthSampler<'a> {
+    let mut synth_generators = SynthGenerators::default();
+    for &(generator_kind, amount) in generators {
+        synth_generators.set(generator_kind, amount);
+    }
+
+    let sample_header = soundfont.sample_header(0).unwrap();
+    let sample_data = soundfont.sample_data(sample_header).unwrap();
+    SynthSampler::new(sample_header, sample_data, &synth_generators, interpolation)
+}
+
+fn sample_point(index: usize) -> f32 {
+    ((index % 1000) * 16) as f32 / 32768.0
+}
+
+/// Plays the sampler until it finishes, returning its output.
+fn play(sampler: &mut SynthSampler, increment: f64, max_length: usize) -> Vec<f32> {
+    let mut output = Vec::new();
+
+    while !sampler.is_finished() && (output.len() < max_length) {
+        output.push(sampler.value());
+        sampler.advance(increment);
+    }
+
+    output
+}
+
+#[test]
+fn integer_positions_are_exact() {
+    let buffer = test_soundfont(1000);
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+
+    for interpolation in SynthInterpolation::iter() {
+        let output = play(&mut sampler(&soundfont, &[], interpolation), 1.0, 2000);
+        assert_eq!(output.len(), 1000);
+
+        // Away from the edges of the sample, where the kernels reach
+        // into silence.
+        for (index, &value) in output.iter().enumerate().take(990).skip(10) {
+            assert!(
+                (value - sample_point(index)).abs() < 1e-6,
+                "{interpolation:} at {index}"
+            );
+        }
+    }
+}
+
+#[test]
+fn fractional_positions() {
+    let buffer = test_soundfont(1000);
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+
+    let halfway = |index: usize| (sample_point(index) + sample_point(index + 1)) / 2.0;
+
+    let nearest = play(
+        &mut sampler(&soundfont, &[], SynthInterpolation::Nearest),
+        0.5,
+        2000,
+    );
+    assert_eq!(nearest.len(), 2000);
+    assert_eq!(nearest[201], sample_point(101));
+
+    // A ramp is reproduced exactly by linear and cubic interpolation, and
+    // closely by the windowed sinc.
+    for (interpolation, tolerance) in [
+        (SynthInterpolation::Linear, 1e-6),
+        (SynthInterpolation::Cubic, 1e-6),
+        (SynthInterpolation::Sinc, 1e-3),
+    ] {
+        let output = play(&mut sampler(&soundfont, &[], interpolation), 0.5, 2000);
+        assert_eq!(output.len(), 2000);
+
+        for index in 10..990 {
+            assert!(
+                (output[index * 2 + 1] - halfway(index)).abs() < tolerance,
+                "{interpolation:} at {index}"
+            );
+        }
+    }
+}
+
+#[test]
+fn continuous_loop() {
+    let buffer = test_soundfont(1000);
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+
+    let mut sampler = sampler(
+        &soundfont,
+        &[(Sf2GeneratorKind::SampleModes, 1)],
+        SynthInterpolation::Linear,
+    );
+    assert_eq!(sampler.loop_mode(), SynthLoopMode::Continuous);
+
+    let output = play(&mut sampler, 1.0, 2000);
+    assert_eq!(output.len(), 2000);
+
+    // The loop end is the first point after the loop.
+    assert_eq!(output[LOOP_END - 1], sample_point(LOOP_END - 1));
+    assert_eq!(output[LOOP_END], sample_point(LOOP_START));
+
+    // Releasing does not leave a continuous loop.
+    sampler.release();
+    assert_eq!(play(&mut sampler, 1.0, 2000).len(), 2000);
+}
+
+#[test]
+fn loop_until_release() {
+    let buffer = test_soundfont(1000);
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+
+    let mut sampler = sampler(
+        &soundfont,
+        &[(Sf2GeneratorKind::SampleModes, 3)],
+        SynthInterpolation::Linear,
+    );
+    assert_eq!(sampler.loop_mode(), SynthLoopMode::UntilRelease);
+
+    assert_eq!(play(&mut sampler, 1.0, 1050).len(), 1050);
+    assert_eq!(sampler.position(), (LOOP_START + 150) as f64);
+
+    // The remainder of the sample plays after the release.
+    sampler.release();
+    let output = play(&mut sampler, 1.0, 2000);
+    assert_eq!(output.len(), 1000 - (LOOP_START + 150));
+    assert_eq!(output[LOOP_END - LOOP_START - 150], sample_point(LOOP_END));
+}
+
+#[test]
+fn loop_wraps_sample_accurately() {
+    let buffer = test_soundfont(1000);
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+
+    let mut sampler = sampler(
+        &soundfont,
+        &[(Sf2GeneratorKind::SampleModes, 1)],
+        SynthInterpolation::Linear,
+    );
+
+    // Increments of 0.75 are exact in fixed point, the position after
+    // crossing the loop end many times stays on the quarter grid.
+    play(&mut sampler, 0.75, 100000);
+
+    let expected = LOOP_START as f64 + (100000.0 * 0.75 - LOOP_START as f64) % 400.0;
+    assert_eq!(sampler.position(), expected);
+}
+
+#[test]
+fn unrepresentable_increments_finish() {
+    let buffer = test_soundfont(1000);
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+
+    for increment in [f64::INFINITY, f64::NAN, 1e30, (1u64 << 32) as f64 - 1.0] {
+        let mut sampler = sampler(
+            &soundfont,
+            &[(Sf2GeneratorKind::SampleModes, 1)],
+            SynthInterpolation::Linear,
+        );
+
+        // Moves the position past the loop start first, so that the last
+        // increment overflows the position.
+        sampler.advance(LOOP_START as f64 + 1.0);
+        sampler.advance(increment);
+        assert!(sampler.is_finished(), "{increment}");
+    }
+}
+
+#[test]
+fn address_offsets() {
+    let buffer = test_soundfont(70000);
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+
+    let mut sampler = sampler(
+        &soundfont,
+        &[
+            (Sf2GeneratorKind::StartAddrsOffset, 5),
+            (Sf2GeneratorKind::StartAddrsCoarseOffset, 1),
+            (Sf2GeneratorKind::EndAddrsOffset, -100),
+            (Sf2GeneratorKind::EndAddrsCoarseOffset, -1),
+        ],
+        SynthInterpolation::Nearest,
+    );
+
+    let output = play(&mut sampler, 1.0, 100000);
+    assert_eq!(output.len(), 70000 - 32768 - 100 - 32773);
+    assert_eq!(output[0], sample_point(32773));
+
+    // The loop moved into the second coarse block, ten points shorter.
+    let mut sampler = sampler_with_loop_offsets(&soundfont);
+    assert_eq!(sampler.loop_mode(), SynthLoopMode::Continuous);
+
+    let output = play(&mut sampler, 1.0, 40000);
+    let loop_start = LOOP_START + 32768 + 10;
+    let loop_end = LOOP_END + 32768;
+    assert_eq!(output[loop_end], sample_point(loop_start));
+    assert_eq!(output[loop_end + 1], sample_point(loop_start + 1));
+}
+
+fn sampler_with_loop_offsets<'a>(soundfont: &'a Sf2SoundFont<'a>) -> SynthSampler<'a> {
+    sampler(
+        soundfont,
+        &[
+            (Sf2GeneratorKind::SampleModes, 1),
+            (Sf2GeneratorKind::StartloopAddrsOffset, 10),
+            (Sf2GeneratorKind::StartloopAddrsCoarseOffset, 1),
+            (Sf2GeneratorKind::EndloopAddrsCoarseOffset, 1),
+        ],
+        SynthInterpolation::Nearest,
+    )
+}
+
+#[test]
+fn invalid_offsets() {
+    let buffer = test_soundfont(1000);
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+
+    // A loop reaching past the end of the sample is not played.
+    let mut sampler_past_end = sampler(
+        &soundfont,
+        &[
+            (Sf2GeneratorKind::SampleModes, 1),
+            (Sf2GeneratorKind::EndloopAddrsOffset, 200),
+        ],
+        SynthInterpolation::Linear,
+    );
+    assert_eq!(sampler_past_end.loop_mode(), SynthLoopMode::NoLoop);
+    assert_eq!(play(&mut sampler_past_end, 1.0, 2000).len(), 1000);
+
+    // Offsets outside of the sample get clamped.
+    let mut sampler_clamped = sampler(
+        &soundfont,
+        &[
+            (Sf2GeneratorKind::StartAddrsOffset, -100),
+            (Sf2GeneratorKind::EndAddrsOffset, 100),
+        ],
+        SynthInterpolation::Linear,
+    );
+    assert_eq!(play(&mut sampler_clamped, 1.0, 2000).len(), 1000);
+
+    let mut sampler_empty = sampler(
+        &soundfont,
+        &[(Sf2GeneratorKind::StartAddrsOffset, 2000)],
+        SynthInterpolation::Linear,
+    );
+    assert!(sampler_empty.is_finished());
+    assert_eq!(sampler_empty.value(), 0.0);
+    assert!(play(&mut sampler_empty, 1.0, 2000).is_empty());
+}
+
+#[test]
+fn reproducible_output() {
+    let buffer = test_soundfont(1000);
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+
+    for interpolation in SynthInterpolation::iter() {
+        let render = || {
+            let mut sampler = sampler(
+                &soundfont,
+                &[(Sf2GeneratorKind::SampleModes, 1)],
+                interpolation,
+            );
+            play(&mut sampler, 1.0 / 3.0_f64.sqrt(), 5000)
+                .iter()
+                .map(|value| value.to_bits())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(render(), render());
+    }
+}
+
+#[test]
+fn interpolation_names() {
+    let names = SynthInterpolation::iter()
+        .map(|interpolation| interpolation.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["nearest", "linear", "cubic", "sinc"]);
+
+    assert_eq!("sinc".parse(), Ok(SynthInterpolation::Sinc));
+    assert!("quadratic".parse::<SynthInterpolation>().is_err());
+    assert_eq!(SynthInterpolation::default(), SynthInterpolation::Linear);
+}
@@ -0,0 +1,600 @@
+use parser_sf2::{
+    Sf2GeneratorAmount, Sf2GeneratorKind, Sf2Model, Sf2ModelGenerator, Sf2ModelInstrument,
+    Sf2ModelModulator, Sf2ModelPreset, Sf2ModelSample, Sf2ModelZone, Sf2SampleType, Sf2SoundFont,
+};
+use synth_sf2::{Synth, SynthError};
+
+const SAMPLE_RATE: u32 = 44100;
+const SAMPLE_LENGTH: usize = 1000;
+
+fn generator(generator_kind: Sf2GeneratorKind, amount: i16) -> Sf2ModelGenerator {
+    Sf2ModelGenerator::new(generator_kind, generator_kind.decode_amount(amount as u16))
+}
+
+fn key_range(low: u8, high: u8) -> Sf2ModelGenerator {
+    Sf2ModelGenerator::new(
+        Sf2GeneratorKind::KeyRange,
+        Sf2GeneratorAmount::Range { low, high },
+    )
+}
+
+fn modulator(
+    source: u16,
+    destination: Sf2GeneratorKind,
+    amount: i16,
+    amount_source: u16,
+) -> Sf2ModelModulator {
+    Sf2ModelModulator {
+        source,
+        destination: destination as u16,
+        amount,
+        amount_source,
+        transform: 0,
+    }
+}
+
+/// Overrides of the default modulators attenuating and filtering notes by
+/// their velocity and the channel volume, so the sample plays as it is.
+fn neutral_modulators() -> Vec<Sf2ModelModulator> {
+    vec![
+        modulator(0x0502, Sf2GeneratorKind::InitialAttenuation, 0, 0),
+        modulator(0x0102, Sf2GeneratorKind::InitialFilterFc, 0, 0),
+        modulator(0x0587, Sf2GeneratorKind::InitialAttenuation, 0, 0),
+    ]
+}
+
+/// A bank with a single preset on 000:000, holding a single instrument with
+/// the given zones. Its only sample is a constant signal of 0.5 amplitude,
+/// with a loop in its second half.
+///
+/// Unless the zones set them otherwise, the volume envelopes have no delay,
+/// attack and release times, so voices start and stop instantly.
+fn test_soundfont(
+    preset_generators: Vec<Sf2ModelGenerator>,
+    instrument_zones: Vec<Vec<Sf2ModelGenerator>>,
+) -> Vec<u8> {
+    test_soundfont_with_modulators(
+        preset_generators,
+        vec![],
+        instrument_zones,
+        neutral_modulators(),
+    )
+}
+
+/// Same as `test_soundfont`, with the given modulators on the preset zone
+/// and on every instrument zone.
+fn test_soundfont_with_modulators(
+    preset_generators: Vec<Sf2ModelGenerator>,
+    preset_modulators: Vec<Sf2ModelModulator>,
+    instrument_zones: Vec<Vec<Sf2ModelGenerator>>,
+    instrument_modulators: Vec<Sf2ModelModulator>,
+) -> Vec<u8> {
+    let mut preset_generators = preset_generators;
+    preset_generators.push(generator(Sf2GeneratorKind::Instrument, 0));
+
+    let model = Sf2Model {
+        presets: vec![Sf2ModelPreset {
+            preset_name: "Test".to_owned(),
+            zones: vec![Sf2ModelZone {
+                generators: preset_generators,
+                modulators: preset_modulators,
+            }],
+            ..Default::default()
+        }],
+        instruments: vec![Sf2ModelInstrument {
+            instrument_name: "Test".to_owned(),
+            zones: instrument_zones
+                .into_iter()
+                .map(|mut generators| {
+                    for generator_kind in [
+                        Sf2GeneratorKind::DelayVolEnv,
+                        Sf2GeneratorKind::AttackVolEnv,
+                        Sf2GeneratorKind::ReleaseVolEnv,
+                    ] {
+                        if !generators
+                            .iter()
+                            .any(|generator| generator.generator_id == generator_kind as u16)
+                        {
+                            generators.push(generator(generator_kind, -32768));
+                        }
+                    }
+
+                    generators.push(generator(Sf2GeneratorKind::SampleId, 0));
+                    Sf2ModelZone {
+                        generators,
+                        modulators: instrument_modulators.clone(),
+                    }
+                })
+                .collect(),
+        }],
+        samples: vec![Sf2ModelSample {
+            sample_name: "Constant".to_owned(),
+            samples_16: vec![16384; SAMPLE_LENGTH],
+            start_loop: (SAMPLE_LENGTH / 2) as u32,
+            end_loop: SAMPLE_LENGTH as u32,
+            sample_rate: SAMPLE_RATE,
+            original_pitch: 60,
+            sample_type: Sf2SampleType::Mono as u16,
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    model.to_bytes().unwrap()
+}
+
+fn render(synth: &mut Synth, frame_count: usize) -> Vec<f32> {
+    let mut output = vec![0.0; frame_count * 2];
+    synth.render(&mut output);
+    output
+}
+
+fn sounding_frames(output: &[f32]) -> usize {
+    output
+        .chunks_exact(2)
+        .filter(|frame| frame[0] != 0.0 || frame[1] != 0.0)
+        .count()
+}
+
+#[test]
+fn missing_preset_and_channel() {
+    let buffer = test_soundfont(vec![], vec![vec![]]);
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    let mut synth = Synth::new(&soundfont, SAMPLE_RATE);
+
+    assert!(matches!(
+        synth.select_preset(0, 0, 1),
+        Err(SynthError::PresetNotFound { bank: 0, preset: 1 })
+    ));
+    assert!(matches!(
+        synth.note_on(16, 60, 100),
+        Err(SynthError::InvalidChannel { channel: 16 })
+    ));
+
+    // Channels without a preset stay silent.
+    synth.note_on(0, 60, 100).unwrap();
+    assert_eq!(synth.active_voice_count(), 0);
+}
+
+#[test]
+fn broken_zones_are_skipped() {
+    let sample = |sample_name: &str, sample_type: Sf2SampleType| Sf2ModelSample {
+        sample_name: sample_name.to_owned(),
+        samples_16: vec![16384; SAMPLE_LENGTH],
+        sample_rate: SAMPLE_RATE,
+        original_pitch: 60,
+        sample_type: sample_type as u16,
+        ..Default::default()
+    };
+
+    let model = Sf2Model {
+        presets: vec![Sf2ModelPreset {
+            preset_name: "Test".to_owned(),
+            zones: vec![
+                Sf2ModelZone {
+                    generators: vec![generator(Sf2GeneratorKind::Instrument, 0)],
+                    modulators: vec![],
+                },
+                // Missing instrument
+                Sf2ModelZone {
+                    generators: vec![generator(Sf2GeneratorKind::Instrument, 5)],
+                    modulators: vec![],
+                },
+            ],
+            ..Default::default()
+        }],
+        instruments: vec![Sf2ModelInstrument {
+            instrument_name: "Test".to_owned(),
+            // The valid sample, a ROM sample, a sample with a broken header
+            // and a missing sample
+            zones: [0, 1, 2, 3]
+                .into_iter()
+                .map(|sample_index| Sf2ModelZone {
+                    generators: vec![generator(Sf2GeneratorKind::SampleId, sample_index)],
+                    modulators: neutral_modulators(),
+                })
+                .collect(),
+        }],
+        samples: vec![
+            sample("Valid", Sf2SampleType::Mono),
+            sample("ROM", Sf2SampleType::RomMono),
+            sample("Broken", Sf2SampleType::Mono),
+        ],
+        ..Default::default()
+    };
+
+    let mut buffer = model.to_bytes().unwrap();
+
+    // The third sample ends past the end of the sample data.
+    let shdr_offset = buffer
+        .windows(4)
+        .position(|window| window == b"shdr")
+        .unwrap()
+        + 8;
+    let end_offset = shdr_offset + 2 * 46 + 24;
+    buffer[end_offset..end_offset + 4].copy_from_slice(&1_000_000u32.to_le_bytes());
+
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    let mut synth = Synth::new(&soundfont, SAMPLE_RATE);
+
+    synth.select_preset(0, 0, 0).unwrap();
+    synth.note_on(0, 60, 100).unwrap();
+    assert_eq!(synth.active_voice_count(), 1);
+
+    let output = render(&mut synth, 1000);
+    assert!(sounding_frames(&output) > 0);
+}
+
+#[test]
+fn plays_sample_at_root_key() {
+    let buffer = test_soundfont(vec![], vec![vec![]]);
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    let mut synth = Synth::new(&soundfont, SAMPLE_RATE);
+
+    synth.select_preset(0, 0, 0).unwrap();
+    assert!(render(&mut synth, 100).iter().all(|&sample| sample == 0.0));
+
+    synth.note_on(0, 60, 100).unwrap();
+    let output = render(&mut synth, SAMPLE_LENGTH * 2);
+
+    // Center panned, unattenuated.
+    let expected = 0.5 * std::f32::consts::FRAC_1_SQRT_2;
+    assert!((output[0] - expected).abs() < 1e-6);
+    assert!((output[1] - expected).abs() < 1e-6);
+
+    assert_eq!(sounding_frames(&output), SAMPLE_LENGTH);
+    assert_eq!(synth.active_voice_count(), 0);
+}
+
+#[test]
+fn tuning_scales_playback_rate() {
+    let buffer = test_soundfont(
+        vec![],
+        vec![vec![
+            generator(Sf2GeneratorKind::CoarseTune, 12),
+            generator(Sf2GeneratorKind::FineTune, -1200 / 2),
+        ]],
+    );
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    let mut synth = Synth::new(&soundfont, SAMPLE_RATE);
+    synth.select_preset(0, 0, 0).unwrap();
+
+    // +12 semitones from key, +12 from coarse tune, -6 from fine tune.
+    synth.note_on(0, 72, 100).unwrap();
+    let output = render(&mut synth, SAMPLE_LENGTH);
+
+    let expected_frames = SAMPLE_LENGTH as f64 / f64::powf(2.0, 18.0 / 12.0);
+    assert_eq!(sounding_frames(&output), expected_frames.ceil() as usize);
+}
+
+#[test]
+fn key_ranges_select_zones() {
+    let buffer = test_soundfont(
+        vec![],
+        vec![
+            vec![key_range(0, 59), generator(Sf2GeneratorKind::Pan, -500)],
+            vec![key_range(60, 127), generator(Sf2GeneratorKind::Pan, 500)],
+        ],
+    );
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    let mut synth = Synth::new(&soundfont, SAMPLE_RATE);
+    synth.select_preset(0, 0, 0).unwrap();
+
+    synth.note_on(0, 40, 100).unwrap();
+    assert_eq!(synth.active_voice_count(), 1);
+
+    let output = render(&mut synth, 10);
+    assert!(output
+        .chunks_exact(2)
+        .all(|frame| (frame[0] - 0.5).abs() < 1e-6));
+    assert!(output.chunks_exact(2).all(|frame| frame[1].abs() < 1e-6));
+}
+
+#[test]
+fn preset_attenuation_is_additive() {
+    let buffer = test_soundfont(
+        vec![generator(Sf2GeneratorKind::InitialAttenuation, 100)],
+        vec![vec![generator(Sf2GeneratorKind::InitialAttenuation, 100)]],
+    );
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    let mut synth = Synth::new(&soundfont, SAMPLE_RATE);
+    synth.select_preset(0, 0, 0).unwrap();
+
+    synth.note_on(0, 60, 100).unwrap();
+    let output = render(&mut synth, 1);
+
+    // 200 centibels in total, which is -20 dB.
+    let expected = 0.05 * std::f32::consts::FRAC_1_SQRT_2;
+    assert!((output[0] - expected).abs() < 1e-6);
+}
+
+#[test]
+fn loop_modes() {
+    for (sample_modes, release_time, expected_frames) in [
+        (0, -32768, SAMPLE_LENGTH),
+        (1, -32768, SAMPLE_LENGTH * 2),
+        (3, -32768, SAMPLE_LENGTH * 2),
+        (0, 8000, SAMPLE_LENGTH),
+        (1, 8000, SAMPLE_LENGTH * 4),
+        (3, 8000, SAMPLE_LENGTH * 2 + SAMPLE_LENGTH / 2),
+    ] {
+        let buffer = test_soundfont(
+            vec![],
+            vec![vec![
+                generator(Sf2GeneratorKind::SampleModes, sample_modes),
+                generator(Sf2GeneratorKind::ReleaseVolEnv, release_time),
+            ]],
+        );
+        let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+        let mut synth = Synth::new(&soundfont, SAMPLE_RATE);
+        synth.select_preset(0, 0, 0).unwrap();
+
+        synth.note_on(0, 60, 100).unwrap();
+        let mut output = render(&mut synth, SAMPLE_LENGTH * 2);
+
+        // During a long release the continuous loop keeps going, while the
+        // loop-until-release mode plays out the rest of the sample.
+        synth.note_off(0, 60).unwrap();
+        output.extend(render(&mut synth, SAMPLE_LENGTH * 2));
+
+        assert_eq!(
+            sounding_frames(&output),
+            expected_frames,
+            "sampleModes {sample_modes}, releaseVolEnv {release_time}"
+        );
+    }
+}
+
+#[test]
+fn exclusive_class_cuts_previous_notes() {
+    let buffer = test_soundfont(
+        vec![],
+        vec![vec![
+            generator(Sf2GeneratorKind::SampleModes, 1),
+            generator(Sf2GeneratorKind::ExclusiveClass, 1),
+        ]],
+    );
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    let mut synth = Synth::new(&soundfont, SAMPLE_RATE);
+    synth.select_preset(0, 0, 0).unwrap();
+    synth.select_preset(1, 0, 0).unwrap();
+
+    synth.note_on(0, 60, 100).unwrap();
+    synth.note_on(1, 60, 100).unwrap();
+    synth.note_on(0, 62, 100).unwrap();
+    render(&mut synth, 1);
+
+    assert_eq!(synth.active_voice_count(), 2);
+}
+
+#[test]
+fn sustain_defers_release() {
+    let buffer = test_soundfont(
+        vec![],
+        vec![vec![generator(Sf2GeneratorKind::SampleModes, 1)]],
+    );
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    let mut synth = Synth::new(&soundfont, SAMPLE_RATE);
+    synth.select_preset(0, 0, 0).unwrap();
+
+    synth.control_change(0, 64, 127).unwrap();
+    synth.note_on(0, 60, 100).unwrap();
+    synth.note_off(0, 60).unwrap();
+    render(&mut synth, 10);
+    assert_eq!(synth.active_voice_count(), 1);
+
+    synth.control_change(0, 64, 0).unwrap();
+    render(&mut synth, 10);
+    assert_eq!(synth.active_voice_count(), 0);
+}
+
+#[test]
+fn pitch_bend_shifts_pitch() {
+    let buffer = test_soundfont(vec![], vec![vec![]]);
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    let mut synth = Synth::new(&soundfont, SAMPLE_RATE);
+    synth.select_preset(0, 0, 0).unwrap();
+
+    // Full upwards bend, two semitones.
+    synth.pitch_bend(0, 0x3FFF).unwrap();
+    synth.note_on(0, 60, 100).unwrap();
+    let output = render(&mut synth, SAMPLE_LENGTH);
+
+    // The default pitch wheel modulator bends by 100 cents per semitone of
+    // sensitivity, rounded to whole cents.
+    let pitch_offset = f64::round(200.0 * (8191.0 / 8192.0));
+    let expected_frames = SAMPLE_LENGTH as f64 / f64::powf(2.0, pitch_offset / 1200.0);
+    assert_eq!(sounding_frames(&output), expected_frames.ceil() as usize);
+}
+
+#[test]
+fn modulation_lfo_swings_volume() {
+    let buffer = test_soundfont(
+        vec![],
+        vec![vec![
+            generator(Sf2GeneratorKind::SampleModes, 1),
+            generator(Sf2GeneratorKind::DelayModLfo, -32768),
+            generator(Sf2GeneratorKind::FreqModLfo, 4500),
+            generator(Sf2GeneratorKind::ModLfoToVolume, 60),
+        ]],
+    );
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    let mut synth = Synth::new(&soundfont, SAMPLE_RATE);
+    synth.select_preset(0, 0, 0).unwrap();
+
+    synth.note_on(0, 60, 100).unwrap();
+    let output = render(&mut synth, SAMPLE_LENGTH);
+
+    // Triangle of about 100 Hz, peaking at +6 dB and bottoming at -6 dB.
+    let frequency = 8.176 * f64::powf(2.0, 4500.0 / 1200.0) / SAMPLE_RATE as f64;
+    for (frame, sample) in output.chunks_exact(2).enumerate() {
+        let phase = (frame as f64 * frequency).fract();
+        let lfo = if phase < 0.25 {
+            4.0 * phase
+        } else if phase < 0.75 {
+            2.0 - 4.0 * phase
+        } else {
+            4.0 * phase - 4.0
+        };
+
+        let expected = 0.5 * std::f64::consts::FRAC_1_SQRT_2 * f64::powf(10.0, lfo * 60.0 / 200.0);
+        assert!((sample[0] as f64 - expected).abs() < 1e-5, "frame {frame}");
+    }
+}
+
+#[test]
+fn filter_smooths_onset() {
+    let buffer = test_soundfont(
+        vec![],
+        vec![vec![
+            generator(Sf2GeneratorKind::SampleModes, 1),
+            generator(Sf2GeneratorKind::InitialFilterFc, 1500),
+        ]],
+    );
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    let mut synth = Synth::new(&soundfont, SAMPLE_RATE);
+    synth.select_preset(0, 0, 0).unwrap();
+
+    synth.note_on(0, 60, 100).unwrap();
+    let output = render(&mut synth, SAMPLE_RATE as usize);
+
+    // The constant signal passes the low-pass filter, but only gradually.
+    let expected = 0.5 * std::f32::consts::FRAC_1_SQRT_2;
+    assert!(output[0] < expected * 0.01);
+    assert!((output[output.len() - 2] - expected).abs() < 1e-4);
+}
+
+#[test]
+fn velocity_and_volume_attenuate() {
+    // Only the velocity and channel volume defaults are left in place.
+    let buffer = test_soundfont_with_modulators(
+        vec![],
+        vec![],
+        vec![vec![generator(Sf2GeneratorKind::SampleModes, 1)]],
+        vec![modulator(0x0102, Sf2GeneratorKind::InitialFilterFc, 0, 0)],
+    );
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    let mut synth = Synth::new(&soundfont, SAMPLE_RATE);
+    synth.select_preset(0, 0, 0).unwrap();
+
+    let concave = |x: f64| -5.0 / 12.0 * f64::log10(1.0 - x);
+    let expected_gain = |velocity: f64, volume: f64| {
+        let attenuation = (960.0 * concave(1.0 - velocity / 127.0)).round()
+            + (960.0 * concave(1.0 - volume / 127.0)).round();
+        0.5 * std::f64::consts::FRAC_1_SQRT_2 * f64::powf(10.0, -attenuation / 200.0)
+    };
+
+    synth.note_on(0, 60, 64).unwrap();
+    let output = render(&mut synth, 1);
+    assert!((output[0] as f64 - expected_gain(64.0, 100.0)).abs() < 1e-6);
+
+    // The sounding note follows the channel volume.
+    synth.control_change(0, 7, 127).unwrap();
+    let output = render(&mut synth, 1);
+    assert!((output[0] as f64 - expected_gain(64.0, 127.0)).abs() < 1e-6);
+}
+
+#[test]
+fn preset_modulator_disables_panning() {
+    let buffer = test_soundfont_with_modulators(
+        vec![],
+        vec![modulator(0x028A, Sf2GeneratorKind::Pan, -1000, 0)],
+        vec![vec![]],
+        neutral_modulators(),
+    );
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    let mut synth = Synth::new(&soundfont, SAMPLE_RATE);
+    synth.select_preset(0, 0, 0).unwrap();
+
+    // The preset-level modulator sums up with the default pan modulator.
+    synth.control_change(0, 10, 0).unwrap();
+    synth.note_on(0, 60, 100).unwrap();
+    let output = render(&mut synth, 1);
+
+    assert!((output[0] - output[1]).abs() < 1e-6);
+}
+
+#[test]
+fn pitch_bend_sensitivity() {
+    let buffer = test_soundfont(vec![], vec![vec![]]);
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    let mut synth = Synth::new(&soundfont, SAMPLE_RATE);
+    synth.select_preset(0, 0, 0).unwrap();
+
+    // Twelve semitones of range, full downwards bend.
+    for (controller, value) in [(101, 0), (100, 0), (6, 12)] {
+        synth.control_change(0, controller, value).unwrap();
+    }
+    synth.pitch_bend(0, 0).unwrap();
+    synth.note_on(0, 60, 100).unwrap();
+    let output = render(&mut synth, SAMPLE_LENGTH * 2);
+
+    let pitch_offset = -1200.0;
+    let expected_frames = SAMPLE_LENGTH as f64 / f64::powf(2.0, pitch_offset / 1200.0);
+    assert_eq!(sounding_frames(&output), expected_frames.ceil() as usize);
+}
+
+#[test]
+fn reverb_send() {
+    let buffer = test_soundfont(
+        vec![],
+        vec![vec![generator(Sf2GeneratorKind::ReverbEffectsSend, 500)]],
+    );
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+
+    let render_note = |is_reverb_enabled: bool| {
+        let mut synth = Synth::new(&soundfont, SAMPLE_RATE);
+        synth.set_reverb_enabled(is_reverb_enabled);
+        synth.select_preset(0, 0, 0).unwrap();
+
+        synth.note_on(0, 60, 100).unwrap();
+        let note = render(&mut synth, SAMPLE_LENGTH);
+        assert_eq!(synth.active_voice_count(), 0);
+
+        let is_active = synth.is_active();
+        let tail = render(&mut synth, SAMPLE_RATE as usize);
+        (note, is_active, tail)
+    };
+
+    // Dry renders keep the plain sample and end with the note.
+    let (dry_note, is_dry_active, dry_tail) = render_note(false);
+    let expected = 0.5 * std::f32::consts::FRAC_1_SQRT_2;
+    assert!(dry_note
+        .iter()
+        .all(|&sample| (sample - expected).abs() < 1e-6));
+    assert!(!is_dry_active);
+    assert!(dry_tail.iter().all(|&sample| sample == 0.0));
+
+    let (wet_note, is_wet_active, wet_tail) = render_note(true);
+    // The first reflection arrives after the short note has ended.
+    assert_eq!(wet_note, dry_note);
+    assert!(is_wet_active);
+    assert!(wet_tail.iter().any(|&sample| sample != 0.0));
+}
+
+#[test]
+fn chorus_send_controller() {
+    let buffer = test_soundfont(vec![], vec![vec![]]);
+    let soundfont = Sf2SoundFont::new(&buffer).unwrap();
+    let mut synth = Synth::new(&soundfont, SAMPLE_RATE);
+    synth.set_reverb_enabled(false);
+    synth.select_preset(0, 0, 0).unwrap();
+
+    // Without a send the chorus stays silent.
+    synth.note_on(0, 60, 100).unwrap();
+    render(&mut synth, SAMPLE_LENGTH);
+    assert!(!synth.is_active());
+
+    // The default modulator routes the chorus depth controller to the send.
+    synth.control_change(0, 93, 127).unwrap();
+    synth.note_on(0, 60, 100).unwrap();
+    render(&mut synth, SAMPLE_LENGTH);
+    assert!(synth.is_active());
+
+    let tail = render(&mut synth, SAMPLE_RATE as usize / 10);
+    assert!(tail.iter().any(|&sample| sample != 0.0));
+    assert!(!synth.is_active());
+
+    synth.set_chorus_enabled(false);
+    assert!(!synth.is_chorus_enabled());
+}